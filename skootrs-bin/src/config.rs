@@ -0,0 +1,276 @@
+//
+// Copyright 2024 The Skootrs Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Loading of the `SkootrsConfig` for the CLI.
+//!
+//! The config is layered, with each layer overriding the options set by the ones before it:
+//! 1. The defaults.
+//! 2. The user config file at `$XDG_CONFIG_HOME/skootrs/config.yaml`, or `~/.config/skootrs/config.yaml`.
+//! 3. The project-local config file `.skootrs.yaml` in the current directory.
+//! 4. `SKOOTRS_*` environment variables, e.g. `SKOOTRS_DEFAULT_BRANCH`. The `SKOOTRS_DEFAULT_FACETS`
+//!    variable is a comma separated list of facet types.
+
+use std::{env, ffi::OsString, fs, path::PathBuf};
+
+use serde_yaml::{Mapping, Value};
+use skootrs_model::skootrs::{SkootError, SkootrsConfig};
+use skootrs_statestore::LOCAL_REFERENCE_CACHE_FILE;
+
+/// The name of the project-local config file.
+pub const LOCAL_CONFIG_FILE: &str = ".skootrs.yaml";
+
+/// The config options that can be set with `SKOOTRS_*` environment variables.
+const ENV_CONFIG_KEYS: [&str; 10] = [
+    "local_project_path",
+    "state_store_path",
+    "state_store",
+    "reference_cache_path",
+    "default_organization",
+    "default_facets",
+    "default_branch",
+    "forge_base_url",
+    "log_format",
+    "ecosystem_scaffolding",
+];
+
+/// Returns the path of the user config file, if a home directory can be found.
+#[must_use]
+pub fn user_config_path() -> Option<PathBuf> {
+    env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .map(|config_dir| config_dir.join("skootrs").join("config.yaml"))
+}
+
+/// Returns the path of the local reference cache of known projects.
+///
+/// This is the configured `reference_cache_path`, or `.skootrscache` in `$XDG_CACHE_HOME/skootrs` or
+/// `~/.cache/skootrs`, so the same projects are known whichever directory Skootrs is run from.
+#[must_use]
+pub fn reference_cache_path(config: &SkootrsConfig) -> String {
+    reference_cache_path_from(config, |name| env::var_os(name))
+}
+
+/// Returns the path of the local reference cache, with the user's directories looked up with `env_var`.
+/// Without a home directory the cache is in the current directory.
+fn reference_cache_path_from(config: &SkootrsConfig, env_var: impl Fn(&str) -> Option<OsString>) -> String {
+    if let Some(path) = &config.reference_cache_path {
+        return path.clone();
+    }
+    env_var("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env_var("HOME").map(|home| PathBuf::from(home).join(".cache")))
+        .map_or_else(
+            || PathBuf::from(LOCAL_REFERENCE_CACHE_FILE),
+            |cache_dir| cache_dir.join("skootrs").join(LOCAL_REFERENCE_CACHE_FILE),
+        )
+        .to_string_lossy()
+        .to_string()
+}
+
+/// Returns the `SkootrsConfig` layered from the defaults, the user config file, the project-local config
+/// file, and the `SKOOTRS_*` environment variables.
+///
+/// # Errors
+///
+/// Returns an error if a config file exists but can't be read or parsed, or if the layered config isn't valid.
+pub fn load() -> Result<SkootrsConfig, SkootError> {
+    let config_files = user_config_path()
+        .into_iter()
+        .chain([PathBuf::from(LOCAL_CONFIG_FILE)]);
+    load_layers(config_files, |name| env::var(name).ok())
+}
+
+/// Returns the `SkootrsConfig` layered from the defaults, the `config_files` in order, and the `SKOOTRS_*`
+/// variables looked up with `env_var`. Config files that don't exist are skipped.
+fn load_layers(
+    config_files: impl IntoIterator<Item = PathBuf>,
+    env_var: impl Fn(&str) -> Option<String>,
+) -> Result<SkootrsConfig, SkootError> {
+    let mut config = serde_yaml::to_value(SkootrsConfig::default())?;
+    for path in config_files {
+        if !path.exists() {
+            continue;
+        }
+        let content = fs::read_to_string(&path)?;
+        let layer: Value = serde_yaml::from_str(&content)
+            .map_err(|err| format!("Failed to parse config file {}: {err}", path.display()))?;
+        // An empty config file parses as null and doesn't set anything.
+        if !layer.is_null() {
+            merge(&mut config, layer);
+        }
+    }
+    merge(&mut config, Value::Mapping(env_layer(env_var)));
+
+    Ok(serde_yaml::from_value(config)?)
+}
+
+/// Returns the config options set with `SKOOTRS_*` environment variables, looked up with `env_var`.
+fn env_layer(env_var: impl Fn(&str) -> Option<String>) -> Mapping {
+    let mut layer = Mapping::new();
+    for key in ENV_CONFIG_KEYS {
+        let Some(value) = env_var(&format!("SKOOTRS_{}", key.to_uppercase())) else {
+            continue;
+        };
+        let value = if key == "default_facets" {
+            Value::Sequence(
+                value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|facet| !facet.is_empty())
+                    .map(|facet| Value::String(facet.to_string()))
+                    .collect(),
+            )
+        } else {
+            Value::String(value)
+        };
+        layer.insert(Value::String(key.to_string()), value);
+    }
+    layer
+}
+
+/// Merges the options set in `layer` over the ones in `config`. Nested mappings are merged key by key,
+/// everything else is replaced.
+fn merge(config: &mut Value, layer: Value) {
+    match (config, layer) {
+        (Value::Mapping(config), Value::Mapping(layer)) => {
+            for (key, value) in layer {
+                match config.get_mut(&key) {
+                    Some(existing) => merge(existing, value),
+                    None => {
+                        config.insert(key, value);
+                    }
+                }
+            }
+        }
+        (config, layer) => *config = layer,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use skootrs_model::skootrs::{facet::SupportedFacetType, EcosystemScaffolding, LogFormat, StateStoreBackend};
+    use tempdir::TempDir;
+
+    use super::*;
+
+    fn write_config(dir: &TempDir, name: &str, content: &str) -> PathBuf {
+        let path = dir.path().join(name);
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    fn env_vars(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(name, value)| ((*name).to_string(), (*value).to_string()))
+            .collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn test_load_defaults() {
+        let dir = TempDir::new("config").unwrap();
+        let config = load_layers([dir.path().join("missing.yaml")], env_vars(&[])).unwrap();
+        assert_eq!(
+            serde_yaml::to_value(config).unwrap(),
+            serde_yaml::to_value(SkootrsConfig::default()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_load_precedence() {
+        let dir = TempDir::new("config").unwrap();
+        let user_config = write_config(
+            &dir,
+            "config.yaml",
+            "local_project_path: /home/me/src\n\
+             default_branch: develop\n\
+             default_organization: kusaridev\n\
+             guac_forwarding:\n  endpoint: https://guac.example.com\n  credentials_secret: USER_TOKEN\n",
+        );
+        let local_config = write_config(
+            &dir,
+            LOCAL_CONFIG_FILE,
+            "default_branch: trunk\n\
+             default_organization: skootrs\n\
+             guac_forwarding:\n  credentials_secret: LOCAL_TOKEN\n",
+        );
+
+        let config = load_layers(
+            [user_config, local_config],
+            env_vars(&[("SKOOTRS_DEFAULT_ORGANIZATION", "from-env")]),
+        )
+        .unwrap();
+        // Each layer only overrides the options it sets.
+        assert_eq!(config.local_project_path, "/home/me/src");
+        assert_eq!(config.default_branch, "trunk");
+        assert_eq!(config.default_organization, Some("from-env".to_string()));
+        let guac_forwarding = config.guac_forwarding.unwrap();
+        assert_eq!(guac_forwarding.endpoint, "https://guac.example.com");
+        assert_eq!(guac_forwarding.credentials_secret, "LOCAL_TOKEN");
+        assert_eq!(config.state_store_path, SkootrsConfig::default().state_store_path);
+    }
+
+    #[test]
+    fn test_load_env_values() {
+        let config = load_layers(
+            Vec::new(),
+            env_vars(&[
+                ("SKOOTRS_DEFAULT_FACETS", "Readme, License,"),
+                ("SKOOTRS_LOG_FORMAT", "pretty"),
+                ("SKOOTRS_ECOSYSTEM_SCAFFOLDING", "template"),
+                ("SKOOTRS_STATE_STORE", "jsondir"),
+            ]),
+        )
+        .unwrap();
+        assert_eq!(
+            config.default_facets,
+            Some(vec![SupportedFacetType::Readme, SupportedFacetType::License])
+        );
+        assert_eq!(config.log_format, LogFormat::Pretty);
+        assert_eq!(config.ecosystem_scaffolding, EcosystemScaffolding::Template);
+        assert_eq!(config.state_store, StateStoreBackend::JsonDir);
+    }
+
+    #[test]
+    fn test_load_invalid_config_file() {
+        let dir = TempDir::new("config").unwrap();
+        let user_config = write_config(&dir, "config.yaml", "log_format: [Pretty\n");
+        assert!(load_layers([user_config], env_vars(&[])).is_err());
+    }
+
+    #[test]
+    fn test_reference_cache_path() {
+        let mut config = SkootrsConfig::default();
+        let dirs = |name: &str| match name {
+            "HOME" => Some(OsString::from("/home/me")),
+            _ => None,
+        };
+        assert_eq!(reference_cache_path_from(&config, dirs), "/home/me/.cache/skootrs/.skootrscache");
+        let dirs = |name: &str| match name {
+            "XDG_CACHE_HOME" => Some(OsString::from("/cache")),
+            "HOME" => Some(OsString::from("/home/me")),
+            _ => None,
+        };
+        assert_eq!(reference_cache_path_from(&config, dirs), "/cache/skootrs/.skootrscache");
+        assert_eq!(reference_cache_path_from(&config, |_| None), ".skootrscache");
+
+        config.reference_cache_path = Some("/srv/skootrs/projects".to_string());
+        assert_eq!(reference_cache_path_from(&config, dirs), "/srv/skootrs/projects");
+    }
+}
@@ -1,24 +1,38 @@
-use base64::prelude::*;
-use inquire::Text;
+use inquire::{validator::Validation, Text};
 use octocrab::Page;
 use skootrs_lib::service::{
-    ecosystem::LocalEcosystemService,
-    facet::LocalFacetService,
-    project::{LocalProjectService, ProjectService},
-    repo::LocalRepoService,
+    ecosystem::validate_ecosystem_params,
+    facet::FacetSetParamsGenerator,
+    project::ProjectService,
+    repo::{LocalRepoService, RepoService},
     source::{LocalSourceService, SourceService},
 };
 use skootrs_model::{
     security_insights::insights10::SecurityInsightsVersion100YamlSchema,
     skootrs::{
-        EcosystemParams, GithubRepoParams, GithubUser, GoParams, InitializedProject, MavenParams,
-        ProjectParams, RepoParams, SkootError, SkootrsConfig, SourceParams, SUPPORTED_ECOSYSTEMS,
+        CargoCrateType, CargoParams, CodeReviewParams, EcosystemParams, GiteaRepoParams, GithubRepoParams, GithubUser, GitlabRepoParams, GoParams, GradleParams, InitializedProject,
+        InitializedRepo, LocalRepoParams, MavenParams, NpmParams, ProjectEcosystemParams, ProjectImportParams, ProjectParams, PythonParams, RepoParams, validate_ecosystem_paths,
+        RepoVisibility, SkootError, SkootrsConfig, SourceParams, SupportedLicense, SUPPORTED_ECOSYSTEMS, SUPPORTED_LICENSES,
+        SUPPORTED_REPO_HOSTS,
     },
 };
-use std::collections::HashMap;
+use std::{collections::HashMap, path::Path};
+use tempdir::TempDir;
 
-use skootrs_model::skootrs::facet::InitializedFacet;
-use skootrs_statestore::SurrealProjectStateStore;
+use skootrs_model::skootrs::facet::{InitializedFacet, SupportedFacetType};
+use skootrs_statestore::{
+    InRepoProjectStateStore, LocalProjectReferenceCache, ProjectStateStore, RemoteProjectStateStore,
+};
+use tracing::warn;
+
+use crate::config::reference_cache_path;
+
+/// The language choice for detecting the ecosystem of an imported repo from its manifests.
+const DETECT_ECOSYSTEM: &str = "Detect automatically";
+/// The Gradle version the wrapper of new Gradle projects is pinned to by default.
+const DEFAULT_GRADLE_VERSION: &str = "8.5";
+/// The SHA-256 checksum of the `DEFAULT_GRADLE_VERSION` binary distribution.
+const DEFAULT_GRADLE_DISTRIBUTION_SHA256_SUM: &str = "9d926787066a081739e8200858338b4a69e837c3a821a33aca9db09dd4a41026";
 
 pub struct Project;
 
@@ -26,17 +40,21 @@ impl Project {
     /// Returns `Ok(())` if the project creation is successful, otherwise returns an error.
     ///
     /// Creates a new skootrs project by prompting the user for repository details and language selection.
-    /// The project can be created for either Go or Maven ecosystems right now.
-    /// The project is created in Github, cloned down, and then initialized along with any other security supporting
-    /// tasks. If the project_params is not provided, the user will be prompted for the project details.
+    /// The project can be created for any of the supported ecosystems, with the ecosystem's own details prompted for.
+    /// The project is created in the repo host, cloned down, and then initialized along with any other security supporting
+    /// tasks. If the project_params is not provided, the user will be prompted for the project details, including the
+    /// repo's visibility, the license, and the facets to enable, before anything is created.
+    /// The state of the created project is committed in the `.skootrs` file of the project's repo along with
+    /// its initial source and stored in `state_store`, and the project is added to the local reference cache.
     ///
     /// # Errors
     ///
     /// Returns an error if the user is not authenticated with Github, or if the project can't be created
     /// for any other reason.
-    pub async fn create<T: ProjectService>(
+    pub async fn create<T: ProjectService, S: ProjectStateStore + Sync>(
         config: &SkootrsConfig,
         project_service: T,
+        state_store: &S,
         project_params: Option<ProjectParams>,
     ) -> Result<(), SkootError> {
         let project_params = match project_params {
@@ -44,168 +62,617 @@ impl Project {
             None => Project::prompt_project(config).await?,
         };
 
-        project_service.initialize(project_params).await?;
+        // The project's state is committed to its repo as part of initializing it.
+        let initialized_project = project_service.initialize(project_params).await?;
+        state_store.create(initialized_project.clone()).await?;
+
+        let mut cache = LocalProjectReferenceCache::load_or_create(&reference_cache_path(config))?;
+        cache.add(initialized_project.repo.full_url());
+        cache.save()?;
         Ok(())
     }
 
-    async fn prompt_project(config: &SkootrsConfig) -> Result<ProjectParams, SkootError> {
-        let name = Text::new("The name of the repository").prompt()?;
-        let description = Text::new("The description of the repository").prompt()?;
-        let user = octocrab::instance().current().user().await?.login;
-        let Page { items, .. } = octocrab::instance()
-            .current()
-            .list_org_memberships_for_authenticated_user()
-            .send()
-            .await?;
-        let organization = inquire::Select::new(
-            "Select an organization",
-            items
-                .iter()
-                .map(|i| i.organization.login.as_str())
-                .chain(vec![user.as_str()])
-                .collect(),
-        )
-        .prompt()?;
-        let language = inquire::Select::new("Select a language", SUPPORTED_ECOSYSTEMS.to_vec());
+    /// Returns `Ok(())` if an existing repo is imported as a Skootrs project.
+    ///
+    /// The repo at `repo_url` is cloned into the local project path and checked for the facets Skootrs
+    /// manages. The detected and missing facets are printed out, the project's state is committed and pushed
+    /// to the repo in its `.skootrs` file and recorded in `state_store`, and the project is added to the local
+    /// reference cache. If the `repo_url` is not provided, the user will be prompted for it. The user is always
+    /// prompted for the repo's language, which can also be detected from the repo's manifests.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repo can't be cloned or the project's state can't be stored.
+    pub async fn import<T: ProjectService, S: ProjectStateStore + Sync>(
+        config: &SkootrsConfig,
+        project_service: T,
+        state_store: &S,
+        repo_url: Option<String>,
+    ) -> Result<(), SkootError> {
+        let repo_url = match repo_url {
+            Some(r) => r,
+            None => Text::new("The URL of the repository to import").prompt()?,
+        };
+        let repo = InitializedRepo::from_url(&repo_url, &config.forge_hosts)?;
+        // e.g. github.com/kusaridev and skootrs, or gitlab.com/kusaridev/security and skootrs
+        let full_url = repo.full_url();
+        let (module_host, name) = match &repo {
+            InitializedRepo::Local(l) => ("localhost", l.name.as_str()),
+            _ => full_url
+                .split_once("://")
+                .and_then(|(_, url)| url.rsplit_once('/'))
+                .ok_or_else(|| SkootError::from(format!("Invalid repo URL: {full_url}")))?,
+        };
+        let languages = [&[DETECT_ECOSYSTEM][..], &SUPPORTED_ECOSYSTEMS[..]].concat();
+        let language = inquire::Select::new("Select a language", languages);
+        let ecosystem_params = match language.prompt()? {
+            DETECT_ECOSYSTEM => None,
+            language => Some(Self::prompt_ecosystem_params(language, name, module_host)?),
+        };
+        let import_params = ProjectImportParams {
+            repo_url,
+            ecosystems: ecosystem_params.into_iter().map(ProjectEcosystemParams::from).collect(),
+            source_params: SourceParams {
+                parent_path: config.local_project_path.clone(),
+            },
+            forge_hosts: config.forge_hosts.clone(),
+        };
 
-        let gh_org = match organization {
-            x if x == user => GithubUser::User(x.to_string()),
-            x => GithubUser::Organization(x.to_string()),
+        let initialized_project = project_service.import(import_params).await?;
+        println!(
+            "Missing facets: {}",
+            serde_json::to_string_pretty(&initialized_project.missing_facets)?
+        );
+        let in_repo_store = InRepoProjectStateStore {
+            initialized_source: initialized_project.source.clone(),
+            source_service: LocalSourceService {
+                author: config.git_author.clone(),
+                branch: Some(config.default_branch.clone()),
+                forge_hosts: config.forge_hosts.clone(),
+            },
+        };
+        in_repo_store.create(&initialized_project)?;
+        // The project may have been imported before.
+        if state_store.select(initialized_project.repo.full_url()).await?.is_some() {
+            state_store.update(initialized_project.clone()).await?;
+        } else {
+            state_store.create(initialized_project.clone()).await?;
+        }
+
+        let mut cache = LocalProjectReferenceCache::load_or_create(&reference_cache_path(config))?;
+        cache.add(initialized_project.repo.full_url());
+        cache.save()?;
+        Ok(())
+    }
+
+    /// Returns `Ok(())` if the project state can be fetched from the project's repo and printed out.
+    ///
+    /// Fetches the `.skootrs` state file from the repo of the project at `repo_url`. If the `repo_url` is
+    /// not provided, the user will be prompted for it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the state file can't be fetched from the repo or can't be parsed.
+    pub async fn get(config: &SkootrsConfig, repo_url: Option<String>) -> Result<(), SkootError> {
+        let repo_url = match repo_url {
+            Some(r) => r,
+            None => Text::new("The URL of the project's repository").prompt()?,
+        };
+
+        let state_store = RemoteProjectStateStore {
+            repo_service: LocalRepoService {
+                branch: Some(config.default_branch.clone()),
+            },
+            forge_hosts: config.forge_hosts.clone(),
+        };
+        let project = state_store.select(repo_url).await?;
+        println!("{}", serde_json::to_string_pretty(&project)?);
+        Ok(())
+    }
+
+    /// Returns `Ok(())` if the facet files and ecosystems of the project could be checked for drift.
+    ///
+    /// Compares the hashes recorded in the project's `.skootrs` state with the facet files in the project's
+    /// local working copy, or in a fresh clone of the remote HEAD if `remote` is set, and the recorded
+    /// ecosystems with the manifests in it. The files that were changed or deleted, and the ecosystems that
+    /// no longer match their manifests, out of band of Skootrs are printed out. If the `repo_url` is not
+    /// provided, the user will be prompted for it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the project's state can't be fetched or the working copy to compare with can't
+    /// be found or read.
+    pub async fn drift<T: ProjectService>(
+        config: &SkootrsConfig,
+        project_service: T,
+        repo_url: Option<String>,
+        remote: bool,
+    ) -> Result<(), SkootError> {
+        let repo_url = match repo_url {
+            Some(r) => r,
+            None => Text::new("The URL of the project's repository").prompt()?,
         };
 
-        let repo_params = match language.prompt()? {
-            "Go" => RepoParams::Github(GithubRepoParams {
-                name: name.clone(),
-                description,
-                organization: gh_org,
-            }),
-            "Maven" => RepoParams::Github(GithubRepoParams {
-                name: name.clone(),
-                description,
-                organization: gh_org,
-            }),
+        let state_store = RemoteProjectStateStore {
+            repo_service: LocalRepoService {
+                branch: Some(config.default_branch.clone()),
+            },
+            forge_hosts: config.forge_hosts.clone(),
+        };
+        let project = state_store.select(repo_url).await?;
+
+        // The clone of the remote HEAD only needs to live as long as the comparison.
+        let temp_dir = TempDir::new("skootrs-drift")?;
+        let source = if remote {
+            let parent_path = temp_dir
+                .path()
+                .to_str()
+                .ok_or_else(|| SkootError::from("Failed to get path of temporary directory"))?;
+            LocalRepoService::default().clone_local(project.repo.clone(), parent_path.to_string())?
+        } else {
+            project.source.clone()
+        };
+        if !Path::new(&source.path).exists() {
+            return Err(format!(
+                "No working copy of {} found at {}. Try comparing with the remote instead.",
+                project.repo.full_url(),
+                source.path
+            )
+            .into());
+        }
+
+        let drift = serde_json::json!({
+            "files": project_service.drift(&project, &source)?,
+            "ecosystems": project_service.ecosystem_drift(&project, &source)?,
+        });
+        println!("{}", serde_json::to_string_pretty(&drift)?);
+        Ok(())
+    }
+
+    /// Returns `Ok(())` if the project is added to the local reference cache.
+    ///
+    /// The project's `.skootrs` state file is fetched first to make sure the repo is a Skootrs project.
+    /// If the `repo_url` is not provided, the user will be prompted for it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the project's state can't be fetched or the cache can't be saved.
+    pub async fn add(config: &SkootrsConfig, repo_url: Option<String>) -> Result<(), SkootError> {
+        let repo_url = match repo_url {
+            Some(r) => r,
+            None => Text::new("The URL of the project's repository").prompt()?,
+        };
+
+        let state_store = RemoteProjectStateStore {
+            repo_service: LocalRepoService {
+                branch: Some(config.default_branch.clone()),
+            },
+            forge_hosts: config.forge_hosts.clone(),
+        };
+        let project = state_store.select(repo_url).await?;
+
+        let mut cache = LocalProjectReferenceCache::load_or_create(&reference_cache_path(config))?;
+        cache.add(project.repo.full_url());
+        cache.save()?;
+        Ok(())
+    }
+
+    /// Returns `Ok(())` if the project is removed from the local reference cache.
+    ///
+    /// This only removes the reference to the project. The project and its repo are left untouched.
+    /// If the `repo_url` is not provided, the user will be prompted to select one of the known projects.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the project isn't in the cache or the cache can't be saved.
+    pub fn remove(config: &SkootrsConfig, repo_url: Option<String>) -> Result<(), SkootError> {
+        let mut cache = LocalProjectReferenceCache::load_or_create(&reference_cache_path(config))?;
+        let repo_url = match repo_url {
+            Some(r) => r,
+            None => inquire::Select::new("Select a project", cache.list()).prompt()?,
+        };
+
+        if !cache.remove(&repo_url) {
+            return Err(format!("{repo_url} is not a project known to the local Skootrs").into());
+        }
+        cache.save()?;
+        Ok(())
+    }
+
+    async fn prompt_project(config: &SkootrsConfig) -> Result<ProjectParams, SkootError> {
+        let name = Text::new("The name of the repository")
+            .with_validator(|name: &str| {
+                // These are the characters all of the supported repo hosts allow in a repo name.
+                let is_valid = !name.is_empty()
+                    && !name.starts_with('.')
+                    && name.chars().all(|c| c.is_ascii_alphanumeric() || "-._".contains(c));
+                if is_valid {
+                    Ok(Validation::Valid)
+                } else {
+                    Ok(Validation::Invalid(
+                        "The name can only contain letters, digits, '-', '.', and '_'".into(),
+                    ))
+                }
+            })
+            .prompt()?;
+        let description = Text::new("The description of the repository").prompt()?;
+        let repo_host = inquire::Select::new("Select a repository host", SUPPORTED_REPO_HOSTS.to_vec()).prompt()?;
+        let (repo_params, module_host) = match repo_host {
+            "Github" => Self::prompt_github_repo(name.clone(), description, Self::prompt_visibility()?, config).await?,
+            "Gitlab" => Self::prompt_gitlab_repo(name.clone(), description, Self::prompt_visibility()?, config)?,
+            "Gitea" => Self::prompt_gitea_repo(name.clone(), description, Self::prompt_visibility()?, config)?,
+            "Local" => Self::prompt_local_repo(name.clone(), config)?,
             _ => {
-                unreachable!("Unsupported language")
+                unreachable!("Unsupported repository host")
             }
         };
+        let ecosystems = Self::prompt_project_ecosystems(&name, &module_host)?;
+        let license = Self::prompt_license()?;
+        let facets = Self::prompt_facets(config)?;
+        let code_review = if facets.contains(&SupportedFacetType::CodeReview) {
+            Self::prompt_code_review()?
+        } else {
+            CodeReviewParams::default()
+        };
 
         Ok(ProjectParams {
-            name: name.clone(),
+            name,
             repo_params,
-            ecosystem_params: EcosystemParams::Go(GoParams {
-                name: name.clone(),
-                host: format!("github.com/{organization}"),
-            }),
+            ecosystems,
             source_params: SourceParams {
                 parent_path: config.local_project_path.clone(),
             },
+            facets: Some(facets),
+            license,
+            code_review,
+            guac_forwarding: config.guac_forwarding.clone(),
+            default_branch: config.default_branch.clone(),
         })
     }
-}
 
-/// Returns `Ok(())` if the project creation is successful, otherwise returns an error.
-///
-/// Creates a new skootrs project by prompting the user for repository details and language selection.
-/// The project can be created for either Go or Maven ecosystems right now.
-/// The project is created in Github, cloned down, and then initialized along with any other security supporting
-/// tasks.
-///
-/// # Errors
-///
-/// Returns an error if the user is not authenticated with Github, or if the project can't be created
-/// for any other reason.
-pub async fn create() -> std::result::Result<(), SkootError> {
-    let name = Text::new("The name of the repository").prompt()?;
-    let description = Text::new("The description of the repository").prompt()?;
-    let user = octocrab::instance().current().user().await?.login;
-    let Page { items, .. } = octocrab::instance()
-        .current()
-        .list_org_memberships_for_authenticated_user()
-        .send()
-        .await?;
-    let organization = inquire::Select::new(
-        "Select an organization",
-        items
+    /// Prompts for the ecosystems of a new project along with the directory each of them is in, e.g. a Go
+    /// backend in `backend` and a TypeScript frontend in `frontend`. At least one ecosystem is prompted for.
+    fn prompt_project_ecosystems(name: &str, module_host: &str) -> Result<Vec<ProjectEcosystemParams>, SkootError> {
+        let languages = SUPPORTED_ECOSYSTEMS.to_vec();
+        let mut ecosystems: Vec<ProjectEcosystemParams> = Vec::new();
+        loop {
+            let language = inquire::Select::new("Select a language", languages.clone()).prompt()?;
+            let ecosystem_params = Self::prompt_ecosystem_params(language, name, module_host)?;
+            ecosystems.push(Self::prompt_ecosystem_path(ecosystem_params, &ecosystems)?);
+            let add_another = inquire::Confirm::new("Add another ecosystem to the project?")
+                .with_default(false)
+                .prompt()?;
+            if !add_another {
+                return Ok(ecosystems);
+            }
+        }
+    }
+
+    /// Prompts for the directory of the project an ecosystem is in, relative to the root of the project. The
+    /// user is prompted again until the directory is valid and isn't the directory of another ecosystem.
+    fn prompt_ecosystem_path(
+        ecosystem_params: EcosystemParams,
+        ecosystems: &[ProjectEcosystemParams],
+    ) -> Result<ProjectEcosystemParams, SkootError> {
+        // The first ecosystem is usually the only one, so it defaults to the root of the project.
+        let default_path = if ecosystems.is_empty() { "./" } else { "" };
+        let mut project_ecosystem = ProjectEcosystemParams::from(ecosystem_params);
+        loop {
+            project_ecosystem.path = Text::new("The directory of the ecosystem in the project, e.g. frontend")
+                .with_default(default_path)
+                .prompt()?;
+            let mut project_ecosystems = ecosystems.to_vec();
+            project_ecosystems.push(project_ecosystem.clone());
+            match validate_ecosystem_paths(&project_ecosystems) {
+                Ok(()) => return Ok(project_ecosystem),
+                Err(error) => eprintln!("{error}, try again."),
+            }
+        }
+    }
+
+    /// Prompts for whether the repo is public or private.
+    fn prompt_visibility() -> Result<RepoVisibility, SkootError> {
+        let visibility =
+            inquire::Select::new("Select the visibility of the repository", vec!["Public", "Private"]).prompt()?;
+
+        Ok(match visibility {
+            "Private" => RepoVisibility::Private,
+            _ => RepoVisibility::Public,
+        })
+    }
+
+    /// Prompts for the license of the project.
+    fn prompt_license() -> Result<SupportedLicense, SkootError> {
+        let license = inquire::Select::new("Select a license", SUPPORTED_LICENSES.to_vec()).prompt()?;
+
+        Ok(match license {
+            "Apache-2.0" => SupportedLicense::Apache2,
+            "MIT" => SupportedLicense::Mit,
+            "BSD-3-Clause" => SupportedLicense::Bsd3Clause,
+            _ => {
+                unreachable!("Unsupported license")
+            }
+        })
+    }
+
+    /// Prompts for the facets to create the project with. The configured default facets are selected to
+    /// start with, or all of them if there aren't any configured.
+    fn prompt_facets(config: &SkootrsConfig) -> Result<Vec<SupportedFacetType>, SkootError> {
+        let facet_types = FacetSetParamsGenerator {}.default_facet_types();
+        let selected: Vec<usize> = facet_types
+            .iter()
+            .enumerate()
+            .filter(|(_, facet_type)| {
+                config
+                    .default_facets
+                    .as_ref()
+                    .is_none_or(|default_facets| default_facets.contains(facet_type))
+            })
+            .map(|(i, _)| i)
+            .collect();
+        let facets = inquire::MultiSelect::new("Select the facets to enable", facet_types)
+            .with_default(&selected)
+            .prompt()?;
+
+        Ok(facets)
+    }
+
+    /// Prompts for how changes to the project are reviewed, including the team that owns the project's code.
+    fn prompt_code_review() -> Result<CodeReviewParams, SkootError> {
+        let defaults = CodeReviewParams::default();
+        let owning_team = Text::new("The team that owns the project's code, e.g. kusaridev/security (optional)")
+            .prompt()?;
+        let required_approving_review_count =
+            inquire::CustomType::<u8>::new("The number of approving reviews changes need")
+                .with_default(defaults.required_approving_review_count)
+                .prompt()?;
+
+        Ok(CodeReviewParams {
+            required_approving_review_count,
+            owning_team: Some(owning_team).filter(|owning_team| !owning_team.is_empty()),
+            ..defaults
+        })
+    }
+
+    /// Prompts for the params of the `language` ecosystem, with defaults based on the project's name and the
+    /// host of its repo. The user is prompted again until the params are valid for the ecosystem.
+    fn prompt_ecosystem_params(language: &str, name: &str, module_host: &str) -> Result<EcosystemParams, SkootError> {
+        // e.g. kusaridev from github.com/kusaridev
+        let owner = module_host.split_once('/').map_or(module_host, |(_, owner)| owner);
+        loop {
+            let ecosystem_params = match language {
+                "Go" => EcosystemParams::Go(Self::prompt_go_params(name.to_string(), module_host)?),
+                "Maven" => EcosystemParams::Maven(Self::prompt_maven_params(owner, name)?),
+                "Gradle" => EcosystemParams::Gradle(Self::prompt_gradle_params(owner, name)?),
+                "Cargo" => EcosystemParams::Cargo(Self::prompt_cargo_params(name)?),
+                "Npm" => EcosystemParams::Npm(Self::prompt_npm_params(name)?),
+                "Python" => EcosystemParams::Python(Self::prompt_python_params(name)?),
+                _ => {
+                    unreachable!("Unsupported language")
+                }
+            };
+            match validate_ecosystem_params(&ecosystem_params) {
+                Ok(()) => return Ok(ecosystem_params),
+                Err(error) => eprintln!("{error}, try again."),
+            }
+        }
+    }
+
+    /// Prompts for the Github user or organization to create the repo in. Returns the repo params along
+    /// with the host to use for the project's Go module.
+    async fn prompt_github_repo(
+        name: String,
+        description: String,
+        visibility: RepoVisibility,
+        config: &SkootrsConfig,
+    ) -> Result<(RepoParams, String), SkootError> {
+        let user = octocrab::instance().current().user().await?.login;
+        let Page { items, .. } = octocrab::instance()
+            .current()
+            .list_org_memberships_for_authenticated_user()
+            .send()
+            .await?;
+        let organizations: Vec<&str> = items
             .iter()
             .map(|i| i.organization.login.as_str())
             .chain(vec![user.as_str()])
-            .collect(),
-    )
-    .prompt()?;
+            .collect();
+        // Start on the configured default organization if it is one the user can create repos in.
+        let starting_cursor = config
+            .default_organization
+            .as_deref()
+            .and_then(|default| organizations.iter().position(|o| *o == default))
+            .unwrap_or(0);
+        let organization = inquire::Select::new("Select an organization", organizations)
+            .with_starting_cursor(starting_cursor)
+            .prompt()?;
+
+        let gh_org = match organization {
+            x if x == user => GithubUser::User(x.to_string()),
+            x => GithubUser::Organization(x.to_string()),
+        };
 
-    let language = inquire::Select::new("Select a language", SUPPORTED_ECOSYSTEMS.to_vec());
+        let repo_params = RepoParams::Github(GithubRepoParams {
+            name,
+            description,
+            organization: gh_org,
+            visibility,
+        });
 
-    let gh_org = match organization {
-        x if x == user => GithubUser::User(x.to_string()),
-        x => GithubUser::Organization(x.to_string()),
-    };
+        Ok((repo_params, format!("github.com/{organization}")))
+    }
 
-    let initialized_project: InitializedProject = match language.prompt()? {
-        "Go" => {
-            // TODO: support more than just github
-            let go_params = GoParams {
-                name: name.clone(),
-                host: format!("github.com/{organization}"),
-            };
-            let project_params = ProjectParams {
-                name: name.clone(),
-                repo_params: RepoParams::Github(GithubRepoParams {
-                    name,
-                    description,
-                    organization: gh_org,
-                }),
-                ecosystem_params: EcosystemParams::Go(go_params),
-                source_params: SourceParams {
-                    parent_path: "/tmp".to_string(), // FIXME: This should be configurable
-                },
-            };
-            let local_project_service = LocalProjectService {
-                repo_service: LocalRepoService {},
-                ecosystem_service: LocalEcosystemService {},
-                source_service: LocalSourceService {},
-                facet_service: LocalFacetService {},
-            };
+    /// Prompts for the Gitlab instance and namespace to create the repo in. Returns the repo params along
+    /// with the host to use for the project's Go module.
+    fn prompt_gitlab_repo(
+        name: String,
+        description: String,
+        visibility: RepoVisibility,
+        config: &SkootrsConfig,
+    ) -> Result<(RepoParams, String), SkootError> {
+        let host_url = Text::new("The URL of the Gitlab instance")
+            .with_default(config.forge_base_url.as_deref().unwrap_or("https://gitlab.com"))
+            .prompt()?;
+        let mut namespace_prompt =
+            Text::new("The group, subgroup, or user to create the repository in (e.g. kusaridev/security)");
+        if let Some(default_organization) = &config.default_organization {
+            namespace_prompt = namespace_prompt.with_default(default_organization);
+        }
+        let namespace = namespace_prompt.prompt()?;
+        let gitlab_repo_params = GitlabRepoParams {
+            name,
+            description,
+            namespace: namespace.trim_matches('/').to_string(),
+            host_url,
+            visibility,
+        };
+        let module_host = format!(
+            "{}/{}",
+            gitlab_repo_params
+                .host_url()
+                .trim_start_matches("https://")
+                .trim_start_matches("http://"),
+            gitlab_repo_params.namespace
+        );
+
+        Ok((RepoParams::Gitlab(gitlab_repo_params), module_host))
+    }
 
-            local_project_service.initialize(project_params).await?
+    /// Prompts for the Gitea or Forgejo instance and owner to create the repo in. Returns the repo params
+    /// along with the host to use for the project's Go module.
+    fn prompt_gitea_repo(
+        name: String,
+        description: String,
+        visibility: RepoVisibility,
+        config: &SkootrsConfig,
+    ) -> Result<(RepoParams, String), SkootError> {
+        let mut host_url_prompt = Text::new("The URL of the Gitea instance").with_placeholder("https://codeberg.org");
+        if let Some(forge_base_url) = &config.forge_base_url {
+            host_url_prompt = host_url_prompt.with_default(forge_base_url);
         }
+        let host_url = host_url_prompt.prompt()?;
+        let mut owner_prompt = Text::new("The user or organization to create the repository under");
+        if let Some(default_organization) = &config.default_organization {
+            owner_prompt = owner_prompt.with_default(default_organization);
+        }
+        let owner = owner_prompt.prompt()?;
+        let gitea_repo_params = GiteaRepoParams {
+            name,
+            description,
+            owner,
+            host_url,
+            visibility,
+        };
+        let module_host = format!(
+            "{}/{}",
+            gitea_repo_params
+                .host_url()
+                .trim_start_matches("https://")
+                .trim_start_matches("http://"),
+            gitea_repo_params.owner
+        );
+
+        Ok((RepoParams::Gitea(gitea_repo_params), module_host))
+    }
 
-        "Maven" => {
-            let maven_params = MavenParams {
-                group_id: format!("com.{organization}.{name}"),
-                artifact_id: name.clone(),
-            };
+    /// Prompts for the host of a Go module, which makes up the module's path along with its name.
+    fn prompt_go_params(name: String, module_host: &str) -> Result<GoParams, SkootError> {
+        let host = Text::new("The host of the Go module (e.g. github.com/kusaridev)")
+            .with_default(module_host)
+            .prompt()?;
 
-            let project_params = ProjectParams {
-                name: name.clone(),
-                repo_params: RepoParams::Github(GithubRepoParams {
-                    name,
-                    description,
-                    organization: gh_org,
-                }),
-                ecosystem_params: EcosystemParams::Maven(maven_params),
-                source_params: SourceParams {
-                    parent_path: "/tmp".to_string(), // FIXME: This should be configurable
-                },
-            };
-            let local_project_service = LocalProjectService {
-                repo_service: LocalRepoService {},
-                ecosystem_service: LocalEcosystemService {},
-                source_service: LocalSourceService {},
-                facet_service: LocalFacetService {},
-            };
+        Ok(GoParams {
+            name,
+            host: host.trim_matches('/').to_string(),
+        })
+    }
 
-            local_project_service.initialize(project_params).await?
-        }
+    /// Prompts for the group and artifact IDs of a Maven project.
+    fn prompt_maven_params(owner: &str, name: &str) -> Result<MavenParams, SkootError> {
+        let group_id = Text::new("The group ID of the project")
+            .with_default(&format!("com.{}.{name}", owner.replace('/', ".")))
+            .prompt()?;
+        let artifact_id = Text::new("The artifact ID of the project").with_default(name).prompt()?;
+
+        Ok(MavenParams { group_id, artifact_id })
+    }
 
-        _ => {
-            unreachable!("Unsupported language")
+    /// Prompts for the name and group of a Gradle project along with the Gradle version its wrapper is pinned to.
+    fn prompt_gradle_params(owner: &str, name: &str) -> Result<GradleParams, SkootError> {
+        let name = Text::new("The name of the project").with_default(name).prompt()?;
+        let group = Text::new("The group of the project")
+            .with_default(&format!("com.{}", owner.replace('/', ".")))
+            .prompt()?;
+        let gradle_version = Text::new("The Gradle version to pin the wrapper to")
+            .with_default(DEFAULT_GRADLE_VERSION)
+            .prompt()?;
+        let mut checksum_prompt = Text::new("The SHA-256 checksum of the Gradle distribution").with_help_message(
+            "Published at https://services.gradle.org/distributions/gradle-<version>-bin.zip.sha256",
+        );
+        if gradle_version == DEFAULT_GRADLE_VERSION {
+            checksum_prompt = checksum_prompt.with_default(DEFAULT_GRADLE_DISTRIBUTION_SHA256_SUM);
         }
-    };
+        let distribution_sha256_sum = checksum_prompt.prompt()?;
 
-    let state_store = SurrealProjectStateStore::new().await?;
-    state_store.create(initialized_project).await?;
+        Ok(GradleParams {
+            group,
+            name,
+            gradle_version,
+            distribution_sha256_sum,
+        })
+    }
 
-    Ok(())
+    /// Prompts for the name of a Rust crate, whether it is a binary or library, and its edition.
+    fn prompt_cargo_params(name: &str) -> Result<CargoParams, SkootError> {
+        let name = Text::new("The name of the crate").with_default(name).prompt()?;
+        let crate_type = inquire::Select::new("Select a crate type", vec!["Binary", "Library"]).prompt()?;
+        let edition = Text::new("The Rust edition of the crate").with_default("2021").prompt()?;
+
+        Ok(CargoParams {
+            name,
+            crate_type: match crate_type {
+                "Library" => CargoCrateType::Library,
+                _ => CargoCrateType::Binary,
+            },
+            edition,
+        })
+    }
+
+    /// Prompts for the name and optional scope of an npm package along with whether it is written in TypeScript.
+    fn prompt_npm_params(name: &str) -> Result<NpmParams, SkootError> {
+        let name = Text::new("The name of the package").with_default(&name.to_lowercase()).prompt()?;
+        let scope = Text::new("The scope of the package, if it has one (e.g. kusaridev)").prompt()?;
+        let typescript = inquire::Confirm::new("Is the package written in TypeScript?")
+            .with_default(true)
+            .prompt()?;
+
+        Ok(NpmParams {
+            name,
+            scope: Some(scope.trim().trim_start_matches('@').to_string()).filter(|s| !s.is_empty()),
+            typescript,
+        })
+    }
+
+    /// Prompts for the name of a Python package along with the minimum Python version it supports.
+    fn prompt_python_params(name: &str) -> Result<PythonParams, SkootError> {
+        let name = Text::new("The name of the package").with_default(name).prompt()?;
+        let python_version = Text::new("The minimum Python version the package supports")
+            .with_default("3.9")
+            .prompt()?;
+
+        Ok(PythonParams { name, python_version })
+    }
+
+    /// Prompts for the directory to create the local bare repo in. Returns the repo params along with the
+    /// host to use for the project's Go module.
+    fn prompt_local_repo(name: String, config: &SkootrsConfig) -> Result<(RepoParams, String), SkootError> {
+        let parent_path = Text::new("The directory to create the bare repository in")
+            .with_default(&format!("{}/remotes", config.local_project_path))
+            .prompt()?;
+        let local_repo_params = LocalRepoParams { name, parent_path };
+
+        Ok((RepoParams::Local(local_repo_params), "localhost".to_string()))
+    }
 }
 
 /// Returns `Ok(())` if the able to print out the content of the facet, otherwise returns an error.
@@ -217,8 +684,8 @@ pub async fn create() -> std::result::Result<(), SkootError> {
 ///
 /// Returns an error if the state store is not able to be accessed or if the selected project or facet
 /// is not found.
-pub async fn get_facet() -> std::result::Result<(), SkootError> {
-    let project = prompt_project().await?;
+pub async fn get_facet(config: &SkootrsConfig) -> std::result::Result<(), SkootError> {
+    let project = prompt_project(config).await?;
 
     let facet_to_project: HashMap<String, InitializedFacet> = project
         .facets
@@ -256,21 +723,36 @@ pub async fn get_facet() -> std::result::Result<(), SkootError> {
     Ok(())
 }
 
-/// Returns `Ok(())` if the able to print out a dump of the statestore.
+/// Returns `Ok(())` if the able to print out a dump of the projects known to the local Skootrs.
 ///
-/// This function prints out the content of the state store in a pretty printed JSON format.
+/// This function prints out the state of every project in the local reference cache in a pretty printed
+/// JSON format. The state of each project is fetched from its repo, and projects whose state can't be fetched
+/// are skipped with a warning.
 /// # Errors
 ///
-/// Returns an error if the state store is not able to be accessed.
-pub async fn dump() -> std::result::Result<(), SkootError> {
-    let projects = get_all().await?;
+/// Returns an error if the reference cache is not able to be accessed.
+pub async fn dump(config: &SkootrsConfig) -> std::result::Result<(), SkootError> {
+    let projects = get_all(config).await?;
     println!("{}", serde_json::to_string_pretty(&projects)?);
     Ok(())
 }
 
-async fn get_all() -> std::result::Result<Vec<InitializedProject>, SkootError> {
-    let state_store = SurrealProjectStateStore::new().await?;
-    let projects = state_store.select_all().await?;
+async fn get_all(config: &SkootrsConfig) -> std::result::Result<Vec<InitializedProject>, SkootError> {
+    let cache = LocalProjectReferenceCache::load_or_create(&reference_cache_path(config))?;
+    let state_store = RemoteProjectStateStore {
+        repo_service: LocalRepoService {
+            branch: Some(config.default_branch.clone()),
+        },
+        forge_hosts: config.forge_hosts.clone(),
+    };
+    let mut projects = Vec::new();
+    for repo_url in cache.list() {
+        // A project whose repo is gone or unreachable shouldn't hide the rest of the known projects.
+        match state_store.select(repo_url.clone()).await {
+            Ok(project) => projects.push(project),
+            Err(error) => warn!("Skipping {repo_url} since its state can't be fetched: {error}"),
+        }
+    }
     Ok(projects)
 }
 
@@ -280,12 +762,12 @@ fn get_facet_content(
 ) -> std::result::Result<String, SkootError> {
     match facet {
         InitializedFacet::SourceFile(f) => {
-            let source_service = LocalSourceService {};
+            let source_service = LocalSourceService::default();
             let content = source_service.read_file(&project.source, &f.path, f.name.clone())?;
             Ok(content)
         }
         InitializedFacet::SourceBundle(f) => {
-            let source_service = LocalSourceService {};
+            let source_service = LocalSourceService::default();
             let content = f
                 .source_files
                 .iter()
@@ -308,33 +790,17 @@ fn get_facet_content(
 /// This return an error if it can't get an intended output for some reason. This
 /// includes issues like unable to get or parse SECURITY-INSIGHTS.yml or unable
 /// to get the intended output.
-pub async fn get_output() -> std::result::Result<(), SkootError> {
-    let project = prompt_project().await?;
-
-    let skootrs_model::skootrs::InitializedRepo::Github(repo) = &project.repo;
+pub async fn get_output(config: &SkootrsConfig) -> std::result::Result<(), SkootError> {
+    let project = prompt_project(config).await?;
 
-    let sec_ins_content_items = octocrab::instance()
-        .repos(repo.organization.get_name(), &repo.name)
-        .get_content()
-        .path("SECURITY-INSIGHTS.yml")
-        .r#ref("main")
-        .send()
+    let repo_service = LocalRepoService {
+        branch: Some(config.default_branch.clone()),
+    };
+    let content_str = repo_service
+        .fetch_file_content(&project.repo, "SECURITY-INSIGHTS.yml")
         .await?;
-
-    let sec_ins = sec_ins_content_items
-        .items
-        .first()
-        .ok_or_else(|| SkootError::from("Failed to get security insights"))?;
-
-    let content = sec_ins
-        .content
-        .as_ref()
-        .ok_or_else(|| SkootError::from("Failed to get content of  security insights"))?;
-    let content_decoded =
-        base64::engine::general_purpose::STANDARD.decode(content.replace('\n', ""))?;
-    let content_str = std::str::from_utf8(&content_decoded)?;
     let insights: SecurityInsightsVersion100YamlSchema =
-        serde_yaml::from_str::<SecurityInsightsVersion100YamlSchema>(content_str)?;
+        serde_yaml::from_str::<SecurityInsightsVersion100YamlSchema>(&content_str)?;
     let sbom_vec = insights
         .dependencies
         .ok_or_else(|| SkootError::from("Failed to get dependencies value from security insights"))?
@@ -354,8 +820,8 @@ pub async fn get_output() -> std::result::Result<(), SkootError> {
     Ok(())
 }
 
-async fn prompt_project() -> Result<InitializedProject, SkootError> {
-    let projects = get_all().await?;
+async fn prompt_project(config: &SkootrsConfig) -> Result<InitializedProject, SkootError> {
+    let projects = get_all(config).await?;
     let repo_to_project: HashMap<String, &InitializedProject> = projects
         .iter()
         .map(|p| (p.repo.full_url(), p))
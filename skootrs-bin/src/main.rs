@@ -26,6 +26,7 @@
 //! giving an interactive prompt to the user to fill in the required
 //! information.
 
+pub mod config;
 pub mod helpers;
 
 use clap::{Parser, Subcommand};
@@ -34,7 +35,8 @@ use skootrs_lib::service::facet::LocalFacetService;
 use skootrs_lib::service::project::LocalProjectService;
 use skootrs_lib::service::repo::LocalRepoService;
 use skootrs_lib::service::source::LocalSourceService;
-use skootrs_model::skootrs::SkootError;
+use skootrs_model::skootrs::{LogFormat, SkootError, SkootrsConfig};
+use skootrs_statestore::ConfiguredProjectStateStore;
 use clio::Input;
 
 use helpers::{dump, get_facet, get_output};
@@ -84,6 +86,13 @@ enum SkootrsCli {
         #[clap(subcommand)]
         daemon: DaemonCommands,
     },
+
+    /// Config commands.
+    #[command(name = "config")]
+    Config {
+        #[clap(subcommand)]
+        config: ConfigCommands,
+    },
 }
 
 /// This is the enum for what nouns the `project` command can take.
@@ -97,12 +106,44 @@ enum ProjectCommands {
         #[clap(value_parser)]
         input: Option<Input>,
     },
+    /// Import an existing repository that wasn't created by Skootrs as a project. The project's state is
+    /// committed and pushed to the repository in a `.skootrs` file.
+    #[command(name = "import")]
+    Import {
+        /// The URL of the repository to import. If it is not provided, the CLI will prompt the user for it.
+        repo_url: Option<String>,
+    },
     /// Get the metadata for a particular project.
     #[command(name = "get")]
-    Get,
+    Get {
+        /// The URL of the project's repository. The project's state is fetched from the `.skootrs` file
+        /// in the repository. If it is not provided, the CLI will prompt the user for it.
+        repo_url: Option<String>,
+    },
     /// List all the projects known to the local Skootrs
     #[command(name = "list")]
     List,
+    /// Check the facet files and ecosystems of a project for changes made out of band of Skootrs.
+    #[command(name = "drift")]
+    Drift {
+        /// The URL of the project's repository. If it is not provided, the CLI will prompt the user for it.
+        repo_url: Option<String>,
+        /// Compare with the remote HEAD instead of the local working copy of the project.
+        #[clap(long)]
+        remote: bool,
+    },
+    /// Add an existing Skootrs project to the projects known to the local Skootrs.
+    #[command(name = "add")]
+    Add {
+        /// The URL of the project's repository. If it is not provided, the CLI will prompt the user for it.
+        repo_url: Option<String>,
+    },
+    /// Remove a project from the projects known to the local Skootrs. This doesn't touch the project itself.
+    #[command(name = "remove")]
+    Remove {
+        /// The URL of the project's repository. If it is not provided, the CLI will prompt the user for it.
+        repo_url: Option<String>,
+    },
 }
 
 /// This is the enum for what nouns the `facet` command can take.
@@ -135,6 +176,14 @@ enum DaemonCommands {
     Start,
 }
 
+/// This is the enum for what nouns the `config` command can take.
+#[derive(Subcommand, Debug)]
+enum ConfigCommands {
+    /// Show the config Skootrs is using after layering the config files and environment variables.
+    #[command(name = "show")]
+    Show,
+}
+
 /*enum SkootrsCli {
     /// Create a new project.
     #[command(name = "create")]
@@ -162,7 +211,7 @@ enum GetCommands {
     Output
 }*/
 
-fn init_tracing() {
+fn init_tracing(log_format: &LogFormat) {
     let app_name = "skootrs";
 
     // Start a new Jaeger trace pipeline.
@@ -178,29 +227,38 @@ fn init_tracing() {
     let env_filter = EnvFilter::try_from_default_env().unwrap_or(EnvFilter::new("info"));
     // Create a `tracing` layer using the Jaeger tracer
     let telemetry = tracing_opentelemetry::layer().with_tracer(tracer);
-    // Create a `tracing` layer to emit spans as structured logs to stdout
-    let formatting_layer = BunyanFormattingLayer::new(app_name.into(), std::io::stdout);
-    // Combined them all together in a `tracing` subscriber
-    let subscriber = Registry::default()
-        .with(env_filter)
-        .with(telemetry)
-        .with(JsonStorageLayer)
-        .with(formatting_layer);
-    tracing::subscriber::set_global_default(subscriber)
-        .expect("Failed to install `tracing` subscriber.");
+    let registry = Registry::default().with(env_filter).with(telemetry);
+    // Combined them all together in a `tracing` subscriber that emits logs to stdout in the configured format
+    let result = match log_format {
+        LogFormat::Bunyan => {
+            let formatting_layer = BunyanFormattingLayer::new(app_name.into(), std::io::stdout);
+            tracing::subscriber::set_global_default(registry.with(JsonStorageLayer).with(formatting_layer))
+        }
+        LogFormat::Pretty => {
+            tracing::subscriber::set_global_default(registry.with(tracing_subscriber::fmt::layer()))
+        }
+    };
+    result.expect("Failed to install `tracing` subscriber.");
 }
 
-/// TODO: This probably should be configurable in some way.
-fn init_project_service() -> LocalProjectService<
+fn init_project_service(config: &SkootrsConfig) -> LocalProjectService<
     LocalRepoService,
     LocalEcosystemService,
     LocalSourceService,
     LocalFacetService,
 > {
     let project_service = LocalProjectService {
-        repo_service: LocalRepoService {},
-        ecosystem_service: LocalEcosystemService {},
-        source_service: LocalSourceService {},
+        repo_service: LocalRepoService {
+            branch: Some(config.default_branch.clone()),
+        },
+        ecosystem_service: LocalEcosystemService {
+            scaffolding: config.ecosystem_scaffolding.clone(),
+        },
+        source_service: LocalSourceService {
+            author: config.git_author.clone(),
+            branch: Some(config.default_branch.clone()),
+            forge_hosts: config.forge_hosts.clone(),
+        },
         facet_service: LocalFacetService {},
     };
     project_service
@@ -219,50 +277,73 @@ fn parse_optional_input<T: DeserializeOwned>(input: Option<Input>) -> Result<Opt
 }
 
 #[tokio::main]
+#[allow(clippy::too_many_lines)]
 async fn main() -> std::result::Result<(), SkootError> {
-    init_tracing();
+    let config = config::load()?;
+    init_tracing(&config.log_format);
     let cli = SkootrsCli::parse();
-    let o: octocrab::Octocrab = octocrab::Octocrab::builder()
-        .personal_token(
-            std::env::var("GITHUB_TOKEN").expect("GITHUB_TOKEN env var must be populated"),
-        )
-        .build()?;
-    octocrab::initialise(o);
+    // The token is only needed for Github projects so things like local repos can be used without it.
+    if let Ok(token) = std::env::var("GITHUB_TOKEN") {
+        let o: octocrab::Octocrab = octocrab::Octocrab::builder()
+            .personal_token(token)
+            .build()?;
+        octocrab::initialise(o);
+    }
 
-    let project_service = init_project_service();
-    // TODO: This should only default when it can't pull a valid config from the environment.
-    let config = skootrs_model::skootrs::SkootrsConfig::default();
+    let project_service = init_project_service(&config);
 
     match cli {
         SkootrsCli::Project { project } => {
             match project {
                 ProjectCommands::Create { input } => {
                     let project_params = parse_optional_input(input)?;
-                    if let Err(ref error) = helpers::Project::create(&config, project_service, project_params).await {
+                    let store = ConfiguredProjectStateStore::open(&config).await?;
+                    if let Err(ref error) = helpers::Project::create(&config, project_service, &store, project_params).await {
                         error!(error = error.as_ref(), "Failed to create project");
                     }
                 }
-                ProjectCommands::Get => {
-                    if let Err(ref error) = dump().await {
+                ProjectCommands::Import { repo_url } => {
+                    let store = ConfiguredProjectStateStore::open(&config).await?;
+                    if let Err(ref error) = helpers::Project::import(&config, project_service, &store, repo_url).await {
+                        error!(error = error.as_ref(), "Failed to import project");
+                    }
+                }
+                ProjectCommands::Get { repo_url } => {
+                    if let Err(ref error) = helpers::Project::get(&config, repo_url).await {
                         error!(error = error.as_ref(), "Failed to get project info");
                     }
                 }
                 ProjectCommands::List => {
-                    if let Err(ref error) = dump().await {
+                    if let Err(ref error) = dump(&config).await {
                         error!(error = error.as_ref(), "Failed to list projects");
                     }
                 }
+                ProjectCommands::Drift { repo_url, remote } => {
+                    if let Err(ref error) = helpers::Project::drift(&config, project_service, repo_url, remote).await {
+                        error!(error = error.as_ref(), "Failed to check project for drift");
+                    }
+                }
+                ProjectCommands::Add { repo_url } => {
+                    if let Err(ref error) = helpers::Project::add(&config, repo_url).await {
+                        error!(error = error.as_ref(), "Failed to add project");
+                    }
+                }
+                ProjectCommands::Remove { repo_url } => {
+                    if let Err(ref error) = helpers::Project::remove(&config, repo_url) {
+                        error!(error = error.as_ref(), "Failed to remove project");
+                    }
+                }
             }
         }
         SkootrsCli::Facet { facet } => {
             match facet {
                 FacetCommands::Get => {
-                    if let Err(ref error) = get_facet().await {
+                    if let Err(ref error) = get_facet(&config).await {
                         error!(error = error.as_ref(), "Failed to get facet");
                     }
                 }
                 FacetCommands::List => {
-                    if let Err(ref error) = get_facet().await {
+                    if let Err(ref error) = get_facet(&config).await {
                         error!(error = error.as_ref(), "Failed to list facets for project");
                     }
                 }
@@ -271,12 +352,12 @@ async fn main() -> std::result::Result<(), SkootError> {
         SkootrsCli::Output { output } => {
             match output {
                 OutputCommands::Get => {
-                    if let Err(ref error) = get_output().await {
+                    if let Err(ref error) = get_output(&config).await {
                         error!(error = error.as_ref(), "Failed to get output");
                     }
                 }
                 OutputCommands::List => {
-                    if let Err(ref error) = get_output().await {
+                    if let Err(ref error) = get_output(&config).await {
                         error!(error = error.as_ref(), "Failed to list outputs for project");
                     }
                 }
@@ -285,14 +366,22 @@ async fn main() -> std::result::Result<(), SkootError> {
         SkootrsCli::Daemon { daemon } => {
             match daemon {
                 DaemonCommands::Start => {
-                    tokio::task::spawn_blocking(|| {
-                        skootrs_rest::server::rest::run_server().expect("Failed to start REST Server");
+                    let store = ConfiguredProjectStateStore::open(&config).await?;
+                    tokio::task::spawn_blocking(move || {
+                        skootrs_rest::server::rest::run_server(store, config).expect("Failed to start REST Server");
                     })
                     .await
                     .expect("REST Server Task Panicked");
                 }
             }
         }
+        SkootrsCli::Config { config: config_command } => {
+            match config_command {
+                ConfigCommands::Show => {
+                    print!("{}", serde_yaml::to_string(&config)?);
+                }
+            }
+        }
     }
 
     Ok(())
@@ -1,19 +1,25 @@
 #![allow(clippy::module_name_repetitions)]
 
-use std::process::Command;
+use std::{fs, path::Path, process::Command};
 
-use tracing::info;
+use askama::Template;
+use serde_json::json;
+use toml_edit::Document;
+use tracing::{info, warn};
+
+use super::source::{is_not_found, SourceService};
 
 use skootrs_model::skootrs::{
-    EcosystemParams, GoParams, InitializedEcosystem, InitializedGo, InitializedMaven,
-    InitializedSource, MavenParams, SkootError,
+    CargoCrateType, CargoParams, EcosystemDrift, EcosystemParams, EcosystemScaffolding, GoParams, GradleParams, InitializedCargo,
+    InitializedEcosystem, InitializedGo, InitializedGradle, InitializedMaven, InitializedNpm, InitializedProjectEcosystem, InitializedPython, InitializedSource, MavenParams, NpmParams, PythonParams, SkootError, SupportedLicense,
 };
 
 /// The `EcosystemService` trait provides an interface for initializing and managing a project's ecosystem.
 /// An ecosystem is the language or packaging ecosystem that a project is built in, such as Maven or Go.
 pub trait EcosystemService {
     /// Initializes a project's ecosystem. This involves setting up the project's package or build system.
-    /// For example `go mod init` for Go.
+    /// For example `go mod init` for Go. Manifests that declare a license, like npm's `package.json`, declare
+    /// the project's `license`.
     ///
     /// # Errors
     ///
@@ -22,39 +28,109 @@ pub trait EcosystemService {
         &self,
         params: EcosystemParams,
         source: InitializedSource,
+        license: &SupportedLicense,
     ) -> Result<InitializedEcosystem, SkootError>;
 }
 
 /// The `LocalEcosystemService` struct provides an implementation of the `EcosystemService` trait for initializing 
 /// and managing a project's ecosystem on the local machine.
-#[derive(Debug)]
-pub struct LocalEcosystemService {}
+#[derive(Debug, Default)]
+pub struct LocalEcosystemService {
+    /// Whether ecosystems are scaffolded with their own tools, or rendered from templates.
+    pub scaffolding: EcosystemScaffolding,
+}
 
 impl EcosystemService for LocalEcosystemService {
     fn initialize(
         &self,
         params: EcosystemParams,
         source: InitializedSource,
+        license: &SupportedLicense,
     ) -> Result<InitializedEcosystem, SkootError> {
+        // Ecosystems in a subdirectory of a project's source are initialized in a directory that doesn't exist yet.
+        fs::create_dir_all(&source.path)?;
         match params {
             EcosystemParams::Maven(m) => {
-                LocalMavenEcosystemHandler::initialize(&source.path, &m)?;
+                match self.scaffolding {
+                    EcosystemScaffolding::Toolchain => LocalMavenEcosystemHandler::initialize(&source.path, &m)?,
+                    EcosystemScaffolding::Template => LocalMavenEcosystemHandler::render(&source.path, &m)?,
+                }
                 Ok(InitializedEcosystem::Maven(InitializedMaven {
                     group_id: m.group_id,
                     artifact_id: m.artifact_id,
                 }))
             }
+            EcosystemParams::Gradle(g) => {
+                match self.scaffolding {
+                    EcosystemScaffolding::Toolchain => LocalGradleEcosystemHandler::initialize(&source.path, &g)?,
+                    EcosystemScaffolding::Template => LocalGradleEcosystemHandler::render(&source.path, &g)?,
+                }
+                Ok(InitializedEcosystem::Gradle(InitializedGradle {
+                    group: g.group,
+                    name: g.name,
+                    gradle_version: g.gradle_version,
+                    distribution_sha256_sum: g.distribution_sha256_sum,
+                }))
+            }
             EcosystemParams::Go(g) => {
-                LocalGoEcosystemHandler::initialize(&source.path, &g)?;
+                match self.scaffolding {
+                    EcosystemScaffolding::Toolchain => LocalGoEcosystemHandler::initialize(&source.path, &g)?,
+                    EcosystemScaffolding::Template => LocalGoEcosystemHandler::render(&source.path, &g)?,
+                }
                 Ok(InitializedEcosystem::Go(InitializedGo {
                     name: g.name,
                     host: g.host,
+                    major_version: None,
+                }))
+            }
+            EcosystemParams::Cargo(c) => {
+                match self.scaffolding {
+                    EcosystemScaffolding::Toolchain => LocalCargoEcosystemHandler::initialize(&source.path, &c)?,
+                    EcosystemScaffolding::Template => LocalCargoEcosystemHandler::render(&source.path, &c)?,
+                }
+                Ok(InitializedEcosystem::Cargo(InitializedCargo {
+                    name: c.name,
+                    crate_type: c.crate_type,
+                    edition: c.edition,
+                }))
+            }
+            EcosystemParams::Npm(n) => {
+                LocalNpmEcosystemHandler::initialize(&source.path, &n, license)?;
+                Ok(InitializedEcosystem::Npm(InitializedNpm {
+                    name: n.name,
+                    scope: n.scope,
+                    typescript: n.typescript,
+                }))
+            }
+            EcosystemParams::Python(p) => {
+                LocalPythonEcosystemHandler::initialize(&source.path, &p)?;
+                Ok(InitializedEcosystem::Python(InitializedPython {
+                    name: p.name,
+                    python_version: p.python_version,
                 }))
             }
         }
     }
 }
 
+/// Returns an error if the params of an ecosystem aren't valid for its package or build system.
+///
+/// e.g. a Maven group ID that isn't a valid Java package. This lets the params be checked before anything
+/// is created for a project.
+///
+/// # Errors
+///
+/// Returns an error describing the first param that isn't valid.
+pub fn validate_ecosystem_params(params: &EcosystemParams) -> Result<(), SkootError> {
+    match params {
+        EcosystemParams::Maven(m) => LocalMavenEcosystemHandler::validate(m),
+        EcosystemParams::Gradle(g) => LocalGradleEcosystemHandler::validate(g),
+        EcosystemParams::Go(g) => LocalGoEcosystemHandler::validate(g),
+        EcosystemParams::Cargo(c) => LocalCargoEcosystemHandler::validate(c),
+        EcosystemParams::Npm(n) => LocalNpmEcosystemHandler::validate(n),
+        EcosystemParams::Python(p) => LocalPythonEcosystemHandler::validate(p),
+    }
+}
 
 /// The `LocalMavenEcosystemHandler` struct represents a handler for initializing and managing a Maven 
 /// project on the local machine.
@@ -64,6 +140,7 @@ impl LocalMavenEcosystemHandler {
     /// Returns `Ok(())` if the Maven project initialization is successful,
     /// otherwise returns an error.
     fn initialize(path: &str, params: &MavenParams) -> Result<(), SkootError> {
+        Self::validate(params)?;
         let output = Command::new("mvn")
             .arg("archetype:generate")
             .arg(format!("-DgroupId={}", params.group_id))
@@ -72,15 +149,176 @@ impl LocalMavenEcosystemHandler {
             .arg("-DinteractiveMode=false")
             .current_dir(path)
             .output()?;
+        if !output.status.success() {
+            return Err(Box::new(std::io::Error::other("Failed to run mvn generate")));
+        }
+
+        // The archetype generates the project in a directory named after the artifact ID, so it's moved up
+        // to the root of the repo where the rest of the project's files, like the workflows, expect it.
+        let generated_path = Path::new(path).join(&params.artifact_id);
+        for entry in fs::read_dir(&generated_path)? {
+            let entry = entry?;
+            fs::rename(entry.path(), Path::new(path).join(entry.file_name()))?;
+        }
+        fs::remove_dir(generated_path)?;
+
+        info!("Initialized maven project for {}", params.artifact_id);
+        Ok(())
+    }
+
+    /// Returns an error if the group or artifact ID of the project aren't valid, or if the `pom.xml` of
+    /// the project can't be written at the specified path.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path where the Maven project should be rendered.
+    fn render(path: &str, params: &MavenParams) -> Result<(), SkootError> {
+        #[derive(Template)]
+        #[template(path = "maven.pom.xml", escape = "none")]
+        struct PomTemplateParams<'a> {
+            group_id: &'a str,
+            artifact_id: &'a str,
+        }
+
+        Self::validate(params)?;
+
+        let pom_template_params = PomTemplateParams {
+            group_id: &params.group_id,
+            artifact_id: &params.artifact_id,
+        };
+        fs::write(
+            Path::new(path).join("pom.xml"),
+            pom_template_params.render()? + "\n",
+        )?;
+
+        info!("Rendered maven project for {}", params.artifact_id);
+        Ok(())
+    }
+
+    /// Returns an error if the group or artifact ID of the project aren't valid.
+    fn validate(params: &MavenParams) -> Result<(), SkootError> {
+        // The group ID is the Java package of the project, so it has to be valid as one.
+        if !params.group_id.split('.').all(is_java_identifier) {
+            return Err(format!("Invalid Maven group ID: {}", params.group_id).into());
+        }
+        let is_valid_artifact_id = !params.artifact_id.is_empty()
+            && params
+                .artifact_id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "-._".contains(c));
+        if !is_valid_artifact_id {
+            return Err(format!("Invalid Maven artifact ID: {}", params.artifact_id).into());
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns whether `identifier` is valid as a part of a Java package name.
+fn is_java_identifier(identifier: &str) -> bool {
+    identifier.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && identifier.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// The `LocalGradleEcosystemHandler` struct represents a handler for initializing and managing a Gradle
+/// project on the local machine. The build scripts are written directly, and `gradle` is only used to
+/// generate the wrapper.
+struct LocalGradleEcosystemHandler {}
+
+impl LocalGradleEcosystemHandler {
+    /// Returns an error if the group or name of the project aren't valid, or if the build scripts and
+    /// wrapper of the project can't be created at the specified path.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path where the Gradle project should be initialized.
+    fn initialize(path: &str, params: &GradleParams) -> Result<(), SkootError> {
+        Self::write_build_scripts(path, params)?;
+
+        // The wrapper pins the Gradle version, and verifies the checksum of the distribution it downloads.
+        let output = Command::new("gradle")
+            .arg("wrapper")
+            .arg("--gradle-version")
+            .arg(&params.gradle_version)
+            .arg("--distribution-type")
+            .arg("bin")
+            .arg("--gradle-distribution-sha256-sum")
+            .arg(&params.distribution_sha256_sum)
+            .current_dir(path)
+            .output()?;
         if output.status.success() {
-            info!("Initialized maven project for {}", params.artifact_id);
+            info!("Initialized Gradle project for {}", params.name);
             Ok(())
         } else {
-            Err(Box::new(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                "Failed to run mvn generate",
-            )))
+            Err(Box::new(std::io::Error::other("Failed to run gradle wrapper")))
+        }
+    }
+
+    /// Returns an error if the group or name of the project aren't valid, or if the build scripts of the
+    /// project can't be written at the specified path.
+    ///
+    /// The wrapper jar and scripts are binaries published by Gradle, so they can't be rendered. `gradle wrapper`
+    /// has to be run with a Gradle toolchain before the project's release workflow can build it.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path where the Gradle project should be rendered.
+    fn render(path: &str, params: &GradleParams) -> Result<(), SkootError> {
+        Self::write_build_scripts(path, params)?;
+
+        warn!(
+            "Rendered Gradle project for {} without its wrapper, run gradle wrapper to add it",
+            params.name
+        );
+        Ok(())
+    }
+
+    /// Returns an error if the group or name of the project aren't valid, or if the `settings.gradle.kts`
+    /// and `build.gradle.kts` of the project can't be written at the specified path.
+    fn write_build_scripts(path: &str, params: &GradleParams) -> Result<(), SkootError> {
+        #[derive(Template)]
+        #[template(path = "settings.gradle.kts", escape = "none")]
+        struct SettingsTemplateParams<'a> {
+            name: &'a str,
+        }
+
+        #[derive(Template)]
+        #[template(path = "build.gradle.kts", escape = "none")]
+        struct BuildTemplateParams<'a> {
+            group: &'a str,
+            package: &'a str,
         }
+
+        Self::validate(params)?;
+
+        let settings_template_params = SettingsTemplateParams { name: &params.name };
+        fs::write(
+            Path::new(path).join("settings.gradle.kts"),
+            settings_template_params.render()? + "\n",
+        )?;
+        let build_template_params = BuildTemplateParams {
+            group: &params.group,
+            package: &params.package(),
+        };
+        fs::write(
+            Path::new(path).join("build.gradle.kts"),
+            build_template_params.render()? + "\n",
+        )?;
+
+        Ok(())
+    }
+
+    /// Returns an error if the group or name of the project aren't valid.
+    fn validate(params: &GradleParams) -> Result<(), SkootError> {
+        // The group and name make up the Java package of the project, so they have to be valid in one.
+        if !params.group.split('.').all(is_java_identifier) {
+            return Err(format!("Invalid Gradle group: {}", params.group).into());
+        }
+        if !is_java_identifier(&params.name.replace('-', "_")) {
+            return Err(format!("Invalid Gradle project name: {}", params.name).into());
+        }
+
+        Ok(())
     }
 }
 
@@ -96,6 +334,7 @@ impl LocalGoEcosystemHandler {
     ///
     /// * `path` - The path where the Go module should be initialized.
     fn initialize(path: &str, params: &GoParams) -> Result<(), SkootError> {
+        Self::validate(params)?;
         let output = Command::new("go")
             .arg("mod")
             .arg("init")
@@ -106,77 +345,1078 @@ impl LocalGoEcosystemHandler {
             info!("Initialized go module for {}", params.name);
             Ok(())
         } else {
-            Err(Box::new(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "Failed to run go mod init: {}",
-                    String::from_utf8(output.stderr)?
-                ),
-            )))
+            Err(Box::new(std::io::Error::other(format!(
+                "Failed to run go mod init: {}",
+                String::from_utf8(output.stderr)?
+            ))))
+        }
+    }
+
+    /// Returns an error if the module path isn't valid or the `go.mod` of the module can't be written at
+    /// the specified path.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path where the Go module should be rendered.
+    fn render(path: &str, params: &GoParams) -> Result<(), SkootError> {
+        #[derive(Template)]
+        #[template(path = "go.mod.tmpl", escape = "none")]
+        struct GoModTemplateParams<'a> {
+            module: &'a str,
+        }
+
+        Self::validate(params)?;
+
+        let module = params.module();
+        let go_mod_template_params = GoModTemplateParams { module: &module };
+        fs::write(
+            Path::new(path).join("go.mod"),
+            go_mod_template_params.render()? + "\n",
+        )?;
+
+        info!("Rendered go module for {}", params.name);
+        Ok(())
+    }
+
+    /// Returns an error if the module path isn't valid.
+    fn validate(params: &GoParams) -> Result<(), SkootError> {
+        // These are the characters `go mod init` allows in a module path.
+        let is_valid_element = |element: &str| {
+            !element.is_empty()
+                && element
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || "-._~".contains(c))
+        };
+        let module = params.module();
+        if !module.split('/').all(is_valid_element) {
+            return Err(format!("Invalid Go module path: {module}").into());
+        }
+
+        Ok(())
+    }
+}
+
+/// The `LocalCargoEcosystemHandler` struct represents a handler for initializing and managing a Rust
+/// crate on the local machine.
+struct LocalCargoEcosystemHandler {}
+
+impl LocalCargoEcosystemHandler {
+    /// Returns an error if the initialization of a crate at the specified path fails.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path where the crate should be initialized.
+    fn initialize(path: &str, params: &CargoParams) -> Result<(), SkootError> {
+        Self::validate(params)?;
+        let crate_type = match params.crate_type {
+            CargoCrateType::Binary => "--bin",
+            CargoCrateType::Library => "--lib",
+        };
+        // The repo is already set up and the .gitignore is handled by the Gitignore facet,
+        // so cargo is told to leave version control alone.
+        let output = Command::new("cargo")
+            .arg("init")
+            .arg(crate_type)
+            .arg("--name")
+            .arg(&params.name)
+            .arg("--edition")
+            .arg(&params.edition)
+            .arg("--vcs")
+            .arg("none")
+            .current_dir(path)
+            .output()?;
+        if output.status.success() {
+            info!("Initialized crate for {}", params.name);
+            Ok(())
+        } else {
+            Err(Box::new(std::io::Error::other(format!(
+                "Failed to run cargo init: {}",
+                String::from_utf8(output.stderr)?
+            ))))
+        }
+    }
+
+    /// Returns an error if the crate name isn't valid or the `Cargo.toml` and `src/main.rs` or `src/lib.rs`
+    /// of the crate can't be written at the specified path.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path where the crate should be rendered.
+    fn render(path: &str, params: &CargoParams) -> Result<(), SkootError> {
+        #[derive(Template)]
+        #[template(path = "rust.Cargo.toml", escape = "none")]
+        struct CargoTomlTemplateParams<'a> {
+            name: &'a str,
+            edition: &'a str,
+        }
+
+        #[derive(Template)]
+        #[template(path = "main.rs.tmpl", escape = "none")]
+        struct MainTemplateParams {}
+
+        #[derive(Template)]
+        #[template(path = "lib.rs.tmpl", escape = "none")]
+        struct LibTemplateParams {}
+
+        Self::validate(params)?;
+
+        let cargo_toml_template_params = CargoTomlTemplateParams {
+            name: &params.name,
+            edition: &params.edition,
+        };
+        fs::write(
+            Path::new(path).join("Cargo.toml"),
+            cargo_toml_template_params.render()? + "\n",
+        )?;
+
+        // Like `cargo init`, the crate gets a source file so it builds on its own.
+        let (source_file, content) = match params.crate_type {
+            CargoCrateType::Binary => ("main.rs", MainTemplateParams {}.render()?),
+            CargoCrateType::Library => ("lib.rs", LibTemplateParams {}.render()?),
+        };
+        let source_path = Path::new(path).join("src");
+        fs::create_dir_all(&source_path)?;
+        fs::write(source_path.join(source_file), content + "\n")?;
+
+        info!("Rendered crate for {}", params.name);
+        Ok(())
+    }
+
+    /// Returns an error if the crate name isn't valid.
+    fn validate(params: &CargoParams) -> Result<(), SkootError> {
+        let is_valid_name = params.name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && params
+                .name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "-_".contains(c));
+        if !is_valid_name {
+            return Err(format!("Invalid crate name: {}", params.name).into());
+        }
+
+        Ok(())
+    }
+}
+
+/// The `LocalNpmEcosystemHandler` struct represents a handler for initializing and managing an npm
+/// package on the local machine. The manifest is written directly so Node.js doesn't need to be installed.
+struct LocalNpmEcosystemHandler {}
+
+impl LocalNpmEcosystemHandler {
+    /// Returns an error if the package name isn't valid or the manifest of the package can't be written
+    /// at the specified path.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path where the package should be initialized.
+    /// * `license` - The license of the project the package is in.
+    fn initialize(path: &str, params: &NpmParams, license: &SupportedLicense) -> Result<(), SkootError> {
+        Self::validate(params)?;
+
+        let package_name = params.package_name();
+
+        let mut package = json!({
+            "name": package_name,
+            "version": "0.1.0",
+            "type": "module",
+            "license": license.spdx_id(),
+            "publishConfig": {
+                "access": "public",
+                "provenance": true,
+            },
+        });
+        if params.typescript {
+            package["main"] = json!("dist/index.js");
+            package["types"] = json!("dist/index.d.ts");
+            package["files"] = json!(["dist"]);
+            package["scripts"] = json!({
+                "build": "tsc",
+                "test": "node --test",
+            });
+            package["devDependencies"] = json!({
+                "typescript": "^5.3.3",
+            });
+        } else {
+            package["main"] = json!("src/index.js");
+            package["files"] = json!(["src"]);
+            package["scripts"] = json!({
+                "test": "node --test",
+            });
+        }
+        // npm provenance requires the package's repository to match the repo it was built from.
+        if let Some(repository) = Self::origin_url(path) {
+            let url = if repository.contains("://") {
+                format!("git+{repository}")
+            } else {
+                repository
+            };
+            package["repository"] = json!({
+                "type": "git",
+                "url": url,
+            });
+        }
+        fs::write(
+            Path::new(path).join("package.json"),
+            serde_json::to_string_pretty(&package)? + "\n",
+        )?;
+
+        if params.typescript {
+            let tsconfig = json!({
+                "compilerOptions": {
+                    "target": "ES2022",
+                    "module": "NodeNext",
+                    "moduleResolution": "NodeNext",
+                    "declaration": true,
+                    "outDir": "dist",
+                    "rootDir": "src",
+                    "strict": true,
+                    "esModuleInterop": true,
+                    "skipLibCheck": true,
+                },
+                "include": ["src"],
+            });
+            fs::write(
+                Path::new(path).join("tsconfig.json"),
+                serde_json::to_string_pretty(&tsconfig)? + "\n",
+            )?;
+        }
+
+        info!("Initialized npm package for {package_name}");
+        Ok(())
+    }
+
+    /// Returns an error if the package name isn't valid.
+    fn validate(params: &NpmParams) -> Result<(), SkootError> {
+        // npm only allows lowercase URL-safe package names.
+        let is_valid_name = |name: &str| {
+            !name.is_empty()
+                && !name.starts_with(['.', '_'])
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || "-._~".contains(c))
+        };
+        if !is_valid_name(&params.name) || !params.scope.as_deref().is_none_or(is_valid_name) {
+            return Err(format!("Invalid npm package name: {}", params.package_name()).into());
+        }
+
+        Ok(())
+    }
+
+    /// Returns the URL of the `origin` remote of the repo at `path`, if there is one.
+    fn origin_url(path: &str) -> Option<String> {
+        let repo = git2::Repository::open(path).ok()?;
+        let remote = repo.find_remote("origin").ok()?;
+        remote.url().map(ToString::to_string)
+    }
+}
+
+/// The `LocalPythonEcosystemHandler` struct represents a handler for initializing and managing a Python
+/// project on the local machine. The `pyproject.toml` is written directly so no Python tooling needs to be
+/// installed.
+struct LocalPythonEcosystemHandler {}
+
+impl LocalPythonEcosystemHandler {
+    /// Returns an error if the project name isn't valid or the `pyproject.toml` of the project can't be
+    /// written at the specified path.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path where the project should be initialized.
+    fn initialize(path: &str, params: &PythonParams) -> Result<(), SkootError> {
+        #[derive(Template)]
+        #[template(path = "pyproject.toml", escape = "none")]
+        struct PyprojectTemplateParams<'a> {
+            name: &'a str,
+            module: &'a str,
+            python_version: &'a str,
         }
+
+        Self::validate(params)?;
+
+        let pyproject_template_params = PyprojectTemplateParams {
+            name: &params.name,
+            module: &params.module(),
+            python_version: &params.python_version,
+        };
+        fs::write(
+            Path::new(path).join("pyproject.toml"),
+            pyproject_template_params.render()? + "\n",
+        )?;
+
+        info!("Initialized Python project for {}", params.name);
+        Ok(())
+    }
+
+    /// Returns an error if the project name or Python version isn't valid.
+    fn validate(params: &PythonParams) -> Result<(), SkootError> {
+        // These are the PEP 508 rules for project names.
+        let is_valid_name = params.name.starts_with(|c: char| c.is_ascii_alphanumeric())
+            && params.name.ends_with(|c: char| c.is_ascii_alphanumeric())
+            && params
+                .name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "-._".contains(c));
+        if !is_valid_name {
+            return Err(format!("Invalid Python project name: {}", params.name).into());
+        }
+        // The version is the minimum in `requires-python`, e.g. 3.9.
+        let is_valid_python_version = params
+            .python_version
+            .split('.')
+            .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()));
+        if !is_valid_python_version {
+            return Err(format!("Invalid Python version: {}", params.python_version).into());
+        }
+
+        Ok(())
     }
 }
 
+/// The `EcosystemDetector` struct represents a service for detecting the ecosystems of a project from its source.
+///
+/// The ecosystems are parsed from the manifests in the source. This is used for projects that weren't created by
+/// Skootrs, and for checking that the ecosystem recorded for a project still matches its source.
+pub struct EcosystemDetector {}
+
+impl EcosystemDetector {
+    /// Returns the ecosystems whose manifests exist at the root of the source, with their params parsed
+    /// from the manifests.
+    ///
+    /// Params that a manifest doesn't have to set, like the Gradle version of a project without a wrapper,
+    /// are left empty. Manifests that aren't a project of their ecosystem, like a Poetry `pyproject.toml`
+    /// without a `[project]` table, or that can't be parsed don't make the source one of their ecosystem.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the manifests exist but can't be read.
+    pub fn detect<S: SourceService>(
+        &self,
+        source_service: &S,
+        source: &InitializedSource,
+    ) -> Result<Vec<InitializedEcosystem>, SkootError> {
+        let read = |path: &str, name: &str| -> Result<Option<String>, SkootError> {
+            match source_service.read_file(source, path, name.to_string()) {
+                Ok(content) => Ok(Some(content)),
+                Err(error) if is_not_found(&error) => Ok(None),
+                Err(error) => Err(error),
+            }
+        };
+
+        let mut ecosystems = Vec::new();
+        let mut push = |manifest: &str, ecosystem: Result<InitializedEcosystem, SkootError>| match ecosystem {
+            Ok(ecosystem) => ecosystems.push(ecosystem),
+            Err(error) => warn!("Not detecting an ecosystem from {manifest}: {error}"),
+        };
+        if let Some(go_mod) = read("./", "go.mod")? {
+            push("go.mod", Self::parse_go_mod(&go_mod));
+        }
+        if let Some(pom) = read("./", "pom.xml")? {
+            push("pom.xml", Self::parse_pom(&pom));
+        }
+        let build_script = match read("./", "build.gradle.kts")? {
+            Some(build_script) => Some(build_script),
+            None => read("./", "build.gradle")?,
+        };
+        if let Some(build_script) = build_script {
+            let settings = match read("./", "settings.gradle.kts")? {
+                Some(settings) => Some(settings),
+                None => read("./", "settings.gradle")?,
+            };
+            let wrapper_properties = read("./gradle/wrapper", "gradle-wrapper.properties")?;
+            push(
+                "the Gradle build script",
+                Self::parse_gradle(source, &build_script, settings.as_deref(), wrapper_properties.as_deref()),
+            );
+        }
+        if let Some(cargo_toml) = read("./", "Cargo.toml")? {
+            // A Cargo.toml without a package is only a workspace, which has no crate of its own.
+            match Self::parse_cargo_toml(&cargo_toml) {
+                Ok(Some(cargo)) => {
+                    let has_main = read("./src", "main.rs")?.is_some();
+                    push(
+                        "Cargo.toml",
+                        Ok(InitializedEcosystem::Cargo(InitializedCargo {
+                            crate_type: if has_main {
+                                CargoCrateType::Binary
+                            } else {
+                                cargo.crate_type
+                            },
+                            ..cargo
+                        })),
+                    );
+                }
+                Ok(None) => {}
+                Err(error) => push("Cargo.toml", Err(error)),
+            }
+        }
+        if let Some(package_json) = read("./", "package.json")? {
+            let has_tsconfig = read("./", "tsconfig.json")?.is_some();
+            push("package.json", Self::parse_package_json(&package_json, has_tsconfig));
+        }
+        if let Some(pyproject) = read("./", "pyproject.toml")? {
+            push("pyproject.toml", Self::parse_pyproject(&pyproject));
+        }
+
+        for ecosystem in &ecosystems {
+            info!("Detected ecosystem from {}", ecosystem.dependency_manifest());
+        }
+        Ok(ecosystems)
+    }
+
+    /// Returns the recorded `ecosystems` of a project that no longer match the manifests in their directories
+    /// of the source, e.g. because a module was renamed or a manifest was deleted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the manifests exist but can't be read.
+    pub fn check<S: SourceService>(
+        &self,
+        source_service: &S,
+        source: &InitializedSource,
+        ecosystems: &[InitializedProjectEcosystem],
+    ) -> Result<Vec<EcosystemDrift>, SkootError> {
+        let mut drifted_ecosystems = Vec::new();
+        for ecosystem in ecosystems {
+            let ecosystem_source = InitializedSource {
+                path: Path::new(&source.path)
+                    .join(ecosystem.directory())
+                    .to_string_lossy()
+                    .to_string(),
+            };
+            let detected = self
+                .detect(source_service, &ecosystem_source)?
+                .into_iter()
+                .find(|detected| detected.kind() == ecosystem.ecosystem.kind());
+            if detected.as_ref() == Some(&ecosystem.ecosystem) {
+                continue;
+            }
+            warn!("The {} ecosystem in {} no longer matches its manifests", ecosystem.ecosystem.kind(), ecosystem.path);
+            drifted_ecosystems.push(EcosystemDrift {
+                path: ecosystem.path.clone(),
+                recorded: ecosystem.ecosystem.clone(),
+                detected,
+            });
+        }
+
+        Ok(drifted_ecosystems)
+    }
+
+    fn parse_go_mod(go_mod: &str) -> Result<InitializedEcosystem, SkootError> {
+        let module = go_mod
+            .lines()
+            .find_map(|line| line.trim().strip_prefix("module "))
+            // e.g. `module example.com/my-project // comment`
+            .map(|module| module.split("//").next().unwrap_or_default().trim().trim_matches('"'))
+            .filter(|module| !module.is_empty())
+            .ok_or("go.mod doesn't declare a module")?;
+        // The major version suffix of modules from v2 on, e.g. `github.com/kusaridev/skootrs/v2`, isn't part
+        // of the name.
+        let (path, major_version) = match module.rsplit_once('/') {
+            Some((path, suffix)) if is_major_version_suffix(suffix) => (path, Some(suffix.to_string())),
+            _ => (module, None),
+        };
+        // A module path doesn't need a host, e.g. `example` for a module that isn't published.
+        let (host, name) = path.rsplit_once('/').unwrap_or(("", path));
+        if name.is_empty() {
+            return Err(format!("Unsupported Go module path: {module}").into());
+        }
+
+        Ok(InitializedEcosystem::Go(InitializedGo {
+            name: name.to_string(),
+            host: host.to_string(),
+            major_version,
+        }))
+    }
+
+    fn parse_pom(pom: &str) -> Result<InitializedEcosystem, SkootError> {
+        let document = roxmltree::Document::parse(pom)?;
+        let project = document.root_element();
+        if project.tag_name().name() != "project" {
+            return Err("pom.xml doesn't have a project element".into());
+        }
+        // Only the project's own elements are looked at, and not the ones of its dependencies or plugins.
+        let child_text = |node: roxmltree::Node, name: &str| {
+            node.children()
+                .find(|child| child.tag_name().name() == name)
+                .and_then(|child| child.text())
+                .map(|text| text.trim().to_string())
+        };
+        let parent_group_id = project
+            .children()
+            .find(|child| child.tag_name().name() == "parent")
+            .and_then(|parent| child_text(parent, "groupId"));
+
+        Ok(InitializedEcosystem::Maven(InitializedMaven {
+            // The group ID is inherited from the parent when the project doesn't set its own.
+            group_id: child_text(project, "groupId")
+                .or(parent_group_id)
+                .ok_or("pom.xml doesn't set the project's group ID")?,
+            artifact_id: child_text(project, "artifactId").ok_or("pom.xml doesn't set the project's artifact ID")?,
+        }))
+    }
+
+    fn parse_gradle(
+        source: &InitializedSource,
+        build_script: &str,
+        settings: Option<&str>,
+        wrapper_properties: Option<&str>,
+    ) -> Result<InitializedEcosystem, SkootError> {
+        // Matches both the Kotlin DSL's `group = "com.example"` and Groovy's `group 'com.example'`.
+        let quoted_value = |content: &str, key: &str| {
+            content.lines().find_map(|line| {
+                let value = line.trim().strip_prefix(key)?.trim_start();
+                let value = value.strip_prefix('=').unwrap_or(value).trim();
+                let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+                value[1..].split(quote).next().map(ToString::to_string)
+            })
+        };
+        let property = |key: &str| {
+            wrapper_properties.and_then(|properties| {
+                properties
+                    .lines()
+                    .find_map(|line| line.trim().strip_prefix(key)?.strip_prefix('='))
+                    .map(|value| value.trim().to_string())
+            })
+        };
+
+        let group = quoted_value(build_script, "group")
+            .ok_or("The Gradle build script doesn't set the project's group")?;
+        // Gradle names the root project after its directory unless the settings name it.
+        let name = settings
+            .and_then(|settings| quoted_value(settings, "rootProject.name"))
+            .or_else(|| {
+                Path::new(&source.path)
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+            })
+            .ok_or("Unable to determine the name of the Gradle project")?;
+        let gradle_version = property("distributionUrl")
+            .and_then(|url| {
+                let distribution = url.rsplit_once("gradle-")?.1;
+                let version = distribution
+                    .strip_suffix("-bin.zip")
+                    .or_else(|| distribution.strip_suffix("-all.zip"))?;
+                Some(version.to_string())
+            })
+            .unwrap_or_default();
+
+        Ok(InitializedEcosystem::Gradle(InitializedGradle {
+            group,
+            name,
+            gradle_version,
+            distribution_sha256_sum: property("distributionSha256Sum").unwrap_or_default(),
+        }))
+    }
+
+    /// Returns `None` if the Cargo.toml doesn't have a package. The crate type is based on the manifest
+    /// alone, so it's only `Binary` if the manifest declares a binary target.
+    fn parse_cargo_toml(cargo_toml: &str) -> Result<Option<InitializedCargo>, SkootError> {
+        let manifest = cargo_toml.parse::<Document>()?;
+        let Some(package) = manifest.get("package") else {
+            return Ok(None);
+        };
+        let name = package
+            .get("name")
+            .and_then(|name| name.as_str())
+            .ok_or("Cargo.toml doesn't set the package's name")?;
+        // Cargo uses the 2015 edition for packages that don't set one.
+        let edition = package
+            .get("edition")
+            .and_then(|edition| edition.as_str())
+            .unwrap_or("2015");
+        let crate_type = if manifest.contains_key("bin") {
+            CargoCrateType::Binary
+        } else {
+            CargoCrateType::Library
+        };
+
+        Ok(Some(InitializedCargo {
+            name: name.to_string(),
+            crate_type,
+            edition: edition.to_string(),
+        }))
+    }
+
+    fn parse_package_json(package_json: &str, has_tsconfig: bool) -> Result<InitializedEcosystem, SkootError> {
+        let package: serde_json::Value = serde_json::from_str(package_json)?;
+        let package_name = package["name"]
+            .as_str()
+            .ok_or("package.json doesn't set the package's name")?;
+        let (scope, name) = match package_name
+            .strip_prefix('@')
+            .and_then(|scoped_name| scoped_name.split_once('/'))
+        {
+            Some((scope, name)) => (Some(scope.to_string()), name),
+            None => (None, package_name),
+        };
+        let typescript = has_tsconfig
+            || ["dependencies", "devDependencies"]
+                .iter()
+                .any(|dependencies| package[dependencies].get("typescript").is_some());
+
+        Ok(InitializedEcosystem::Npm(InitializedNpm {
+            name: name.to_string(),
+            scope,
+            typescript,
+        }))
+    }
+
+    fn parse_pyproject(pyproject: &str) -> Result<InitializedEcosystem, SkootError> {
+        let manifest = pyproject.parse::<Document>()?;
+        let project = manifest
+            .get("project")
+            .ok_or("pyproject.toml doesn't have a project table")?;
+        let name = project
+            .get("name")
+            .and_then(|name| name.as_str())
+            .ok_or("pyproject.toml doesn't set the project's name")?;
+        // e.g. `3.9` from `>=3.9` or `>=3.9,<4`
+        let python_version = project
+            .get("requires-python")
+            .and_then(|requires_python| requires_python.as_str())
+            .and_then(|requires_python| requires_python.split(',').next())
+            .map(|minimum| minimum.trim_start_matches(['>', '=', '~', ' ']).trim().to_string())
+            .unwrap_or_default();
+
+        Ok(InitializedEcosystem::Python(InitializedPython {
+            name: name.to_string(),
+            python_version,
+        }))
+    }
+}
+
+/// Returns whether the last element of a Go module path is a major version suffix, e.g. `v2`. Modules before v2
+/// don't have one.
+fn is_major_version_suffix(element: &str) -> bool {
+    element
+        .strip_prefix('v')
+        .and_then(|version| version.parse::<u32>().ok())
+        .is_some_and(|version| version >= 2)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::service::source::LocalSourceService;
     use tempdir::TempDir;
 
+    /// The files an ecosystem is expected to be scaffolded with, along with text each of them has to contain.
+    type ExpectedFiles = &'static [(&'static str, &'static str)];
+
     #[test]
-    fn test_local_maven_ecosystem_handler_initialize_success() {
-        let temp_dir = TempDir::new("test").unwrap();
-        let path = temp_dir.path().to_str().unwrap();
-        let params = MavenParams {
+    #[allow(clippy::too_many_lines)]
+    fn test_local_ecosystem_service_initialize() {
+        use EcosystemScaffolding::{Template, Toolchain};
+        let gradle = |group: &str, name: &str| {
+            EcosystemParams::Gradle(GradleParams {
+                group: group.to_string(),
+                name: name.to_string(),
+                gradle_version: "8.5".to_string(),
+                distribution_sha256_sum: "9d926787066a081739e8200858338b4a69e837c3a821a33aca9db09dd4a41026"
+                    .to_string(),
+            })
+        };
+        let cargo = |name: &str, crate_type: CargoCrateType| {
+            EcosystemParams::Cargo(CargoParams {
+                name: name.to_string(),
+                crate_type,
+                edition: "2021".to_string(),
+            })
+        };
+        // Params that aren't valid don't have any expected files, and are rejected before any tools are run.
+        let cases: Vec<(EcosystemScaffolding, EcosystemParams, Option<ExpectedFiles>)> = vec![
+            (
+                Template,
+                EcosystemParams::Maven(MavenParams {
+                    group_id: "com.example".to_string(),
+                    artifact_id: "my-project".to_string(),
+                }),
+                Some(&[
+                    ("pom.xml", "<groupId>com.example</groupId>"),
+                    ("pom.xml", "<artifactId>my-project</artifactId>"),
+                ]),
+            ),
+            (
+                Toolchain,
+                EcosystemParams::Maven(MavenParams {
+                    group_id: String::new(),
+                    artifact_id: "my-project".to_string(),
+                }),
+                None,
+            ),
+            (
+                Template,
+                EcosystemParams::Maven(MavenParams {
+                    group_id: String::new(),
+                    artifact_id: "my-project".to_string(),
+                }),
+                None,
+            ),
+            // The wrapper can only be generated by Gradle, so only the build scripts are rendered.
+            (
+                Template,
+                gradle("com.example", "my-project"),
+                Some(&[("settings.gradle.kts", "my-project"), ("build.gradle.kts", "com.example")]),
+            ),
+            (Toolchain, gradle("com.1example", "my-project"), None),
+            (Template, gradle("com.example", "1-project"), None),
+            (
+                Template,
+                EcosystemParams::Go(GoParams {
+                    name: "my-project".to_string(),
+                    host: "github.com/kusaridev".to_string(),
+                }),
+                Some(&[("go.mod", "module github.com/kusaridev/my-project\n")]),
+            ),
+            (
+                Toolchain,
+                EcosystemParams::Go(GoParams {
+                    name: String::new(),
+                    host: "github.com".to_string(),
+                }),
+                None,
+            ),
+            (
+                Template,
+                EcosystemParams::Go(GoParams {
+                    name: String::new(),
+                    host: "github.com".to_string(),
+                }),
+                None,
+            ),
+            (
+                Toolchain,
+                cargo("my-project", CargoCrateType::Library),
+                Some(&[("Cargo.toml", "name = \"my-project\""), ("src/lib.rs", "")]),
+            ),
+            (
+                Template,
+                cargo("my-project", CargoCrateType::Library),
+                Some(&[
+                    ("Cargo.toml", "name = \"my-project\""),
+                    ("Cargo.toml", "edition = \"2021\""),
+                    ("src/lib.rs", ""),
+                ]),
+            ),
+            (Template, cargo("my-project", CargoCrateType::Binary), Some(&[("src/main.rs", "")])),
+            (Toolchain, cargo("", CargoCrateType::Binary), None),
+            (Template, cargo("", CargoCrateType::Binary), None),
+            (
+                Template,
+                EcosystemParams::Npm(NpmParams {
+                    name: "my-project".to_string(),
+                    scope: Some("kusaridev".to_string()),
+                    typescript: true,
+                }),
+                Some(&[
+                    ("package.json", "\"name\": \"@kusaridev/my-project\""),
+                    ("package.json", "\"license\": \"MIT\""),
+                    ("tsconfig.json", ""),
+                ]),
+            ),
+            (
+                Template,
+                EcosystemParams::Npm(NpmParams {
+                    name: "My Project".to_string(),
+                    scope: None,
+                    typescript: false,
+                }),
+                None,
+            ),
+            (
+                Template,
+                EcosystemParams::Python(PythonParams {
+                    name: "my-project".to_string(),
+                    python_version: "3.9".to_string(),
+                }),
+                Some(&[
+                    ("pyproject.toml", "name = \"my-project\""),
+                    ("pyproject.toml", "packages = [\"src/my_project\"]"),
+                ]),
+            ),
+            (
+                Template,
+                EcosystemParams::Python(PythonParams {
+                    name: "my project".to_string(),
+                    python_version: "3.9".to_string(),
+                }),
+                None,
+            ),
+        ];
+
+        for (scaffolding, params, expected_files) in cases {
+            let temp_dir = TempDir::new("test").unwrap();
+            let source = InitializedSource {
+                path: temp_dir.path().to_str().unwrap().to_string(),
+            };
+            let ecosystem_service = LocalEcosystemService { scaffolding };
+
+            let result = ecosystem_service.initialize(params.clone(), source, &SupportedLicense::Mit);
+
+            let Some(expected_files) = expected_files else {
+                assert!(result.is_err(), "{params:?} should be invalid");
+                continue;
+            };
+            assert!(result.is_ok(), "{params:?} should be scaffolded: {result:?}");
+            for (name, expected_content) in expected_files {
+                let content = fs::read_to_string(temp_dir.path().join(name))
+                    .unwrap_or_else(|error| panic!("{name} should be scaffolded for {params:?}: {error}"));
+                assert!(content.contains(expected_content), "{name} should contain {expected_content}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_ecosystem_params() {
+        let valid = EcosystemParams::Maven(MavenParams {
             group_id: "com.example".to_string(),
             artifact_id: "my-project".to_string(),
+        });
+        assert!(validate_ecosystem_params(&valid).is_ok());
+
+        let invalid = [
+            EcosystemParams::Maven(MavenParams {
+                group_id: "com.1example".to_string(),
+                artifact_id: "my-project".to_string(),
+            }),
+            EcosystemParams::Go(GoParams {
+                name: "my project".to_string(),
+                host: "github.com/example".to_string(),
+            }),
+            EcosystemParams::Npm(NpmParams {
+                name: "MyProject".to_string(),
+                scope: None,
+                typescript: false,
+            }),
+            EcosystemParams::Python(PythonParams {
+                name: "my-project".to_string(),
+                python_version: "3.9\"\nclassifiers = [".to_string(),
+            }),
+        ];
+        for params in invalid {
+            assert!(validate_ecosystem_params(&params).is_err());
+        }
+    }
+
+    #[test]
+    fn test_ecosystem_detector_detect() {
+        let temp_dir = TempDir::new("test").unwrap();
+        fs::write(
+            temp_dir.path().join("go.mod"),
+            "module github.com/kusaridev/my-project\n\ngo 1.21\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("package.json"),
+            r#"{"name": "@kusaridev/my-project", "devDependencies": {"typescript": "^5.3.3"}}"#,
+        )
+        .unwrap();
+        let source = InitializedSource {
+            path: temp_dir.path().to_str().unwrap().to_string(),
         };
 
-        let result = LocalMavenEcosystemHandler::initialize(path, &params);
+        let ecosystems = EcosystemDetector {}
+            .detect(&LocalSourceService::default(), &source)
+            .unwrap();
 
-        assert!(result.is_ok());
+        assert_eq!(ecosystems.len(), 2);
+        match &ecosystems[0] {
+            InitializedEcosystem::Go(g) => assert_eq!(g.module(), "github.com/kusaridev/my-project"),
+            _ => panic!("Go should be detected first"),
+        }
+        match &ecosystems[1] {
+            InitializedEcosystem::Npm(n) => {
+                assert_eq!(n.package_name(), "@kusaridev/my-project");
+                assert!(n.typescript);
+            }
+            _ => panic!("npm should be detected second"),
+        }
     }
 
     #[test]
-    fn test_local_maven_ecosystem_handler_initialize_failure() {
+    fn test_ecosystem_detector_detect_none() {
         let temp_dir = TempDir::new("test").unwrap();
-        let path = temp_dir.path().to_str().unwrap();
-        let params = MavenParams {
-            // Invalid group ID
-            group_id: "".to_string(),
-            artifact_id: "my-project".to_string(),
+        let source = InitializedSource {
+            path: temp_dir.path().to_str().unwrap().to_string(),
         };
 
-        let result = LocalMavenEcosystemHandler::initialize(path, &params);
+        let ecosystems = EcosystemDetector {}
+            .detect(&LocalSourceService::default(), &source)
+            .unwrap();
 
-        assert!(result.is_err());
+        assert!(ecosystems.is_empty());
     }
 
     #[test]
-    fn test_local_go_ecosystem_handler_initialize_success() {
+    fn test_ecosystem_detector_detect_skips_other_manifests() {
         let temp_dir = TempDir::new("test").unwrap();
-        let path = temp_dir.path().to_str().unwrap();
-        let params = GoParams {
-            name: "my-project".to_string(),
-            host: "github.com".to_string(),
+        fs::write(temp_dir.path().join("go.mod"), "module example\n\ngo 1.21\n").unwrap();
+        // A Poetry project and a package.json that only holds scripts aren't Python or npm projects.
+        fs::write(
+            temp_dir.path().join("pyproject.toml"),
+            "[tool.poetry]\nname = \"my-project\"\n",
+        )
+        .unwrap();
+        fs::write(temp_dir.path().join("package.json"), r#"{"private": true}"#).unwrap();
+        let source = InitializedSource {
+            path: temp_dir.path().to_str().unwrap().to_string(),
         };
 
-        let result = LocalGoEcosystemHandler::initialize(path, &params);
+        let ecosystems = EcosystemDetector {}
+            .detect(&LocalSourceService::default(), &source)
+            .unwrap();
 
-        assert!(result.is_ok());
+        assert_eq!(ecosystems.len(), 1);
+        assert_eq!(ecosystems[0].kind(), "go");
     }
 
     #[test]
-    fn test_local_go_ecosystem_handler_initialize_failure() {
+    fn test_ecosystem_detector_check() {
         let temp_dir = TempDir::new("test").unwrap();
-        let path = temp_dir.path().to_str().unwrap();
-        let params = GoParams {
-            // Invalid project name
-            name: "".to_string(),
-            host: "github.com".to_string(),
+        fs::create_dir(temp_dir.path().join("backend")).unwrap();
+        fs::write(
+            temp_dir.path().join("backend/go.mod"),
+            "module github.com/kusaridev/backend\n\ngo 1.21\n",
+        )
+        .unwrap();
+        let source = InitializedSource {
+            path: temp_dir.path().to_str().unwrap().to_string(),
         };
+        let go = InitializedProjectEcosystem {
+            path: "backend".to_string(),
+            ecosystem: InitializedEcosystem::Go(InitializedGo {
+                name: "backend".to_string(),
+                host: "github.com/kusaridev".to_string(),
+                major_version: None,
+            }),
+        };
+        let npm: InitializedProjectEcosystem = InitializedEcosystem::Npm(InitializedNpm {
+            name: "frontend".to_string(),
+            scope: None,
+            typescript: false,
+        })
+        .into();
 
-        let result = LocalGoEcosystemHandler::initialize(path, &params);
+        let drift = EcosystemDetector {}
+            .check(&LocalSourceService::default(), &source, &[go.clone(), npm])
+            .unwrap();
+        // The npm ecosystem's package.json is gone.
+        assert_eq!(drift.len(), 1);
+        assert_eq!(drift[0].recorded.kind(), "npm");
+        assert!(drift[0].detected.is_none());
 
-        assert!(result.is_err());
+        fs::write(
+            temp_dir.path().join("backend/go.mod"),
+            "module github.com/kusaridev/backend/v2\n\ngo 1.21\n",
+        )
+        .unwrap();
+        let drift = EcosystemDetector {}
+            .check(&LocalSourceService::default(), &source, &[go])
+            .unwrap();
+        assert_eq!(drift.len(), 1);
+        match &drift[0].detected {
+            Some(InitializedEcosystem::Go(g)) => assert_eq!(g.module(), "github.com/kusaridev/backend/v2"),
+            _ => panic!("The renamed Go module should be detected"),
+        }
+    }
+
+    #[test]
+    fn test_ecosystem_detector_parse_go_mod() {
+        match EcosystemDetector::parse_go_mod("module example\n\ngo 1.21\n").unwrap() {
+            InitializedEcosystem::Go(g) => {
+                assert_eq!(g.name, "example");
+                assert_eq!(g.host, "");
+                assert_eq!(g.module(), "example");
+            }
+            _ => panic!("Go should be detected"),
+        }
+
+        match EcosystemDetector::parse_go_mod("module \"github.com/kusaridev/skootrs/v2\" // The v2 module\n").unwrap() {
+            InitializedEcosystem::Go(g) => {
+                assert_eq!(g.name, "skootrs");
+                assert_eq!(g.host, "github.com/kusaridev");
+                assert_eq!(g.major_version.as_deref(), Some("v2"));
+                assert_eq!(g.module(), "github.com/kusaridev/skootrs/v2");
+            }
+            _ => panic!("Go should be detected"),
+        }
+
+        // `v1` and `v0` aren't major version suffixes.
+        match EcosystemDetector::parse_go_mod("module github.com/kusaridev/v1\n").unwrap() {
+            InitializedEcosystem::Go(g) => {
+                assert_eq!(g.name, "v1");
+                assert!(g.major_version.is_none());
+            }
+            _ => panic!("Go should be detected"),
+        }
+
+        assert!(EcosystemDetector::parse_go_mod("go 1.21\n").is_err());
+    }
+
+    #[test]
+    fn test_ecosystem_detector_parse_pom() {
+        let pom = r#"<?xml version="1.0" encoding="UTF-8"?>
+<project xmlns="http://maven.apache.org/POM/4.0.0">
+  <!-- <artifactId>commented-out</artifactId> -->
+  <name><![CDATA[My <Project>]]></name>
+  <parent>
+    <groupId>com.example.parent</groupId>
+    <artifactId>parent</artifactId>
+  </parent>
+  <artifactId>my-project</artifactId>
+  <dependencies>
+    <dependency>
+      <groupId>org.junit.jupiter</groupId>
+      <artifactId>junit-jupiter</artifactId>
+    </dependency>
+  </dependencies>
+</project>"#;
+
+        match EcosystemDetector::parse_pom(pom).unwrap() {
+            InitializedEcosystem::Maven(m) => {
+                assert_eq!(m.group_id, "com.example.parent");
+                assert_eq!(m.artifact_id, "my-project");
+            }
+            _ => panic!("Maven should be detected"),
+        }
+    }
+
+    #[test]
+    fn test_ecosystem_detector_parse_gradle() {
+        let source = InitializedSource {
+            path: "/tmp/my-project".to_string(),
+        };
+        let properties = "distributionSha256Sum=9d926787066a081739e8200858338b4a69e837c3a821a33aca9db09dd4a41026\n\
+            distributionUrl=https\\://services.gradle.org/distributions/gradle-8.5-bin.zip\n";
+
+        match EcosystemDetector::parse_gradle(&source, "group 'com.example'\n", None, Some(properties)).unwrap() {
+            InitializedEcosystem::Gradle(g) => {
+                assert_eq!(g.group, "com.example");
+                assert_eq!(g.name, "my-project");
+                assert_eq!(g.gradle_version, "8.5");
+                assert_eq!(g.distribution_sha256_sum, "9d926787066a081739e8200858338b4a69e837c3a821a33aca9db09dd4a41026");
+            }
+            _ => panic!("Gradle should be detected"),
+        }
+    }
+
+    #[test]
+    fn test_ecosystem_detector_parse_toml_manifests() {
+        let cargo = EcosystemDetector::parse_cargo_toml("[package]\nname = \"my-project\"\nedition = \"2021\"\n")
+            .unwrap()
+            .unwrap();
+        assert_eq!(cargo.name, "my-project");
+        assert_eq!(cargo.edition, "2021");
+        assert_eq!(cargo.crate_type, CargoCrateType::Library);
+        assert!(EcosystemDetector::parse_cargo_toml("[workspace]\nmembers = []\n")
+            .unwrap()
+            .is_none());
+
+        match EcosystemDetector::parse_pyproject("[project]\nname = \"my-project\"\nrequires-python = \">=3.9,<4\"\n").unwrap() {
+            InitializedEcosystem::Python(p) => {
+                assert_eq!(p.name, "my-project");
+                assert_eq!(p.python_version, "3.9");
+            }
+            _ => panic!("Python should be detected"),
+        }
     }
 }
@@ -21,26 +21,27 @@
 #![allow(clippy::module_name_repetitions)]
 #![allow(clippy::unused_self)]
 
-use std::{error::Error, str::FromStr};
+use std::{collections::HashMap, error::Error, fs, path::Path, str::FromStr};
 
 use askama::Template;
 use chrono::Datelike;
 
-use tracing::info;
+use toml_edit::Document;
+use tracing::{info, warn};
 
 use skootrs_model::{
     security_insights::insights10::{
-        SecurityInsightsVersion100YamlSchema, SecurityInsightsVersion100YamlSchemaContributionPolicy, SecurityInsightsVersion100YamlSchemaDependencies, SecurityInsightsVersion100YamlSchemaDependenciesSbomItem, SecurityInsightsVersion100YamlSchemaDependenciesSbomItemSbomCreation, SecurityInsightsVersion100YamlSchemaHeader, SecurityInsightsVersion100YamlSchemaHeaderSchemaVersion, SecurityInsightsVersion100YamlSchemaProjectLifecycle, SecurityInsightsVersion100YamlSchemaProjectLifecycleStatus, SecurityInsightsVersion100YamlSchemaVulnerabilityReporting
+        SecurityInsightsVersion100YamlSchema, SecurityInsightsVersion100YamlSchemaContributionPolicy, SecurityInsightsVersion100YamlSchemaDependencies, SecurityInsightsVersion100YamlSchemaDependenciesSbomItem, SecurityInsightsVersion100YamlSchemaDependenciesSbomItemSbomCreation, SecurityInsightsVersion100YamlSchemaHeader, SecurityInsightsVersion100YamlSchemaHeaderSchemaVersion, SecurityInsightsVersion100YamlSchemaProjectLifecycle, SecurityInsightsVersion100YamlSchemaProjectLifecycleStatus, SecurityInsightsVersion100YamlSchemaSecurityTestingItem, SecurityInsightsVersion100YamlSchemaSecurityTestingItemComment, SecurityInsightsVersion100YamlSchemaSecurityTestingItemIntegration, SecurityInsightsVersion100YamlSchemaSecurityTestingItemToolType, SecurityInsightsVersion100YamlSchemaVulnerabilityReporting
     },
     skootrs::{
         facet::{
             APIBundleFacet, APIBundleFacetParams, APIContent, CommonFacetParams, FacetParams, FacetSetParams, InitializedFacet, SourceBundleFacet, SourceBundleFacetParams, SourceFileContent, SourceFileFacet, SourceFileFacetParams, SupportedFacetType
-        }, InitializedEcosystem, InitializedGithubRepo, InitializedRepo, SkootError
+        }, CargoCrateType, GithubUser, InitializedCargo, InitializedEcosystem, InitializedProjectEcosystem, InitializedGiteaRepo, InitializedGithubRepo, InitializedGitlabRepo, InitializedMaven, InitializedRepo, InitializedSource, SkootError, SupportedLicense
     },
 };
-use crate::service::source::SourceService;
+use crate::service::source::{content_hash, is_not_found, SourceService};
 
-use super::source::LocalSourceService;
+use super::{repo::ForgeClient, source::LocalSourceService};
 
 /// The `LocalFacetService` struct represents a service for creating and managing facets on the local machine.
 #[derive(Debug)]
@@ -94,45 +95,44 @@ impl SourceBundleFacetService for LocalFacetService {
         &self,
         params: SourceBundleFacetParams,
     ) -> Result<SourceBundleFacet, SkootError> {
-        let source_service = LocalSourceService {};
+        let source_service = LocalSourceService::default();
         let default_source_bundle_content_handler = DefaultSourceBundleContentHandler {};
         // TODO: Update this to be more generic on the repo service
-        let language_specific_source_bundle_content_handler = match params.common.ecosystem {
-            InitializedEcosystem::Go(_) => GoGithubSourceBundleContentHandler {},
-            InitializedEcosystem::Maven(_) => todo!(),
-        };
+        let ecosystems_source_bundle_content_handler = EcosystemsSourceBundleContentHandler {};
 
         let source_bundle_content = match params.facet_type {
             SupportedFacetType::Readme
             | SupportedFacetType::License
             | SupportedFacetType::SecurityPolicy
             | SupportedFacetType::Scorecard
-            | SupportedFacetType::SecurityInsights => {
+            | SupportedFacetType::SecurityInsights
+            | SupportedFacetType::SBOMGenerator
+            | SupportedFacetType::CodeReview
+            | SupportedFacetType::Allstar
+            | SupportedFacetType::GUACForwardingConfig
+            | SupportedFacetType::VulnerabilityScanner
+            | SupportedFacetType::DependencyUpdateTool => {
                 default_source_bundle_content_handler.generate_content(&params)?
             }
-            SupportedFacetType::Gitignore
-            | SupportedFacetType::SLSABuild
-            | SupportedFacetType::DependencyUpdateTool => {
-                language_specific_source_bundle_content_handler.generate_content(&params)?
+            SupportedFacetType::Gitignore | SupportedFacetType::SLSABuild => {
+                ecosystems_source_bundle_content_handler.generate_content(&params)?
             }
-            SupportedFacetType::SBOMGenerator => todo!(),
             SupportedFacetType::StaticCodeAnalysis => todo!(),
             SupportedFacetType::BranchProtection => todo!(),
-            SupportedFacetType::CodeReview => todo!(),
             SupportedFacetType::Fuzzing => {
-                language_specific_source_bundle_content_handler.generate_content(&params)?
+                ecosystems_source_bundle_content_handler.generate_content(&params)?
             }
             SupportedFacetType::PublishPackages => todo!(),
-            SupportedFacetType::PinnedDependencies => todo!(),
+            SupportedFacetType::PinnedDependencies => {
+                PinnedDependenciesSourceBundleContentHandler {}.generate_content(&params)?
+            }
             SupportedFacetType::SAST => default_source_bundle_content_handler.generate_content(&params)?,
-            SupportedFacetType::VulnerabilityScanner => todo!(),
-            SupportedFacetType::GUACForwardingConfig => todo!(),
-            SupportedFacetType::Allstar => todo!(),
-            SupportedFacetType::DefaultSourceCode => language_specific_source_bundle_content_handler.generate_content(&params)?,
+            SupportedFacetType::DefaultSourceCode => ecosystems_source_bundle_content_handler.generate_content(&params)?,
             SupportedFacetType::VulnerabilityReporting => unimplemented!("VulnerabilityReporting is not implemented for source bundles"),
         };
 
-        for source_file_content in &source_bundle_content.source_files_content {
+        let mut source_files = Vec::new();
+        for source_file_content in source_bundle_content.source_files_content {
             info!(
                 "Writing file {} to {}",
                 source_file_content.name, source_file_content.path
@@ -143,10 +143,14 @@ impl SourceBundleFacetService for LocalFacetService {
                 source_file_content.name.clone(),
                 source_file_content.content.clone(),
             )?;
+            source_files.push(SourceFileContent {
+                hash: Some(content_hash(&source_file_content.content)),
+                ..source_file_content
+            });
         }
 
         let source_bundle_facet = SourceBundleFacet {
-            source_files: source_bundle_content.source_files_content,
+            source_files,
             facet_type: params.facet_type,
         };
 
@@ -170,15 +174,30 @@ impl APIBundleFacetService for LocalFacetService {
         &self,
         params: APIBundleFacetParams,
     ) -> Result<APIBundleFacet, SkootError> {
-        // TODO: This should support more than just Github
         match params.facet_type {
             SupportedFacetType::CodeReview | SupportedFacetType::BranchProtection | SupportedFacetType::VulnerabilityReporting => {
-                let github_api_bundle_handler = GithubAPIBundleHandler {};
-                let api_bundle_facet =
-                    github_api_bundle_handler.generate(&params).await?;
+                let api_bundle_facet = match params.common.repo {
+                    InitializedRepo::Github(_) => {
+                        let github_api_bundle_handler = GithubAPIBundleHandler {};
+                        github_api_bundle_handler.generate(&params).await?
+                    }
+                    InitializedRepo::Gitlab(_) => {
+                        let gitlab_api_bundle_handler = GitlabAPIBundleHandler {};
+                        gitlab_api_bundle_handler.generate(&params).await?
+                    }
+                    InitializedRepo::Gitea(_) => {
+                        let gitea_api_bundle_handler = GiteaAPIBundleHandler {};
+                        gitea_api_bundle_handler.generate(&params).await?
+                    }
+                    // There is no API for a local bare repo so these facets aren't applicable.
+                    InitializedRepo::Local(_) => APIBundleFacet {
+                        facet_type: params.facet_type.clone(),
+                        apis: vec![],
+                    },
+                };
                 Ok(api_bundle_facet)
             }
-            _ => todo!("Not implemented yet"),
+            _ => Err(format!("The {} facet can't be initialized with APIs", params.facet_type).into()),
         
         }
     }
@@ -213,17 +232,22 @@ impl RootFacetService for LocalFacetService {
         &self,
         params: FacetSetParams,
     ) -> Result<Vec<InitializedFacet>, SkootError> {
-        let futures = params
-        .facets_params
-        .iter()
-        .map(move |params| RootFacetService::initialize(self, params.clone()) );
-
-        let results = futures::future::try_join_all(futures).await?;
-        Ok(results)
-        //.collect::<Result<Vec<InitializedFacet>, SkootError>>()
+        initialize_in_order(self, params).await
     }
+}
 
-    
+/// Initializes the facets one after another in the order they are listed in. Facets can build on what the
+/// ones before them set up, e.g. code review updates the protection branch protection creates, so they
+/// can't be initialized concurrently.
+async fn initialize_in_order<S: RootFacetService + Sync>(
+    service: &S,
+    params: FacetSetParams,
+) -> Result<Vec<InitializedFacet>, SkootError> {
+    let mut initialized_facets = Vec::new();
+    for facet_params in params.facets_params {
+        initialized_facets.push(service.initialize(facet_params).await?);
+    }
+    Ok(initialized_facets)
 }
 
 /// The `APIBundleHandler` trait provides an interface for generating an `APIBundleFacet`.
@@ -245,11 +269,14 @@ impl APIBundleHandler for GithubAPIBundleHandler {
         &self,
         params: &APIBundleFacetParams,
     ) -> Result<APIBundleFacet, SkootError> {
-        let InitializedRepo::Github(repo) = &params.common.repo;
+        let InitializedRepo::Github(repo) = &params.common.repo else {
+            return Err("Github API bundle facets can only be generated for Github repos".into());
+        };
         match params.facet_type {
-            SupportedFacetType::BranchProtection => self.generate_branch_protection(repo).await,
+            SupportedFacetType::BranchProtection => self.generate_branch_protection(params, repo).await,
             SupportedFacetType::VulnerabilityReporting => self.generate_vulnerability_reporting(repo).await,
-            _ => todo!("Not implemented yet"),
+            SupportedFacetType::CodeReview => self.generate_code_review(params, repo).await,
+            _ => Err(format!("The {} facet isn't supported for Github repos", params.facet_type).into()),
         }
     }
 }
@@ -257,13 +284,14 @@ impl APIBundleHandler for GithubAPIBundleHandler {
 impl GithubAPIBundleHandler {
     async fn generate_branch_protection(
         &self,
+        params: &APIBundleFacetParams,
         repo: &InitializedGithubRepo,
     ) -> Result<APIBundleFacet, SkootError> {
         let enforce_branch_protection_endpoint = format!(
             "/repos/{owner}/{repo}/branches/{branch}/protection",
             owner = repo.organization.get_name(),
             repo = repo.name,
-            branch = "main",
+            branch = params.common.default_branch,
         );
         info!("Enabling branch protection for {}", enforce_branch_protection_endpoint);
         // TODO: This should be a struct that serializes to json instead of just json directly
@@ -321,6 +349,334 @@ impl GithubAPIBundleHandler {
             apis,
         })
     }
+
+    async fn generate_code_review(
+        &self,
+        params: &APIBundleFacetParams,
+        repo: &InitializedGithubRepo,
+    ) -> Result<APIBundleFacet, SkootError> {
+        // Users can't approve their own changes, so requiring reviews would leave a repo owned by a user with
+        // changes that can never be merged.
+        if let GithubUser::User(user) = &repo.organization {
+            warn!("Not requiring code review for {} since it is owned by the user {}", repo.name, user);
+            return Ok(APIBundleFacet {
+                facet_type: SupportedFacetType::CodeReview,
+                apis: vec![],
+            });
+        }
+        // This updates the protection of the branch, so it has to come after the BranchProtection facet.
+        let required_reviews_endpoint = format!(
+            "/repos/{owner}/{repo}/branches/{branch}/protection/required_pull_request_reviews",
+            owner = repo.organization.get_name(),
+            repo = repo.name,
+            branch = params.common.default_branch,
+        );
+        info!("Requiring code review for {}", required_reviews_endpoint);
+        // Skootrs keeps pushing the project's state straight to the branch, so the user it runs as bypasses
+        // the required reviews.
+        let user = octocrab::instance().current().user().await?.login;
+        let code_review = &params.common.code_review;
+        // TODO: This should be a struct that serializes to json instead of just json directly
+        let required_reviews_body = serde_json::json!({
+            "dismiss_stale_reviews": code_review.dismiss_stale_reviews,
+            "require_code_owner_reviews": code_review.require_code_owner_reviews && code_owner(&params.common).is_some(),
+            "required_approving_review_count": code_review.required_approving_review_count,
+            "bypass_pull_request_allowances": {
+                "users": [user],
+            },
+        });
+
+        let response: serde_json::Value = octocrab::instance()
+            .patch(&required_reviews_endpoint, Some(&required_reviews_body))
+            .await?;
+
+        let apis = vec![APIContent {
+            name: "Require Code Review".to_string(),
+            url: required_reviews_endpoint,
+            response: serde_json::to_string_pretty(&response)?,
+        }];
+
+        Ok(APIBundleFacet {
+            facet_type: SupportedFacetType::CodeReview,
+            apis,
+        })
+    }
+}
+
+/// The `GitlabAPIBundleHandler` struct represents a handler for generating an `APIBundleFacet` related to
+/// API calls made to Gitlab, including self-hosted instances.
+struct GitlabAPIBundleHandler {}
+
+impl APIBundleHandler for GitlabAPIBundleHandler {
+    async fn generate(
+        &self,
+        params: &APIBundleFacetParams,
+    ) -> Result<APIBundleFacet, SkootError> {
+        let InitializedRepo::Gitlab(repo) = &params.common.repo else {
+            return Err("Gitlab API bundle facets can only be generated for Gitlab repos".into());
+        };
+        let client = ForgeClient::gitlab(&repo.host_url())?;
+        match params.facet_type {
+            SupportedFacetType::BranchProtection => self.generate_branch_protection(&client, params, repo).await,
+            SupportedFacetType::VulnerabilityReporting => self.generate_vulnerability_reporting(&client, repo).await,
+            SupportedFacetType::CodeReview => self.generate_code_review(&client, params, repo).await,
+            _ => Err(format!("The {} facet isn't supported for Gitlab repos", params.facet_type).into()),
+        }
+    }
+}
+
+impl GitlabAPIBundleHandler {
+    async fn generate_branch_protection(
+        &self,
+        client: &ForgeClient,
+        params: &APIBundleFacetParams,
+        repo: &InitializedGitlabRepo,
+    ) -> Result<APIBundleFacet, SkootError> {
+        let branch = &params.common.default_branch;
+        let protected_branches_endpoint = format!(
+            "{}/protected_branches",
+            ForgeClient::gitlab_project_endpoint(repo),
+        );
+        info!("Enabling branch protection for {}", protected_branches_endpoint);
+        // Gitlab protects the default branch on the first push with the instance's defaults, which would
+        // make creating the protection fail, so any existing protection is replaced.
+        client
+            .delete(&format!("{protected_branches_endpoint}/{}", urlencoding::encode(branch)))
+            .await?;
+        // TODO: This should be a struct that serializes to json instead of just json directly
+        let protect_branch_body = serde_json::json!({
+            "name": branch,
+            // Maintainers
+            "push_access_level": 40,
+            "merge_access_level": 40,
+            "allow_force_push": false,
+        });
+
+        let response = client
+            .post(&protected_branches_endpoint, &protect_branch_body)
+            .await?;
+
+        let apis = vec![APIContent {
+            name: "Enforce Branch Protection".to_string(),
+            url: protected_branches_endpoint,
+            response: serde_json::to_string_pretty(&response)?,
+        }];
+
+        Ok(APIBundleFacet {
+            facet_type: SupportedFacetType::BranchProtection,
+            apis,
+        })
+    }
+
+    async fn generate_vulnerability_reporting(
+        &self,
+        client: &ForgeClient,
+        repo: &InitializedGitlabRepo,
+    ) -> Result<APIBundleFacet, SkootError> {
+        let project_endpoint = ForgeClient::gitlab_project_endpoint(repo);
+        info!("Enabling vulnerability reporting for {}", &project_endpoint);
+        // Gitlab doesn't have an equivalent of Github's private vulnerability reporting. Vulnerabilities are
+        // reported through confidential issues, so issues need to be enabled, and the project's security
+        // findings are limited to project members.
+        let vulnerability_reporting_body = serde_json::json!({
+            "issues_access_level": "enabled",
+            "security_and_compliance_access_level": "private",
+        });
+        let response = client
+            .put(&project_endpoint, &vulnerability_reporting_body)
+            .await?;
+        let apis = vec![APIContent {
+            name: "Enabling vulnerability reporting".to_string(),
+            url: project_endpoint.clone(),
+            response: serde_json::to_string_pretty(&response)?,
+        }];
+        info!("Vulnerability reporting enabled for {}", &project_endpoint);
+
+        Ok(APIBundleFacet {
+            facet_type: SupportedFacetType::VulnerabilityReporting,
+            apis,
+        })
+    }
+
+    async fn generate_code_review(
+        &self,
+        client: &ForgeClient,
+        params: &APIBundleFacetParams,
+        repo: &InitializedGitlabRepo,
+    ) -> Result<APIBundleFacet, SkootError> {
+        let project_endpoint = ForgeClient::gitlab_project_endpoint(repo);
+        info!("Requiring code review for {}", &project_endpoint);
+        let code_review = &params.common.code_review;
+        // Approvals are only needed to merge, so maintainers like Skootrs can still push to the protected branch.
+        // TODO: These should be structs that serialize to json instead of just json directly
+        let approvals_endpoint = format!("{project_endpoint}/approvals");
+        let approvals_response = client
+            .post(
+                &approvals_endpoint,
+                &serde_json::json!({
+                    "reset_approvals_on_push": code_review.dismiss_stale_reviews,
+                }),
+            )
+            .await?;
+        let approval_rules_endpoint = format!("{project_endpoint}/approval_rules");
+        let approval_rules_response = client
+            .post(
+                &approval_rules_endpoint,
+                &serde_json::json!({
+                    "name": "Code review",
+                    "approvals_required": code_review.required_approving_review_count,
+                }),
+            )
+            .await?;
+        // This updates the protection of the branch, so it has to come after the BranchProtection facet.
+        let protected_branch_endpoint = format!(
+            "{project_endpoint}/protected_branches/{}",
+            urlencoding::encode(&params.common.default_branch),
+        );
+        let protected_branch_response = client
+            .patch(
+                &protected_branch_endpoint,
+                &serde_json::json!({
+                    "code_owner_approval_required":
+                        code_review.require_code_owner_reviews && code_owner(&params.common).is_some(),
+                }),
+            )
+            .await?;
+
+        let apis = vec![
+            APIContent {
+                name: "Dismiss Stale Approvals".to_string(),
+                url: approvals_endpoint,
+                response: serde_json::to_string_pretty(&approvals_response)?,
+            },
+            APIContent {
+                name: "Require Approvals".to_string(),
+                url: approval_rules_endpoint,
+                response: serde_json::to_string_pretty(&approval_rules_response)?,
+            },
+            APIContent {
+                name: "Require Code Owner Approval".to_string(),
+                url: protected_branch_endpoint,
+                response: serde_json::to_string_pretty(&protected_branch_response)?,
+            },
+        ];
+
+        Ok(APIBundleFacet {
+            facet_type: SupportedFacetType::CodeReview,
+            apis,
+        })
+    }
+}
+
+/// The `GiteaAPIBundleHandler` struct represents a handler for generating an `APIBundleFacet` related to
+/// API calls made to Gitea or Forgejo.
+struct GiteaAPIBundleHandler {}
+
+impl APIBundleHandler for GiteaAPIBundleHandler {
+    async fn generate(
+        &self,
+        params: &APIBundleFacetParams,
+    ) -> Result<APIBundleFacet, SkootError> {
+        let InitializedRepo::Gitea(repo) = &params.common.repo else {
+            return Err("Gitea API bundle facets can only be generated for Gitea repos".into());
+        };
+        match params.facet_type {
+            SupportedFacetType::BranchProtection => {
+                let client = ForgeClient::gitea(&repo.host_url())?;
+                self.generate_branch_protection(&client, params, repo).await
+            }
+            SupportedFacetType::CodeReview => {
+                let client = ForgeClient::gitea(&repo.host_url())?;
+                self.generate_code_review(&client, params, repo).await
+            }
+            // Gitea doesn't have a private vulnerability reporting feature so there is nothing to enable.
+            SupportedFacetType::VulnerabilityReporting => Ok(APIBundleFacet {
+                facet_type: SupportedFacetType::VulnerabilityReporting,
+                apis: vec![],
+            }),
+            _ => Err(format!("The {} facet isn't supported for Gitea repos", params.facet_type).into()),
+        }
+    }
+}
+
+impl GiteaAPIBundleHandler {
+    async fn generate_branch_protection(
+        &self,
+        client: &ForgeClient,
+        params: &APIBundleFacetParams,
+        repo: &InitializedGiteaRepo,
+    ) -> Result<APIBundleFacet, SkootError> {
+        let branch_protections_endpoint = format!(
+            "/repos/{owner}/{repo}/branch_protections",
+            owner = repo.owner,
+            repo = repo.name,
+        );
+        info!("Enabling branch protection for {}", branch_protections_endpoint);
+        // Protected branches in Gitea can't be force pushed to or deleted. Pushes are still allowed so Skootrs
+        // can keep pushing the project's state.
+        // TODO: This should be a struct that serializes to json instead of just json directly
+        let branch_protection_body = serde_json::json!({
+            "rule_name": params.common.default_branch,
+            "enable_push": true,
+            "block_on_outdated_branch": true,
+        });
+
+        let response = client
+            .post(&branch_protections_endpoint, &branch_protection_body)
+            .await?;
+
+        let apis = vec![APIContent {
+            name: "Enforce Branch Protection".to_string(),
+            url: branch_protections_endpoint,
+            response: serde_json::to_string_pretty(&response)?,
+        }];
+
+        Ok(APIBundleFacet {
+            facet_type: SupportedFacetType::BranchProtection,
+            apis,
+        })
+    }
+
+    async fn generate_code_review(
+        &self,
+        client: &ForgeClient,
+        params: &APIBundleFacetParams,
+        repo: &InitializedGiteaRepo,
+    ) -> Result<APIBundleFacet, SkootError> {
+        // This updates the protection of the branch, so it has to come after the BranchProtection facet.
+        let branch_protection_endpoint = format!(
+            "/repos/{owner}/{repo}/branch_protections/{branch}",
+            owner = repo.owner,
+            repo = repo.name,
+            branch = urlencoding::encode(&params.common.default_branch),
+        );
+        info!("Requiring code review for {}", branch_protection_endpoint);
+        let code_review = &params.common.code_review;
+        // Gitea requests reviews from the code owners of a change, and blocking on those requests is the closest
+        // it has to requiring their approval. Approvals are only needed to merge, so Skootrs can still push.
+        // TODO: This should be a struct that serializes to json instead of just json directly
+        let code_review_body = serde_json::json!({
+            "required_approvals": code_review.required_approving_review_count,
+            "dismiss_stale_approvals": code_review.dismiss_stale_reviews,
+            "block_on_official_review_requests":
+                code_review.require_code_owner_reviews && code_owner(&params.common).is_some(),
+        });
+
+        let response = client
+            .patch(&branch_protection_endpoint, &code_review_body)
+            .await?;
+
+        let apis = vec![APIContent {
+            name: "Require Code Review".to_string(),
+            url: branch_protection_endpoint,
+            response: serde_json::to_string_pretty(&response)?,
+        }];
+
+        Ok(APIBundleFacet {
+            facet_type: SupportedFacetType::CodeReview,
+            apis,
+        })
+    }
 }
 
 /// The `SourceBundleContentGenerator` trait provides an interface for generating the
@@ -348,7 +704,15 @@ impl SourceBundleContentGenerator for DefaultSourceBundleContentHandler {
             SupportedFacetType::Scorecard => self.generate_scorecard_content(params),
             SupportedFacetType::SecurityInsights => self.generate_security_insights_content(params),
             SupportedFacetType::SAST => self.generate_sast_content(params),
-            _ => todo!("Not implemented yet"),
+            SupportedFacetType::SBOMGenerator => self.generate_sbom_generator_content(params),
+            SupportedFacetType::CodeReview => self.generate_code_review_content(params),
+            SupportedFacetType::Allstar => self.generate_allstar_content(params),
+            SupportedFacetType::GUACForwardingConfig => self.generate_guac_forwarding_content(params),
+            SupportedFacetType::VulnerabilityScanner => self.generate_vulnerability_scanner_content(params),
+            SupportedFacetType::DependencyUpdateTool => {
+                self.generate_dependency_update_tool_content(params)
+            }
+            _ => Err(format!("The {} facet isn't one of the default source files", params.facet_type).into()),
         }
     }
 }
@@ -374,34 +738,54 @@ impl DefaultSourceBundleContentHandler {
                 name: "README.md".to_string(),
                 path: "./".to_string(),
                 content,
+                hash: None,
             }],
             facet_type: SupportedFacetType::Readme,
         })
     }
-    // TODO: Support more than Apache 2.0
     fn generate_license_content(
         &self,
         params: &SourceBundleFacetParams,
     ) -> Result<SourceBundleContent, SkootError> {
         #[derive(Template)]
         #[template(path = "LICENSE", escape = "none")]
-        struct LicenseTemplateParams {
+        struct ApacheLicenseTemplateParams {
             project_name: String,
             date: i32,
         }
 
-        let license_template_params = LicenseTemplateParams {
-            project_name: params.common.project_name.clone(),
-            date: chrono::Utc::now().year(),
-        };
+        #[derive(Template)]
+        #[template(path = "LICENSE.MIT", escape = "none")]
+        struct MitLicenseTemplateParams {
+            project_name: String,
+            date: i32,
+        }
 
-        let content = license_template_params.render()?;
+        #[derive(Template)]
+        #[template(path = "LICENSE.BSD-3-Clause", escape = "none")]
+        struct Bsd3ClauseLicenseTemplateParams {
+            project_name: String,
+            date: i32,
+        }
+
+        let project_name = params.common.project_name.clone();
+        let date = chrono::Utc::now().year();
+        let content = match params.common.license {
+            SupportedLicense::Apache2 => {
+                ApacheLicenseTemplateParams { project_name, date }.render()?
+            }
+            SupportedLicense::Mit => MitLicenseTemplateParams { project_name, date }.render()?,
+            SupportedLicense::Bsd3Clause => {
+                Bsd3ClauseLicenseTemplateParams { project_name, date }.render()?
+            }
+        };
 
         Ok(SourceBundleContent {
             source_files_content: vec![SourceFileContent {
                 name: "LICENSE".to_string(),
                 path: "./".to_string(),
                 content,
+                hash: None,
             }],
             facet_type: SupportedFacetType::License,
         })
@@ -424,6 +808,7 @@ impl DefaultSourceBundleContentHandler {
                 name: "SECURITY.md".to_string(),
                 path: "./".to_string(),
                 content,
+                hash: None,
             }],
             facet_type: SupportedFacetType::SecurityPolicy,
         })
@@ -431,14 +816,18 @@ impl DefaultSourceBundleContentHandler {
 
     fn generate_scorecard_content(
         &self,
-        _params: &SourceBundleFacetParams,
+        params: &SourceBundleFacetParams,
     ) -> Result<SourceBundleContent, SkootError> {
         // TODO: This should serialize to yaml instead of just a file template
         #[derive(Template)]
         #[template(path = "scorecard.yml", escape = "none")]
-        struct ScorecardTemplateParams {}
+        struct ScorecardTemplateParams {
+            default_branch: String,
+        }
 
-        let scorecard_template_params = ScorecardTemplateParams {};
+        let scorecard_template_params = ScorecardTemplateParams {
+            default_branch: params.common.default_branch.clone(),
+        };
         let content = scorecard_template_params.render()?;
 
         Ok(SourceBundleContent {
@@ -446,6 +835,7 @@ impl DefaultSourceBundleContentHandler {
                 name: "scorecard.yml".to_string(),
                 path: "./.github/workflows".to_string(),
                 content,
+                hash: None,
             }],
             facet_type: SupportedFacetType::Scorecard,
         })
@@ -456,6 +846,81 @@ impl DefaultSourceBundleContentHandler {
         &self,
         params: &SourceBundleFacetParams,
     ) -> Result<SourceBundleContent, SkootError> {
+        // Goreleaser generates SBOMs for the binaries of Go projects as part of their releases.
+        let has_go_ecosystem = params
+            .common
+            .ecosystems
+            .iter()
+            .any(|ecosystem| matches!(ecosystem.ecosystem, InitializedEcosystem::Go(_)));
+        let mut sboms = if has_go_ecosystem {
+            vec![
+                SecurityInsightsVersion100YamlSchemaDependenciesSbomItem {
+                    sbom_creation: Some(
+                        SecurityInsightsVersion100YamlSchemaDependenciesSbomItemSbomCreation::from_str("Created by goreleaser")?),
+                    sbom_file: Some(format!("{}/releases/latest/download/main-linux-amd64.spdx.sbom.json", &params.common.repo.full_url())), 
+                    sbom_format: Some("SPDX".to_string()),
+                    sbom_url: Some("https://spdx.github.io/spdx-spec/v2.3/".to_string()), 
+                },
+                SecurityInsightsVersion100YamlSchemaDependenciesSbomItem {
+                    sbom_creation: Some(
+                        SecurityInsightsVersion100YamlSchemaDependenciesSbomItemSbomCreation::from_str("Created by goreleaser")?),
+                    sbom_file: Some(format!("{}/releases/latest/download/main-linux-arm.spdx.sbom.json", &params.common.repo.full_url())), 
+                    sbom_format: Some("SPDX".to_string()),
+                    sbom_url: Some("https://spdx.github.io/spdx-spec/v2.3/".to_string()), 
+                },
+                SecurityInsightsVersion100YamlSchemaDependenciesSbomItem {
+                    sbom_creation: Some(
+                        SecurityInsightsVersion100YamlSchemaDependenciesSbomItemSbomCreation::from_str("Created by goreleaser")?),
+                    sbom_file: Some(format!("{}/releases/latest/download/main-linux-arm64.spdx.sbom.json", &params.common.repo.full_url())), 
+                    sbom_format: Some("SPDX".to_string()),
+                    sbom_url: Some("https://spdx.github.io/spdx-spec/v2.3/".to_string()), 
+                },
+                SecurityInsightsVersion100YamlSchemaDependenciesSbomItem {
+                    sbom_creation: Some(
+                        SecurityInsightsVersion100YamlSchemaDependenciesSbomItemSbomCreation::from_str("Created by goreleaser")?),
+                    sbom_file: Some(format!("{}/releases/latest/download/main-windows-amd64.exe.spdx.sbom.json", &params.common.repo.full_url())), 
+                    sbom_format: Some("SPDX".to_string()),
+                    sbom_url: Some("https://spdx.github.io/spdx-spec/v2.3/".to_string()), 
+                },
+                SecurityInsightsVersion100YamlSchemaDependenciesSbomItem {
+                    sbom_creation: Some(
+                        SecurityInsightsVersion100YamlSchemaDependenciesSbomItemSbomCreation::from_str("Created by goreleaser")?),
+                    sbom_file: Some(format!("{}/releases/latest/download/main.spdx.sbom.json", &params.common.repo.full_url())), 
+                    sbom_format: Some("SPDX".to_string()),
+                    sbom_url: Some("https://spdx.github.io/spdx-spec/v2.3/".to_string()), 
+                },
+            ]
+        } else {
+            Vec::new()
+        };
+        // The syft SBOMs are only released if the SBOM generator workflow was created.
+        let syft_sboms = if params.common.facets.contains(&SupportedFacetType::SBOMGenerator) {
+            sbom_targets(params)
+        } else {
+            Vec::new()
+        };
+        for sbom in syft_sboms {
+            sboms.push(SecurityInsightsVersion100YamlSchemaDependenciesSbomItem {
+                sbom_creation: Some(
+                    SecurityInsightsVersion100YamlSchemaDependenciesSbomItemSbomCreation::from_str("Created by syft")?),
+                sbom_file: Some(format!("{}/releases/latest/download/{}.spdx.json", params.common.repo.full_url(), sbom.name)),
+                sbom_format: Some("SPDX".to_string()),
+                sbom_url: Some("https://spdx.github.io/spdx-spec/v2.3/".to_string()),
+            });
+            sboms.push(SecurityInsightsVersion100YamlSchemaDependenciesSbomItem {
+                sbom_creation: Some(
+                    SecurityInsightsVersion100YamlSchemaDependenciesSbomItemSbomCreation::from_str("Created by syft")?),
+                sbom_file: Some(format!("{}/releases/latest/download/{}.cyclonedx.json", params.common.repo.full_url(), sbom.name)),
+                sbom_format: Some("CycloneDX".to_string()),
+                sbom_url: Some("https://cyclonedx.org/specification/overview/".to_string()),
+            });
+        }
+        // The scanners only need to be listed if their workflow was created.
+        let security_testing = if params.common.facets.contains(&SupportedFacetType::VulnerabilityScanner) {
+            vulnerability_scanner_security_testing(params)?
+        } else {
+            Vec::new()
+        };
         let insights = SecurityInsightsVersion100YamlSchema {
             contribution_policy: SecurityInsightsVersion100YamlSchemaContributionPolicy {
                 accepts_automated_pull_requests: true,
@@ -466,47 +931,21 @@ impl DefaultSourceBundleContentHandler {
             },
             dependencies: Some(SecurityInsightsVersion100YamlSchemaDependencies{
                 dependencies_lifecycle: None,
-                dependencies_lists: vec![
-                    format!("{}/blob/main/go.mod", &params.common.repo.full_url())
-                ],
+                dependencies_lists: params
+                    .common
+                    .ecosystems
+                    .iter()
+                    .map(|ecosystem| {
+                        format!(
+                            "{}/blob/{}/{}",
+                            &params.common.repo.full_url(),
+                            params.common.default_branch,
+                            ecosystem.dependency_manifest()
+                        )
+                    })
+                    .collect(),
                 env_dependencies_policy: None,
-                sbom: Some(vec![
-                    SecurityInsightsVersion100YamlSchemaDependenciesSbomItem {
-                        sbom_creation: Some(
-                            SecurityInsightsVersion100YamlSchemaDependenciesSbomItemSbomCreation::from_str("Created by goreleaser")?),
-                        sbom_file: Some(format!("{}/releases/latest/download/main-linux-amd64.spdx.sbom.json", &params.common.repo.full_url())), 
-                        sbom_format: Some("SPDX".to_string()),
-                        sbom_url: Some("https://spdx.github.io/spdx-spec/v2.3/".to_string()), 
-                    },
-                    SecurityInsightsVersion100YamlSchemaDependenciesSbomItem {
-                        sbom_creation: Some(
-                            SecurityInsightsVersion100YamlSchemaDependenciesSbomItemSbomCreation::from_str("Created by goreleaser")?),
-                        sbom_file: Some(format!("{}/releases/latest/download/main-linux-arm.spdx.sbom.json", &params.common.repo.full_url())), 
-                        sbom_format: Some("SPDX".to_string()),
-                        sbom_url: Some("https://spdx.github.io/spdx-spec/v2.3/".to_string()), 
-                    },
-                    SecurityInsightsVersion100YamlSchemaDependenciesSbomItem {
-                        sbom_creation: Some(
-                            SecurityInsightsVersion100YamlSchemaDependenciesSbomItemSbomCreation::from_str("Created by goreleaser")?),
-                        sbom_file: Some(format!("{}/releases/latest/download/main-linux-arm64.spdx.sbom.json", &params.common.repo.full_url())), 
-                        sbom_format: Some("SPDX".to_string()),
-                        sbom_url: Some("https://spdx.github.io/spdx-spec/v2.3/".to_string()), 
-                    },
-                    SecurityInsightsVersion100YamlSchemaDependenciesSbomItem {
-                        sbom_creation: Some(
-                            SecurityInsightsVersion100YamlSchemaDependenciesSbomItemSbomCreation::from_str("Created by goreleaser")?),
-                        sbom_file: Some(format!("{}/releases/latest/download/main-windows-amd64.exe.spdx.sbom.json", &params.common.repo.full_url())), 
-                        sbom_format: Some("SPDX".to_string()),
-                        sbom_url: Some("https://spdx.github.io/spdx-spec/v2.3/".to_string()), 
-                    },
-                    SecurityInsightsVersion100YamlSchemaDependenciesSbomItem {
-                        sbom_creation: Some(
-                            SecurityInsightsVersion100YamlSchemaDependenciesSbomItemSbomCreation::from_str("Created by goreleaser")?),
-                        sbom_file: Some(format!("{}/releases/latest/download/main.spdx.sbom.json", &params.common.repo.full_url())), 
-                        sbom_format: Some("SPDX".to_string()),
-                        sbom_url: Some("https://spdx.github.io/spdx-spec/v2.3/".to_string()), 
-                    },
-                ]),
+                sbom: Some(sboms).filter(|sboms| !sboms.is_empty()),
                 third_party_packages: Some(true),
             }),
             distribution_points: Vec::new(),
@@ -518,8 +957,9 @@ impl DefaultSourceBundleContentHandler {
                 last_reviewed: Some(chrono::Utc::now()),
                 last_updated: Some(chrono::Utc::now()),
                 license: Some(format!(
-                    "{}/blob/main/LICENSE",
-                    &params.common.repo.full_url()
+                    "{}/blob/{}/LICENSE",
+                    &params.common.repo.full_url(),
+                    params.common.default_branch
                 )),
                 project_release: None,
                 project_url: params.common.repo.full_url(),
@@ -538,7 +978,7 @@ impl DefaultSourceBundleContentHandler {
             security_artifacts: None,
             security_assessments: None,
             security_contacts: Vec::new(),
-            security_testing: Vec::new(),
+            security_testing,
             vulnerability_reporting: SecurityInsightsVersion100YamlSchemaVulnerabilityReporting {
                 accepts_vulnerability_reports: true,
                 bug_bounty_available: None,
@@ -548,7 +988,11 @@ impl DefaultSourceBundleContentHandler {
                 in_scope: None,
                 out_scope: None,
                 pgp_key: None,
-                security_policy: Some(format!("{}/blob/main/SECURITY.md", &params.common.repo.full_url())),
+                security_policy: Some(format!(
+                    "{}/blob/{}/SECURITY.md",
+                    &params.common.repo.full_url(),
+                    params.common.default_branch
+                )),
             },
         };
 
@@ -559,6 +1003,7 @@ impl DefaultSourceBundleContentHandler {
                 name: "SECURITY-INSIGHTS.yml".to_string(),
                 path: "./".to_string(),
                 content,
+                hash: None,
             }],
             facet_type: SupportedFacetType::SecurityInsights,
         })
@@ -566,13 +1011,36 @@ impl DefaultSourceBundleContentHandler {
 
     fn generate_sast_content(
         &self,
-        _params: &SourceBundleFacetParams,
+        params: &SourceBundleFacetParams,
     ) -> Result<SourceBundleContent, SkootError> {
         #[derive(Template)]
         #[template(path = "codeql.yml", escape = "none")]
-        struct SASTTemplateParams {}
+        struct SASTTemplateParams {
+            default_branch: String,
+            languages: Vec<String>,
+            setup_go: bool,
+        }
 
-        let sast_template_params = SASTTemplateParams {};
+        // These are the names CodeQL uses for the languages it analyzes. Ecosystems of the same language
+        // are analyzed together.
+        let mut languages: Vec<String> = Vec::new();
+        for ecosystem in &params.common.ecosystems {
+            let language = match ecosystem.ecosystem {
+                InitializedEcosystem::Go(_) => "go",
+                InitializedEcosystem::Maven(_) | InitializedEcosystem::Gradle(_) => "java-kotlin",
+                InitializedEcosystem::Cargo(_) => "rust",
+                InitializedEcosystem::Npm(_) => "javascript-typescript",
+                InitializedEcosystem::Python(_) => "python",
+            };
+            if !languages.iter().any(|l| l == language) {
+                languages.push(language.to_string());
+            }
+        }
+        let sast_template_params = SASTTemplateParams {
+            default_branch: params.common.default_branch.clone(),
+            setup_go: languages.iter().any(|l| l == "go"),
+            languages,
+        };
         let content = sast_template_params.render()?;
 
         Ok(SourceBundleContent {
@@ -580,93 +1048,701 @@ impl DefaultSourceBundleContentHandler {
                 name: "codeql.yml".to_string(),
                 path: "./.github/workflows".to_string(),
                 content,
+                hash: None,
             }],
             facet_type: SupportedFacetType::SAST,
         })
     }
-}
-
-/// Handles the generation of source files content specific to Go projects hosted on Github.
-/// e.g. Github actions running goreleaser
-struct GoGithubSourceBundleContentHandler {}
 
-impl SourceBundleContentGenerator for GoGithubSourceBundleContentHandler {
-    fn generate_content(
+    fn generate_dependency_update_tool_content(
         &self,
         params: &SourceBundleFacetParams,
     ) -> Result<SourceBundleContent, SkootError> {
-        match params.facet_type {
-            SupportedFacetType::Gitignore => self.generate_gitignore_content(params),
-            // TODO: Rename this to something like SecureBuild.
-            // This also does a bunch of other stuff like setting up releases, generating SBOM, etc.
-            // So for now just we just use it instead of creating multiple facets.
-            // The better option is to probably set up some mapping of properties like SLSA, SBOMGenerating, etc.
-            // to a single SecureBuild facet.
-            SupportedFacetType::SLSABuild => self.generate_slsa_build_content(params),
-            SupportedFacetType::DependencyUpdateTool => {
-                self.generate_dependency_update_tool_content(params)
-            }
-            SupportedFacetType::Fuzzing => self.generate_fuzzing_content(params),
-            SupportedFacetType::DefaultSourceCode => {
-                self.generate_default_source_code_content(params)
-            }
-            _ => todo!("Not implemented yet"),
+        struct DependabotUpdate {
+            ecosystem: String,
+            directory: String,
         }
-    }
-}
-impl GoGithubSourceBundleContentHandler {
-    fn generate_gitignore_content(
-        &self,
-        _params: &SourceBundleFacetParams,
-    ) -> Result<SourceBundleContent, SkootError> {
+
         #[derive(Template)]
-        #[template(path = "go.gitignore", escape = "none")]
-        struct GitignoreTemplateParams {}
+        #[template(path = "dependabot.yml", escape = "none")]
+        struct DependabotTemplateParams {
+            updates: Vec<DependabotUpdate>,
+        }
 
-        let gitignore_template_params = GitignoreTemplateParams {};
-        let content = gitignore_template_params.render()?;
+        // These are the names Dependabot uses for the package ecosystems it updates.
+        let updates = params
+            .common
+            .ecosystems
+            .iter()
+            .map(|ecosystem| {
+                let package_ecosystem = match ecosystem.ecosystem {
+                    InitializedEcosystem::Go(_) => "gomod",
+                    InitializedEcosystem::Maven(_) => "maven",
+                    InitializedEcosystem::Gradle(_) => "gradle",
+                    InitializedEcosystem::Cargo(_) => "cargo",
+                    InitializedEcosystem::Npm(_) => "npm",
+                    InitializedEcosystem::Python(_) => "pip",
+                };
+                DependabotUpdate {
+                    ecosystem: package_ecosystem.to_string(),
+                    directory: format!("/{}", ecosystem.directory()),
+                }
+            })
+            .collect();
+        let dependabot_template_params = DependabotTemplateParams { updates };
+        let content = dependabot_template_params.render()?;
 
         Ok(SourceBundleContent {
             source_files_content: vec![SourceFileContent {
-                name: ".gitignore".to_string(),
-                path: "./".to_string(),
+                name: "dependabot.yml".to_string(),
+                path: ".github/".to_string(),
                 content,
+                hash: None,
             }],
-            facet_type: SupportedFacetType::Gitignore,
+            facet_type: SupportedFacetType::DependencyUpdateTool,
         })
     }
-    // Note: GoReleaser also does a bunch of other stuff like setting up releases, generating SBOM, etc.
-    // So for now just we just use it instead of creating multiple facets.
-    // Note: Content mostly taken from https://github.com/guacsec/guac/blob/f1703bd4ca3c0ec0fa55c5a3401d50578fb1680e/.github/workflows/release.yaml
-    fn generate_slsa_build_content(
+
+    /// Generates the Allstar config that opts the repo in to Allstar along with its policies. The policies
+    /// match the facets applied to the project, so Allstar enforces what Skootrs set up. Allstar is a Github
+    /// app, and it has to be installed for the config to take effect.
+    fn generate_allstar_content(
         &self,
         params: &SourceBundleFacetParams,
     ) -> Result<SourceBundleContent, SkootError> {
-        // TODO: This should really be a struct that serializes to yaml instead of just a file template
         #[derive(Template)]
-        #[template(path = "go.releases.yml", escape = "none")]
-        struct ReleaseTemplateParams {}
+        #[template(path = "allstar.yaml", escape = "none")]
+        struct AllstarTemplateParams {}
 
         #[derive(Template)]
-        #[template(path = "Dockerfile.goreleaser", escape = "none")]
-        struct DockerfileTemplateParams {
-            project_name: String,
+        #[template(path = "allstar.branch_protection.yaml", escape = "none")]
+        struct BranchProtectionTemplateParams {
+            opt_config: &'static str,
+            require_approval: bool,
+            approval_count: u8,
+            dismiss_stale: bool,
+            require_code_owner_reviews: bool,
         }
 
         #[derive(Template)]
-        #[template(path = "goreleaser.yml", escape = "none")]
-        struct GoReleaserTemplateParams {
-            project_name: String,
+        #[template(path = "allstar.binary_artifacts.yaml", escape = "none")]
+        struct BinaryArtifactsTemplateParams {
+            ignore_paths: Vec<String>,
+        }
+
+        #[derive(Template)]
+        #[template(path = "allstar.outside.yaml", escape = "none")]
+        struct OutsideTemplateParams {}
+
+        #[derive(Template)]
+        #[template(path = "allstar.security.yaml", escape = "none")]
+        struct SecurityTemplateParams {
+            opt_config: &'static str,
+        }
+
+        let InitializedRepo::Github(repo) = &params.common.repo else {
+            warn!(
+                "Not creating an Allstar config for {} since Allstar only supports Github",
+                params.common.project_name
+            );
+            return Ok(SourceBundleContent {
+                source_files_content: vec![],
+                facet_type: SupportedFacetType::Allstar,
+            });
+        };
+        let facets = &params.common.facets;
+        // Policies for facets that weren't applied are opted out of, so Allstar doesn't enforce them.
+        let opt_config = |facet_type: SupportedFacetType| {
+            if facets.contains(&facet_type) {
+                "optIn"
+            } else {
+                "optOut"
+            }
+        };
+        let code_review = &params.common.code_review;
+        // Code review isn't required for repos owned by a user, since they can't approve their own changes.
+        let require_approval = facets.contains(&SupportedFacetType::CodeReview)
+            && matches!(repo.organization, GithubUser::Organization(_));
+        let branch_protection = BranchProtectionTemplateParams {
+            opt_config: opt_config(SupportedFacetType::BranchProtection),
+            require_approval,
+            approval_count: code_review.required_approving_review_count,
+            dismiss_stale: require_approval && code_review.dismiss_stale_reviews,
+            require_code_owner_reviews: require_approval
+                && code_review.require_code_owner_reviews
+                && code_owner(&params.common).is_some(),
+        };
+        // The Gradle wrapper jar is a binary, but it is published by Gradle and verified by the wrapper itself.
+        let binary_artifacts = BinaryArtifactsTemplateParams {
+            ignore_paths: params
+                .common
+                .ecosystems
+                .iter()
+                .filter(|ecosystem| matches!(ecosystem.ecosystem, InitializedEcosystem::Gradle(_)))
+                .map(|ecosystem| match ecosystem.directory() {
+                    "" => "gradle/wrapper/gradle-wrapper.jar".to_string(),
+                    directory => format!("{directory}/gradle/wrapper/gradle-wrapper.jar"),
+                })
+                .collect(),
+        };
+        let security = SecurityTemplateParams {
+            opt_config: opt_config(SupportedFacetType::SecurityPolicy),
+        };
+
+        let source_files_content = [
+            ("allstar.yaml", AllstarTemplateParams {}.render()?),
+            ("branch_protection.yaml", branch_protection.render()?),
+            ("binary_artifacts.yaml", binary_artifacts.render()?),
+            ("outside.yaml", OutsideTemplateParams {}.render()?),
+            ("security.yaml", security.render()?),
+        ]
+        .into_iter()
+        .map(|(name, content)| SourceFileContent {
+            name: name.to_string(),
+            path: "./.allstar".to_string(),
+            content,
+            hash: None,
+        })
+        .collect();
+
+        Ok(SourceBundleContent {
+            source_files_content,
+            facet_type: SupportedFacetType::Allstar,
+        })
+    }
+
+    /// Generates the workflow that scans the dependencies of every ecosystem with OSV-Scanner, along with
+    /// govulncheck for Go modules, and uploads the results to code scanning.
+    fn generate_vulnerability_scanner_content(
+        &self,
+        params: &SourceBundleFacetParams,
+    ) -> Result<SourceBundleContent, SkootError> {
+        #[derive(Template)]
+        #[template(path = "vulnerability-scanner.yml", escape = "none")]
+        struct VulnerabilityScannerTemplateParams {
+            default_branch: String,
+            directories: Vec<String>,
+            go_modules: Vec<GoModule>,
+        }
+
+        struct GoModule {
+            /// The name of the module's job and results, e.g. `govulncheck-backend`.
+            name: String,
+            working_directory: String,
+        }
+
+        let mut directories: Vec<String> = Vec::new();
+        for ecosystem in &params.common.ecosystems {
+            let working_directory = working_directory(ecosystem);
+            if !directories.contains(&working_directory) {
+                directories.push(working_directory);
+            }
+        }
+        let go_modules = params
+            .common
+            .ecosystems
+            .iter()
+            .filter(|ecosystem| matches!(ecosystem.ecosystem, InitializedEcosystem::Go(_)))
+            .map(|ecosystem| GoModule {
+                name: match ecosystem.directory() {
+                    "" => "govulncheck".to_string(),
+                    directory => format!("govulncheck-{}", directory.replace('/', "-")),
+                },
+                working_directory: working_directory(ecosystem),
+            })
+            .collect();
+        let content = VulnerabilityScannerTemplateParams {
+            default_branch: params.common.default_branch.clone(),
+            directories,
+            go_modules,
+        }
+        .render()?;
+
+        Ok(SourceBundleContent {
+            source_files_content: vec![SourceFileContent {
+                name: "vulnerability-scanner.yml".to_string(),
+                path: "./.github/workflows".to_string(),
+                content,
+                hash: None,
+            }],
+            facet_type: SupportedFacetType::VulnerabilityScanner,
+        })
+    }
+
+    /// Generates the workflow that forwards the SBOMs, SLSA attestations, and Scorecard results of each
+    /// release to the GUAC collector in the Skootrs config.
+    fn generate_guac_forwarding_content(
+        &self,
+        params: &SourceBundleFacetParams,
+    ) -> Result<SourceBundleContent, SkootError> {
+        #[derive(Template)]
+        #[template(path = "guac.yml", escape = "none")]
+        struct GuacForwardingTemplateParams<'a> {
+            endpoint: &'a str,
+            credentials_secret: &'a str,
+        }
+
+        let Some(guac_forwarding) = &params.common.guac_forwarding else {
+            warn!(
+                "Not forwarding the releases of {} to GUAC since there is no GUAC collector configured",
+                params.common.project_name
+            );
+            return Ok(SourceBundleContent {
+                source_files_content: vec![],
+                facet_type: SupportedFacetType::GUACForwardingConfig,
+            });
+        };
+        let content = GuacForwardingTemplateParams {
+            endpoint: &guac_forwarding.endpoint,
+            credentials_secret: &guac_forwarding.credentials_secret,
+        }
+        .render()?;
+
+        Ok(SourceBundleContent {
+            source_files_content: vec![SourceFileContent {
+                name: "guac.yml".to_string(),
+                path: "./.github/workflows".to_string(),
+                content,
+                hash: None,
+            }],
+            facet_type: SupportedFacetType::GUACForwardingConfig,
+        })
+    }
+
+    fn generate_code_review_content(
+        &self,
+        params: &SourceBundleFacetParams,
+    ) -> Result<SourceBundleContent, SkootError> {
+        #[derive(Template)]
+        #[template(path = "CODEOWNERS", escape = "none")]
+        struct CodeOwnersTemplateParams {
+            code_owner: String,
+        }
+
+        let Some(code_owner) = code_owner(&params.common) else {
+            warn!(
+                "Not creating a CODEOWNERS file for {} since it has no owning team",
+                params.common.project_name
+            );
+            return Ok(SourceBundleContent {
+                source_files_content: vec![],
+                facet_type: SupportedFacetType::CodeReview,
+            });
+        };
+        let content = CodeOwnersTemplateParams { code_owner }.render()?;
+        // Each forge also looks for the file in a directory of its own.
+        let path = match params.common.repo {
+            InitializedRepo::Github(_) => "./.github",
+            InitializedRepo::Gitlab(_) => "./.gitlab",
+            InitializedRepo::Gitea(_) => "./.gitea",
+            InitializedRepo::Local(_) => "./",
+        };
+
+        Ok(SourceBundleContent {
+            source_files_content: vec![SourceFileContent {
+                name: "CODEOWNERS".to_string(),
+                path: path.to_string(),
+                content,
+                hash: None,
+            }],
+            facet_type: SupportedFacetType::CodeReview,
+        })
+    }
+
+    fn generate_sbom_generator_content(
+        &self,
+        params: &SourceBundleFacetParams,
+    ) -> Result<SourceBundleContent, SkootError> {
+        #[derive(Template)]
+        #[template(path = "sbom.yml", escape = "none")]
+        struct SBOMGeneratorTemplateParams {
+            default_branch: String,
+            sboms: Vec<SbomTarget>,
+        }
+
+        let sbom_generator_template_params = SBOMGeneratorTemplateParams {
+            default_branch: params.common.default_branch.clone(),
+            sboms: sbom_targets(params),
+        };
+        let content = sbom_generator_template_params.render()?;
+
+        Ok(SourceBundleContent {
+            source_files_content: vec![SourceFileContent {
+                name: "sbom.yml".to_string(),
+                path: "./.github/workflows".to_string(),
+                content,
+                hash: None,
+            }],
+            facet_type: SupportedFacetType::SBOMGenerator,
+        })
+    }
+}
+
+/// Returns the code owner of the project the way CODEOWNERS files refer to it, e.g. `@kusaridev/security`. This
+/// is the project's owning team, or the user or group that owns the repo if it doesn't have one.
+fn code_owner(common: &CommonFacetParams) -> Option<String> {
+    if let Some(owning_team) = &common.code_review.owning_team {
+        return Some(format!("@{}", owning_team.trim_start_matches('@')));
+    }
+    match &common.repo {
+        InitializedRepo::Github(repo) => match &repo.organization {
+            GithubUser::User(user) => Some(format!("@{user}")),
+            // Only the teams of an organization can own code, not the organization itself.
+            GithubUser::Organization(_) => None,
+        },
+        InitializedRepo::Gitlab(repo) => Some(format!("@{}", repo.namespace)),
+        InitializedRepo::Gitea(repo) => Some(format!("@{}", repo.owner)),
+        InitializedRepo::Local(_) => None,
+    }
+}
+
+/// Returns the security testing entries of the scanners the `VulnerabilityScanner` facet runs.
+fn vulnerability_scanner_security_testing(
+    params: &SourceBundleFacetParams,
+) -> Result<Vec<SecurityInsightsVersion100YamlSchemaSecurityTestingItem>, SkootError> {
+    let integration = SecurityInsightsVersion100YamlSchemaSecurityTestingItemIntegration {
+        ad_hoc: false,
+        before_release: true,
+        ci: true,
+    };
+    let workflow_url = format!(
+        "{}/actions/workflows/vulnerability-scanner.yml",
+        params.common.repo.full_url()
+    );
+    let mut security_testing = vec![SecurityInsightsVersion100YamlSchemaSecurityTestingItem {
+        comment: Some(SecurityInsightsVersion100YamlSchemaSecurityTestingItemComment::from_str(
+            "Scans the dependencies of every ecosystem for known vulnerabilities on each change and weekly.",
+        )?),
+        integration: integration.clone(),
+        tool_name: "OSV-Scanner".to_string(),
+        tool_rulesets: None,
+        tool_type: SecurityInsightsVersion100YamlSchemaSecurityTestingItemToolType::Sca,
+        tool_url: Some(workflow_url.clone()),
+        tool_version: "1.6.2".to_string(),
+    }];
+    let has_go_ecosystem = params
+        .common
+        .ecosystems
+        .iter()
+        .any(|ecosystem| matches!(ecosystem.ecosystem, InitializedEcosystem::Go(_)));
+    if has_go_ecosystem {
+        security_testing.push(SecurityInsightsVersion100YamlSchemaSecurityTestingItem {
+            comment: Some(SecurityInsightsVersion100YamlSchemaSecurityTestingItemComment::from_str(
+                "Scans the Go modules for known vulnerabilities in the code they call on each change and weekly.",
+            )?),
+            integration,
+            tool_name: "govulncheck".to_string(),
+            tool_rulesets: None,
+            tool_type: SecurityInsightsVersion100YamlSchemaSecurityTestingItemToolType::Sca,
+            tool_url: Some(workflow_url),
+            tool_version: "1.1.0".to_string(),
+        });
+    }
+
+    Ok(security_testing)
+}
+
+/// The SBOMs generated for the ecosystems in one of the project's directories.
+struct SbomTarget {
+    /// The name of the SBOM files without their extension, e.g. `test-backend`.
+    name: String,
+    /// The directory the SBOMs are generated from, i.e. the ecosystems' directory or `.` for the root.
+    working_directory: String,
+}
+
+/// Returns the SBOMs to generate for the project. There is one for each directory with ecosystems in it,
+/// since the SBOM of a directory covers all of the ecosystems in it.
+fn sbom_targets(params: &SourceBundleFacetParams) -> Vec<SbomTarget> {
+    let mut sboms: Vec<SbomTarget> = Vec::new();
+    for ecosystem in &params.common.ecosystems {
+        let working_directory = working_directory(ecosystem);
+        if sboms.iter().any(|sbom| sbom.working_directory == working_directory) {
+            continue;
+        }
+        let name = match ecosystem.directory() {
+            "" => params.common.project_name.clone(),
+            directory => format!("{}-{}", params.common.project_name, directory.replace('/', "-")),
+        };
+        sboms.push(SbomTarget {
+            name,
+            working_directory,
+        });
+    }
+
+    sboms
+}
+
+/// Handles the generation of source files content specific to the project's ecosystems by delegating to
+/// the handler of each ecosystem. Files outside of `.github` are put in the ecosystem's directory, and
+/// workflows of ecosystems in a subdirectory are named after it, e.g. `releases-backend.yml`.
+struct EcosystemsSourceBundleContentHandler {}
+
+impl SourceBundleContentGenerator for EcosystemsSourceBundleContentHandler {
+    fn generate_content(
+        &self,
+        params: &SourceBundleFacetParams,
+    ) -> Result<SourceBundleContent, SkootError> {
+        let mut source_files_content: Vec<SourceFileContent> = Vec::new();
+        for ecosystem in &params.common.ecosystems {
+            let ecosystem_source_bundle_content_handler: &dyn SourceBundleContentGenerator =
+                match ecosystem.ecosystem {
+                    InitializedEcosystem::Go(_) => &GoGithubSourceBundleContentHandler { ecosystem },
+                    InitializedEcosystem::Maven(_) => &MavenGithubSourceBundleContentHandler { ecosystem },
+                    InitializedEcosystem::Gradle(_) => &GradleGithubSourceBundleContentHandler { ecosystem },
+                    InitializedEcosystem::Cargo(_) => &RustGithubSourceBundleContentHandler { ecosystem },
+                    InitializedEcosystem::Npm(_) => &NodeGithubSourceBundleContentHandler { ecosystem },
+                    InitializedEcosystem::Python(_) => &PythonGithubSourceBundleContentHandler { ecosystem },
+                };
+            let content = ecosystem_source_bundle_content_handler.generate_content(params)?;
+            for file in content.source_files_content {
+                let file = self.in_ecosystem(ecosystem, file);
+                let existing = source_files_content.iter_mut().find(|existing| {
+                    existing.name == file.name && normalize_path(&existing.path) == normalize_path(&file.path)
+                });
+                match existing {
+                    // Ecosystems in the same directory share its gitignore.
+                    Some(existing) if existing.name == ".gitignore" => {
+                        existing.content.push('\n');
+                        existing.content.push_str(&file.content);
+                    }
+                    // Ecosystems in the same directory each get their own workflows, e.g. `releases-npm.yml`.
+                    Some(_) if normalize_path(&file.path).starts_with(".github/workflows") => {
+                        let file = SourceFileContent {
+                            name: with_suffix(&file.name, ecosystem.ecosystem.kind()),
+                            ..file
+                        };
+                        if source_files_content.iter().any(|existing| existing.name == file.name) {
+                            return Err(format!("More than one ecosystem generated the workflow {}", file.name).into());
+                        }
+                        source_files_content.push(file);
+                    }
+                    Some(existing) => {
+                        return Err(format!(
+                            "More than one ecosystem generated {} in {}",
+                            existing.name, existing.path
+                        )
+                        .into());
+                    }
+                    None => source_files_content.push(file),
+                }
+            }
+        }
+
+        Ok(SourceBundleContent {
+            source_files_content,
+            facet_type: params.facet_type.clone(),
+        })
+    }
+}
+
+impl EcosystemsSourceBundleContentHandler {
+    fn in_ecosystem(
+        &self,
+        ecosystem: &InitializedProjectEcosystem,
+        source_file_content: SourceFileContent,
+    ) -> SourceFileContent {
+        let directory = ecosystem.directory();
+        if directory.is_empty() {
+            return source_file_content;
+        }
+        if !normalize_path(&source_file_content.path).starts_with(".github") {
+            return SourceFileContent {
+                path: ecosystem.path_in_ecosystem(&source_file_content.path),
+                ..source_file_content
+            };
+        }
+        if !normalize_path(&source_file_content.path).starts_with(".github/workflows") {
+            return source_file_content;
+        }
+        SourceFileContent {
+            name: with_suffix(&source_file_content.name, &directory.replace('/', "-")),
+            ..source_file_content
+        }
+    }
+}
+
+/// Handles the generation of the content of the `PinnedDependencies` facet by pinning the workflows and
+/// Dockerfiles in the project's source. This rewrites the files written by the other facets, so it has to come
+/// after them.
+struct PinnedDependenciesSourceBundleContentHandler {}
+
+impl SourceBundleContentGenerator for PinnedDependenciesSourceBundleContentHandler {
+    fn generate_content(
+        &self,
+        params: &SourceBundleFacetParams,
+    ) -> Result<SourceBundleContent, SkootError> {
+        let dependency_pinner = DependencyPinner::bundled()?;
+        let source_service = LocalSourceService::default();
+        let mut source_files_content = Vec::new();
+        for (path, name) in self.pinnable_files(params)? {
+            let content = source_service.read_file(&params.common.source, &path, name.clone())?;
+            let content = if name.starts_with("Dockerfile") {
+                dependency_pinner.pin_dockerfile(&content)
+            } else {
+                dependency_pinner.pin_workflow(&content)
+            };
+            source_files_content.push(SourceFileContent {
+                name,
+                path,
+                content,
+                hash: None,
+            });
+        }
+
+        Ok(SourceBundleContent {
+            source_files_content,
+            facet_type: SupportedFacetType::PinnedDependencies,
+        })
+    }
+}
+
+impl PinnedDependenciesSourceBundleContentHandler {
+    /// Returns the (path, name) pairs of the workflows in the project's source along with the Dockerfiles in the
+    /// directories of its ecosystems.
+    fn pinnable_files(&self, params: &SourceBundleFacetParams) -> Result<Vec<(String, String)>, SkootError> {
+        let mut directories = vec![("./.github/workflows".to_string(), false)];
+        for ecosystem in &params.common.ecosystems {
+            let directory = ecosystem.path_in_ecosystem("./");
+            if !directories.iter().any(|(existing, _)| normalize_path(existing) == normalize_path(&directory)) {
+                directories.push((directory, true));
+            }
+        }
+
+        let mut files = Vec::new();
+        for (directory, is_ecosystem_directory) in directories {
+            let full_path = Path::new(&params.common.source.path).join(&directory);
+            if !full_path.is_dir() {
+                continue;
+            }
+            let mut names = fs::read_dir(full_path)?
+                .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+                .filter(|name| {
+                    if is_ecosystem_directory {
+                        name == "Dockerfile" || name.starts_with("Dockerfile.")
+                    } else {
+                        Path::new(name)
+                            .extension()
+                            .is_some_and(|extension| extension == "yml" || extension == "yaml")
+                    }
+                })
+                .collect::<Vec<String>>();
+            names.sort();
+            files.extend(names.into_iter().map(|name| (directory.clone(), name)));
+        }
+
+        Ok(files)
+    }
+}
+
+/// Returns a path relative to the root of the project's source without any leading `./` or trailing `/`, so
+/// paths like `./.github/workflows` and `.github/workflows/` can be compared.
+pub(crate) fn normalize_path(path: &str) -> &str {
+    path.trim_start_matches("./").trim_end_matches('/')
+}
+
+/// Returns the file name with the suffix added before its extension, e.g. `releases-backend.yml`.
+fn with_suffix(name: &str, suffix: &str) -> String {
+    match name.rsplit_once('.') {
+        Some((stem, extension)) => format!("{stem}-{suffix}.{extension}"),
+        None => format!("{name}-{suffix}"),
+    }
+}
+
+/// Returns the directory to run an ecosystem's workflows in, i.e. its directory or `.` for the root.
+fn working_directory(ecosystem: &InitializedProjectEcosystem) -> String {
+    match ecosystem.directory() {
+        "" => ".".to_string(),
+        directory => directory.to_string(),
+    }
+}
+
+/// Handles the generation of source files content specific to Go projects hosted on Github.
+/// e.g. Github actions running goreleaser
+struct GoGithubSourceBundleContentHandler<'a> {
+    ecosystem: &'a InitializedProjectEcosystem,
+}
+
+impl SourceBundleContentGenerator for GoGithubSourceBundleContentHandler<'_> {
+    fn generate_content(
+        &self,
+        params: &SourceBundleFacetParams,
+    ) -> Result<SourceBundleContent, SkootError> {
+        match params.facet_type {
+            SupportedFacetType::Gitignore => self.generate_gitignore_content(params),
+            // TODO: Rename this to something like SecureBuild.
+            // This also does a bunch of other stuff like setting up releases, generating SBOM, etc.
+            // So for now just we just use it instead of creating multiple facets.
+            // The better option is to probably set up some mapping of properties like SLSA, SBOMGenerating, etc.
+            // to a single SecureBuild facet.
+            SupportedFacetType::SLSABuild => self.generate_slsa_build_content(params),
+            SupportedFacetType::Fuzzing => self.generate_fuzzing_content(params),
+            SupportedFacetType::DefaultSourceCode => {
+                self.generate_default_source_code_content(params)
+            }
+            _ => Err(format!("The {} facet isn't supported for Go projects", params.facet_type).into()),
+        }
+    }
+}
+impl GoGithubSourceBundleContentHandler<'_> {
+    fn generate_gitignore_content(
+        &self,
+        _params: &SourceBundleFacetParams,
+    ) -> Result<SourceBundleContent, SkootError> {
+        #[derive(Template)]
+        #[template(path = "go.gitignore", escape = "none")]
+        struct GitignoreTemplateParams {}
+
+        let gitignore_template_params = GitignoreTemplateParams {};
+        let content = gitignore_template_params.render()?;
+
+        Ok(SourceBundleContent {
+            source_files_content: vec![SourceFileContent {
+                name: ".gitignore".to_string(),
+                path: "./".to_string(),
+                content,
+                hash: None,
+            }],
+            facet_type: SupportedFacetType::Gitignore,
+        })
+    }
+    // Note: GoReleaser also does a bunch of other stuff like setting up releases, generating SBOM, etc.
+    // So for now just we just use it instead of creating multiple facets.
+    // Note: Content mostly taken from https://github.com/guacsec/guac/blob/f1703bd4ca3c0ec0fa55c5a3401d50578fb1680e/.github/workflows/release.yaml
+    fn generate_slsa_build_content(
+        &self,
+        params: &SourceBundleFacetParams,
+    ) -> Result<SourceBundleContent, SkootError> {
+        // TODO: This should really be a struct that serializes to yaml instead of just a file template
+        #[derive(Template)]
+        #[template(path = "go.releases.yml", escape = "none")]
+        struct ReleaseTemplateParams {
+            default_branch: String,
+            working_directory: String,
+        }
+
+        #[derive(Template)]
+        #[template(path = "Dockerfile.goreleaser", escape = "none")]
+        struct DockerfileTemplateParams {
+            project_name: String,
+        }
+
+        #[derive(Template)]
+        #[template(path = "goreleaser.yml", escape = "none")]
+        struct GoReleaserTemplateParams {
+            project_name: String,
             module_name: String,
         }
         
         #[allow(clippy::match_wildcard_for_single_variants)]
-        let module = match &params.common.ecosystem {
+        let module = match &self.ecosystem.ecosystem {
             InitializedEcosystem::Go(go) => go.module(),
             _ => unreachable!("Ecosystem should be Go"),
         };
 
-        let slsa_build_template_params = ReleaseTemplateParams {};
+        let slsa_build_template_params = ReleaseTemplateParams {
+            default_branch: params.common.default_branch.clone(),
+            working_directory: working_directory(self.ecosystem),
+        };
         let dockerfile_template_params = DockerfileTemplateParams {
             project_name: params.common.project_name.clone(),
         };
@@ -680,46 +1756,24 @@ impl GoGithubSourceBundleContentHandler {
                 name: "releases.yml".to_string(),
                 path: ".github/workflows/".to_string(),
                 content: slsa_build_template_params.render()?,
+                hash: None,
             },
             SourceFileContent {
                 name: "Dockerfile.goreleaser".to_string(),
                 path: "./".to_string(),
                 content: dockerfile_template_params.render()?,
+                hash: None,
             },
             SourceFileContent {
                 name: ".goreleaser.yml".to_string(),
                 path: "./".to_string(),
                 content: goreleaser_template_params.render()?,
+                hash: None,
             }],
             facet_type: SupportedFacetType::SLSABuild,
         })
     }
 
-    fn generate_dependency_update_tool_content(
-        &self,
-        _params: &SourceBundleFacetParams,
-    ) -> Result<SourceBundleContent, SkootError> {
-        #[derive(Template)]
-        #[template(path = "dependabot.yml", escape = "none")]
-        struct DependabotTemplateParams {
-            ecosystem: String,
-        }
-
-        let dependabot_template_params = DependabotTemplateParams {
-            ecosystem: "gomod".to_string(),
-        };
-        let content = dependabot_template_params.render()?;
-
-        Ok(SourceBundleContent {
-            source_files_content: vec![SourceFileContent {
-                name: "dependabot.yml".to_string(),
-                path: ".github/".to_string(),
-                content,
-            }],
-            facet_type: SupportedFacetType::DependencyUpdateTool,
-        })
-    }
-
     fn generate_fuzzing_content(
         &self,
         params: &SourceBundleFacetParams,
@@ -727,11 +1781,13 @@ impl GoGithubSourceBundleContentHandler {
         #[derive(Template)]
         #[template(path = "cifuzz.yml", escape = "none")]
         struct FuzzingTemplateParams {
+            default_branch: String,
             project_name: String,
             language: String,
         }
 
         let fuzzing_template_params = FuzzingTemplateParams {
+            default_branch: params.common.default_branch.clone(),
             project_name: params.common.project_name.clone(),
             language: "go".to_string(),
         };
@@ -742,6 +1798,7 @@ impl GoGithubSourceBundleContentHandler {
                 name: "cifuzz.yml".to_string(),
                 path: ".github/workflows/".to_string(),
                 content,
+                hash: None,
             }],
             facet_type: SupportedFacetType::Fuzzing,
         })
@@ -752,18 +1809,734 @@ impl GoGithubSourceBundleContentHandler {
         _params: &SourceBundleFacetParams,
     ) -> Result<SourceBundleContent, SkootError> {
         #[derive(Template)]
-        #[template(path = "main.go.tmpl", escape = "none")]
-        struct DefaultSourceCodeTemplateParams {}
+        #[template(path = "main.go.tmpl", escape = "none")]
+        struct DefaultSourceCodeTemplateParams {}
+
+        let default_source_code_template_params = DefaultSourceCodeTemplateParams {};
+        let content = default_source_code_template_params.render()?;
+
+        Ok(SourceBundleContent {
+            source_files_content: vec![SourceFileContent {
+                name: "main.go".to_string(),
+                path: "./".to_string(),
+                content,
+                hash: None,
+            }],
+            facet_type: SupportedFacetType::DefaultSourceCode,
+        })
+    }
+}
+
+/// Handles the generation of source files content specific to Rust projects hosted on Github.
+/// e.g. Github actions building the crate with SLSA provenance
+struct RustGithubSourceBundleContentHandler<'a> {
+    ecosystem: &'a InitializedProjectEcosystem,
+}
+
+impl SourceBundleContentGenerator for RustGithubSourceBundleContentHandler<'_> {
+    fn generate_content(
+        &self,
+        params: &SourceBundleFacetParams,
+    ) -> Result<SourceBundleContent, SkootError> {
+        match params.facet_type {
+            SupportedFacetType::Gitignore => self.generate_gitignore_content(params),
+            SupportedFacetType::SLSABuild => self.generate_slsa_build_content(params),
+            SupportedFacetType::Fuzzing => self.generate_fuzzing_content(params),
+            SupportedFacetType::DefaultSourceCode => {
+                self.generate_default_source_code_content(params)
+            }
+            _ => Err(format!("The {} facet isn't supported for Rust projects", params.facet_type).into()),
+        }
+    }
+}
+
+impl RustGithubSourceBundleContentHandler<'_> {
+    fn cargo(&self) -> &InitializedCargo {
+        #[allow(clippy::match_wildcard_for_single_variants)]
+        match &self.ecosystem.ecosystem {
+            InitializedEcosystem::Cargo(cargo) => cargo,
+            _ => unreachable!("Ecosystem should be Cargo"),
+        }
+    }
+
+    fn generate_gitignore_content(
+        &self,
+        _params: &SourceBundleFacetParams,
+    ) -> Result<SourceBundleContent, SkootError> {
+        #[derive(Template)]
+        #[template(path = "rust.gitignore", escape = "none")]
+        struct GitignoreTemplateParams {}
+
+        let gitignore_template_params = GitignoreTemplateParams {};
+        let content = gitignore_template_params.render()?;
+
+        Ok(SourceBundleContent {
+            source_files_content: vec![SourceFileContent {
+                name: ".gitignore".to_string(),
+                path: "./".to_string(),
+                content,
+                hash: None,
+            }],
+            facet_type: SupportedFacetType::Gitignore,
+        })
+    }
+
+    // Note: This builds the crate package, along with the executable for binary crates, and generates
+    // SLSA provenance for them with the generic SLSA generator.
+    fn generate_slsa_build_content(
+        &self,
+        params: &SourceBundleFacetParams,
+    ) -> Result<SourceBundleContent, SkootError> {
+        #[derive(Template)]
+        #[template(path = "rust.releases.yml", escape = "none")]
+        struct ReleaseTemplateParams {
+            default_branch: String,
+            crate_name: String,
+            binary: bool,
+            working_directory: String,
+        }
+
+        let cargo = self.cargo();
+        let release_template_params = ReleaseTemplateParams {
+            default_branch: params.common.default_branch.clone(),
+            crate_name: cargo.name.clone(),
+            binary: cargo.crate_type == CargoCrateType::Binary,
+            working_directory: working_directory(self.ecosystem),
+        };
+
+        Ok(SourceBundleContent {
+            source_files_content: vec![SourceFileContent {
+                name: "releases.yml".to_string(),
+                path: ".github/workflows/".to_string(),
+                content: release_template_params.render()?,
+                hash: None,
+            }],
+            facet_type: SupportedFacetType::SLSABuild,
+        })
+    }
+
+    // Note: This scaffolds a cargo-fuzz project in `fuzz/` along with the CIFuzz workflow to run it.
+    fn generate_fuzzing_content(
+        &self,
+        params: &SourceBundleFacetParams,
+    ) -> Result<SourceBundleContent, SkootError> {
+        #[derive(Template)]
+        #[template(path = "cifuzz.yml", escape = "none")]
+        struct FuzzingTemplateParams {
+            default_branch: String,
+            project_name: String,
+            language: String,
+        }
+
+        #[derive(Template)]
+        #[template(path = "rust.fuzz.Cargo.toml", escape = "none")]
+        struct FuzzCargoTemplateParams {
+            crate_name: String,
+            edition: String,
+            library: bool,
+        }
+
+        #[derive(Template)]
+        #[template(path = "rust.fuzz_target.rs", escape = "none")]
+        struct FuzzTargetTemplateParams {}
+
+        #[derive(Template)]
+        #[template(path = "rust.fuzz.gitignore", escape = "none")]
+        struct FuzzGitignoreTemplateParams {}
+
+        let cargo = self.cargo();
+        let fuzzing_template_params = FuzzingTemplateParams {
+            default_branch: params.common.default_branch.clone(),
+            project_name: params.common.project_name.clone(),
+            language: "rust".to_string(),
+        };
+        let fuzz_cargo_template_params = FuzzCargoTemplateParams {
+            crate_name: cargo.name.clone(),
+            edition: cargo.edition.clone(),
+            library: cargo.crate_type == CargoCrateType::Library,
+        };
+
+        Ok(SourceBundleContent {
+            source_files_content: vec![SourceFileContent {
+                name: "cifuzz.yml".to_string(),
+                path: ".github/workflows/".to_string(),
+                content: fuzzing_template_params.render()?,
+                hash: None,
+            },
+            SourceFileContent {
+                name: "Cargo.toml".to_string(),
+                path: "fuzz/".to_string(),
+                content: fuzz_cargo_template_params.render()?,
+                hash: None,
+            },
+            SourceFileContent {
+                name: ".gitignore".to_string(),
+                path: "fuzz/".to_string(),
+                content: FuzzGitignoreTemplateParams {}.render()?,
+                hash: None,
+            },
+            SourceFileContent {
+                name: "fuzz_target_1.rs".to_string(),
+                path: "fuzz/fuzz_targets/".to_string(),
+                content: FuzzTargetTemplateParams {}.render()?,
+                hash: None,
+            }],
+            facet_type: SupportedFacetType::Fuzzing,
+        })
+    }
+
+    fn generate_default_source_code_content(
+        &self,
+        _params: &SourceBundleFacetParams,
+    ) -> Result<SourceBundleContent, SkootError> {
+        #[derive(Template)]
+        #[template(path = "main.rs.tmpl", escape = "none")]
+        struct MainTemplateParams {}
+
+        #[derive(Template)]
+        #[template(path = "lib.rs.tmpl", escape = "none")]
+        struct LibTemplateParams {}
+
+        let (name, content) = match self.cargo().crate_type {
+            CargoCrateType::Binary => ("main.rs", MainTemplateParams {}.render()?),
+            CargoCrateType::Library => ("lib.rs", LibTemplateParams {}.render()?),
+        };
+
+        Ok(SourceBundleContent {
+            source_files_content: vec![SourceFileContent {
+                name: name.to_string(),
+                path: "src/".to_string(),
+                content,
+                hash: None,
+            }],
+            facet_type: SupportedFacetType::DefaultSourceCode,
+        })
+    }
+}
+
+/// Handles the generation of source files content specific to Node.js projects hosted on Github.
+/// e.g. Github actions publishing the package to npm with provenance
+struct NodeGithubSourceBundleContentHandler<'a> {
+    ecosystem: &'a InitializedProjectEcosystem,
+}
+
+impl SourceBundleContentGenerator for NodeGithubSourceBundleContentHandler<'_> {
+    fn generate_content(
+        &self,
+        params: &SourceBundleFacetParams,
+    ) -> Result<SourceBundleContent, SkootError> {
+        match params.facet_type {
+            SupportedFacetType::Gitignore => self.generate_gitignore_content(params),
+            SupportedFacetType::SLSABuild => self.generate_slsa_build_content(params),
+            SupportedFacetType::DefaultSourceCode => {
+                self.generate_default_source_code_content(params)
+            }
+            _ => Err(format!("The {} facet isn't supported for Node.js projects", params.facet_type).into()),
+        }
+    }
+}
+
+impl NodeGithubSourceBundleContentHandler<'_> {
+    fn generate_gitignore_content(
+        &self,
+        _params: &SourceBundleFacetParams,
+    ) -> Result<SourceBundleContent, SkootError> {
+        #[derive(Template)]
+        #[template(path = "node.gitignore", escape = "none")]
+        struct GitignoreTemplateParams {}
+
+        let gitignore_template_params = GitignoreTemplateParams {};
+        let content = gitignore_template_params.render()?;
+
+        Ok(SourceBundleContent {
+            source_files_content: vec![SourceFileContent {
+                name: ".gitignore".to_string(),
+                path: "./".to_string(),
+                content,
+                hash: None,
+            }],
+            facet_type: SupportedFacetType::Gitignore,
+        })
+    }
+
+    // Note: npm generates the SLSA provenance for the package itself when publishing from Github actions.
+    fn generate_slsa_build_content(
+        &self,
+        params: &SourceBundleFacetParams,
+    ) -> Result<SourceBundleContent, SkootError> {
+        #[derive(Template)]
+        #[template(path = "npm.releases.yml", escape = "none")]
+        struct ReleaseTemplateParams {
+            default_branch: String,
+            working_directory: String,
+        }
+
+        let release_template_params = ReleaseTemplateParams {
+            default_branch: params.common.default_branch.clone(),
+            working_directory: working_directory(self.ecosystem),
+        };
+
+        Ok(SourceBundleContent {
+            source_files_content: vec![SourceFileContent {
+                name: "releases.yml".to_string(),
+                path: ".github/workflows/".to_string(),
+                content: release_template_params.render()?,
+                hash: None,
+            }],
+            facet_type: SupportedFacetType::SLSABuild,
+        })
+    }
+
+    fn generate_default_source_code_content(
+        &self,
+        _params: &SourceBundleFacetParams,
+    ) -> Result<SourceBundleContent, SkootError> {
+        #[derive(Template)]
+        #[template(path = "index.js.tmpl", escape = "none")]
+        struct JavaScriptTemplateParams {}
+
+        #[derive(Template)]
+        #[template(path = "index.ts.tmpl", escape = "none")]
+        struct TypeScriptTemplateParams {}
+
+        #[allow(clippy::match_wildcard_for_single_variants)]
+        let typescript = match &self.ecosystem.ecosystem {
+            InitializedEcosystem::Npm(npm) => npm.typescript,
+            _ => unreachable!("Ecosystem should be Npm"),
+        };
+        let (name, content) = if typescript {
+            ("index.ts", TypeScriptTemplateParams {}.render()?)
+        } else {
+            ("index.js", JavaScriptTemplateParams {}.render()?)
+        };
+
+        Ok(SourceBundleContent {
+            source_files_content: vec![SourceFileContent {
+                name: name.to_string(),
+                path: "src/".to_string(),
+                content,
+                hash: None,
+            }],
+            facet_type: SupportedFacetType::DefaultSourceCode,
+        })
+    }
+}
+
+/// Handles the generation of source files content specific to Maven projects hosted on Github.
+/// e.g. Github actions building the project with SLSA provenance
+struct MavenGithubSourceBundleContentHandler<'a> {
+    ecosystem: &'a InitializedProjectEcosystem,
+}
+
+impl SourceBundleContentGenerator for MavenGithubSourceBundleContentHandler<'_> {
+    fn generate_content(
+        &self,
+        params: &SourceBundleFacetParams,
+    ) -> Result<SourceBundleContent, SkootError> {
+        match params.facet_type {
+            SupportedFacetType::Gitignore => self.generate_gitignore_content(params),
+            SupportedFacetType::SLSABuild => self.generate_slsa_build_content(params),
+            SupportedFacetType::Fuzzing => self.generate_fuzzing_content(params),
+            SupportedFacetType::DefaultSourceCode => {
+                self.generate_default_source_code_content(params)
+            }
+            _ => Err(format!("The {} facet isn't supported for Maven projects", params.facet_type).into()),
+        }
+    }
+}
+
+impl MavenGithubSourceBundleContentHandler<'_> {
+    fn maven(&self) -> &InitializedMaven {
+        #[allow(clippy::match_wildcard_for_single_variants)]
+        match &self.ecosystem.ecosystem {
+            InitializedEcosystem::Maven(maven) => maven,
+            _ => unreachable!("Ecosystem should be Maven"),
+        }
+    }
+
+    fn generate_gitignore_content(
+        &self,
+        _params: &SourceBundleFacetParams,
+    ) -> Result<SourceBundleContent, SkootError> {
+        #[derive(Template)]
+        #[template(path = "maven.gitignore", escape = "none")]
+        struct GitignoreTemplateParams {}
+
+        let gitignore_template_params = GitignoreTemplateParams {};
+        let content = gitignore_template_params.render()?;
+
+        Ok(SourceBundleContent {
+            source_files_content: vec![SourceFileContent {
+                name: ".gitignore".to_string(),
+                path: "./".to_string(),
+                content,
+                hash: None,
+            }],
+            facet_type: SupportedFacetType::Gitignore,
+        })
+    }
+
+    // Note: This generates SLSA provenance for the jars with the generic SLSA generator.
+    fn generate_slsa_build_content(
+        &self,
+        params: &SourceBundleFacetParams,
+    ) -> Result<SourceBundleContent, SkootError> {
+        #[derive(Template)]
+        #[template(path = "maven.releases.yml", escape = "none")]
+        struct ReleaseTemplateParams {
+            default_branch: String,
+            working_directory: String,
+        }
+
+        let release_template_params = ReleaseTemplateParams {
+            default_branch: params.common.default_branch.clone(),
+            working_directory: working_directory(self.ecosystem),
+        };
+
+        Ok(SourceBundleContent {
+            source_files_content: vec![SourceFileContent {
+                name: "releases.yml".to_string(),
+                path: ".github/workflows/".to_string(),
+                content: release_template_params.render()?,
+                hash: None,
+            }],
+            facet_type: SupportedFacetType::SLSABuild,
+        })
+    }
+
+    // Note: The Jazzer fuzz targets live in their own Maven project under `fuzz/`, the same way
+    // cargo-fuzz keeps them out of a crate's build.
+    fn generate_fuzzing_content(
+        &self,
+        params: &SourceBundleFacetParams,
+    ) -> Result<SourceBundleContent, SkootError> {
+        #[derive(Template)]
+        #[template(path = "cifuzz.yml", escape = "none")]
+        struct FuzzingTemplateParams {
+            default_branch: String,
+            project_name: String,
+            language: String,
+        }
+
+        #[derive(Template)]
+        #[template(path = "maven.fuzz.pom.xml", escape = "none")]
+        struct FuzzPomTemplateParams {
+            group_id: String,
+            artifact_id: String,
+        }
+
+        #[derive(Template)]
+        #[template(path = "AppFuzzer.java.tmpl", escape = "none")]
+        struct FuzzTargetTemplateParams {
+            package: String,
+        }
+
+        let maven = self.maven();
+        let fuzzing_template_params = FuzzingTemplateParams {
+            default_branch: params.common.default_branch.clone(),
+            project_name: params.common.project_name.clone(),
+            language: "jvm".to_string(),
+        };
+        let fuzz_pom_template_params = FuzzPomTemplateParams {
+            group_id: maven.group_id.clone(),
+            artifact_id: maven.artifact_id.clone(),
+        };
+        let fuzz_target_template_params = FuzzTargetTemplateParams {
+            package: maven.group_id.clone(),
+        };
+
+        Ok(SourceBundleContent {
+            source_files_content: vec![
+                SourceFileContent {
+                    name: "cifuzz.yml".to_string(),
+                    path: ".github/workflows/".to_string(),
+                    content: fuzzing_template_params.render()?,
+                    hash: None,
+                },
+                SourceFileContent {
+                    name: "pom.xml".to_string(),
+                    path: "fuzz/".to_string(),
+                    content: fuzz_pom_template_params.render()?,
+                    hash: None,
+                },
+                SourceFileContent {
+                    name: "AppFuzzer.java".to_string(),
+                    path: format!("fuzz/src/main/java/{}/", maven.group_id.replace('.', "/")),
+                    content: fuzz_target_template_params.render()?,
+                    hash: None,
+                },
+            ],
+            facet_type: SupportedFacetType::Fuzzing,
+        })
+    }
+
+    // Note: This replaces the `App.java` generated by the quickstart archetype when the project is
+    // scaffolded with Maven, which puts it in the package named after the group ID.
+    fn generate_default_source_code_content(
+        &self,
+        _params: &SourceBundleFacetParams,
+    ) -> Result<SourceBundleContent, SkootError> {
+        #[derive(Template)]
+        #[template(path = "App.java.tmpl", escape = "none")]
+        struct AppTemplateParams {
+            package: String,
+        }
+
+        let maven = self.maven();
+        let app_template_params = AppTemplateParams {
+            package: maven.group_id.clone(),
+        };
+
+        Ok(SourceBundleContent {
+            source_files_content: vec![SourceFileContent {
+                name: "App.java".to_string(),
+                path: format!("src/main/java/{}/", maven.group_id.replace('.', "/")),
+                content: app_template_params.render()?,
+                hash: None,
+            }],
+            facet_type: SupportedFacetType::DefaultSourceCode,
+        })
+    }
+}
+
+/// Handles the generation of source files content specific to Gradle projects hosted on Github.
+/// e.g. Github actions building the project with SLSA provenance
+struct GradleGithubSourceBundleContentHandler<'a> {
+    ecosystem: &'a InitializedProjectEcosystem,
+}
+
+impl SourceBundleContentGenerator for GradleGithubSourceBundleContentHandler<'_> {
+    fn generate_content(
+        &self,
+        params: &SourceBundleFacetParams,
+    ) -> Result<SourceBundleContent, SkootError> {
+        match params.facet_type {
+            SupportedFacetType::Gitignore => self.generate_gitignore_content(params),
+            SupportedFacetType::SLSABuild => self.generate_slsa_build_content(params),
+            SupportedFacetType::DefaultSourceCode => {
+                self.generate_default_source_code_content(params)
+            }
+            _ => Err(format!("The {} facet isn't supported for Gradle projects", params.facet_type).into()),
+        }
+    }
+}
+
+impl GradleGithubSourceBundleContentHandler<'_> {
+    fn generate_gitignore_content(
+        &self,
+        _params: &SourceBundleFacetParams,
+    ) -> Result<SourceBundleContent, SkootError> {
+        #[derive(Template)]
+        #[template(path = "gradle.gitignore", escape = "none")]
+        struct GitignoreTemplateParams {}
+
+        let gitignore_template_params = GitignoreTemplateParams {};
+        let content = gitignore_template_params.render()?;
+
+        Ok(SourceBundleContent {
+            source_files_content: vec![SourceFileContent {
+                name: ".gitignore".to_string(),
+                path: "./".to_string(),
+                content,
+                hash: None,
+            }],
+            facet_type: SupportedFacetType::Gitignore,
+        })
+    }
+
+    // Note: This verifies the checked in wrapper jar before building with it, and generates SLSA
+    // provenance for the jars and distributions with the generic SLSA generator.
+    fn generate_slsa_build_content(
+        &self,
+        params: &SourceBundleFacetParams,
+    ) -> Result<SourceBundleContent, SkootError> {
+        #[derive(Template)]
+        #[template(path = "gradle.releases.yml", escape = "none")]
+        struct ReleaseTemplateParams {
+            default_branch: String,
+            working_directory: String,
+        }
+
+        let release_template_params = ReleaseTemplateParams {
+            default_branch: params.common.default_branch.clone(),
+            working_directory: working_directory(self.ecosystem),
+        };
+
+        Ok(SourceBundleContent {
+            source_files_content: vec![SourceFileContent {
+                name: "releases.yml".to_string(),
+                path: ".github/workflows/".to_string(),
+                content: release_template_params.render()?,
+                hash: None,
+            }],
+            facet_type: SupportedFacetType::SLSABuild,
+        })
+    }
+
+    fn generate_default_source_code_content(
+        &self,
+        _params: &SourceBundleFacetParams,
+    ) -> Result<SourceBundleContent, SkootError> {
+        #[derive(Template)]
+        #[template(path = "App.java.tmpl", escape = "none")]
+        struct AppTemplateParams {
+            package: String,
+        }
+
+        #[derive(Template)]
+        #[template(path = "AppTest.java.tmpl", escape = "none")]
+        struct AppTestTemplateParams {
+            package: String,
+        }
+
+        #[allow(clippy::match_wildcard_for_single_variants)]
+        let package = match &self.ecosystem.ecosystem {
+            InitializedEcosystem::Gradle(gradle) => gradle.package(),
+            _ => unreachable!("Ecosystem should be Gradle"),
+        };
+        let package_path = package.replace('.', "/");
+        let app_template_params = AppTemplateParams {
+            package: package.clone(),
+        };
+        let app_test_template_params = AppTestTemplateParams { package };
+
+        Ok(SourceBundleContent {
+            source_files_content: vec![
+                SourceFileContent {
+                    name: "App.java".to_string(),
+                    path: format!("src/main/java/{package_path}/"),
+                    content: app_template_params.render()?,
+                    hash: None,
+                },
+                SourceFileContent {
+                    name: "AppTest.java".to_string(),
+                    path: format!("src/test/java/{package_path}/"),
+                    content: app_test_template_params.render()?,
+                    hash: None,
+                },
+            ],
+            facet_type: SupportedFacetType::DefaultSourceCode,
+        })
+    }
+}
+
+/// Handles the generation of source files content specific to Python projects hosted on Github.
+/// e.g. Github actions publishing the package to `PyPI` with trusted publishing
+struct PythonGithubSourceBundleContentHandler<'a> {
+    ecosystem: &'a InitializedProjectEcosystem,
+}
+
+impl SourceBundleContentGenerator for PythonGithubSourceBundleContentHandler<'_> {
+    fn generate_content(
+        &self,
+        params: &SourceBundleFacetParams,
+    ) -> Result<SourceBundleContent, SkootError> {
+        match params.facet_type {
+            SupportedFacetType::Gitignore => self.generate_gitignore_content(params),
+            SupportedFacetType::SLSABuild => self.generate_slsa_build_content(params),
+            SupportedFacetType::DefaultSourceCode => {
+                self.generate_default_source_code_content(params)
+            }
+            _ => Err(format!("The {} facet isn't supported for Python projects", params.facet_type).into()),
+        }
+    }
+}
+
+impl PythonGithubSourceBundleContentHandler<'_> {
+    fn generate_gitignore_content(
+        &self,
+        _params: &SourceBundleFacetParams,
+    ) -> Result<SourceBundleContent, SkootError> {
+        #[derive(Template)]
+        #[template(path = "python.gitignore", escape = "none")]
+        struct GitignoreTemplateParams {}
+
+        let gitignore_template_params = GitignoreTemplateParams {};
+        let content = gitignore_template_params.render()?;
+
+        Ok(SourceBundleContent {
+            source_files_content: vec![SourceFileContent {
+                name: ".gitignore".to_string(),
+                path: "./".to_string(),
+                content,
+                hash: None,
+            }],
+            facet_type: SupportedFacetType::Gitignore,
+        })
+    }
+
+    // Note: This builds the sdist and wheel, generates SLSA provenance for them with the generic SLSA
+    // generator and publishes them to PyPI with trusted publishing.
+    fn generate_slsa_build_content(
+        &self,
+        params: &SourceBundleFacetParams,
+    ) -> Result<SourceBundleContent, SkootError> {
+        #[derive(Template)]
+        #[template(path = "python.releases.yml", escape = "none")]
+        struct ReleaseTemplateParams {
+            default_branch: String,
+            working_directory: String,
+        }
+
+        let release_template_params = ReleaseTemplateParams {
+            default_branch: params.common.default_branch.clone(),
+            working_directory: working_directory(self.ecosystem),
+        };
+
+        Ok(SourceBundleContent {
+            source_files_content: vec![SourceFileContent {
+                name: "releases.yml".to_string(),
+                path: ".github/workflows/".to_string(),
+                content: release_template_params.render()?,
+                hash: None,
+            }],
+            facet_type: SupportedFacetType::SLSABuild,
+        })
+    }
+
+    fn generate_default_source_code_content(
+        &self,
+        _params: &SourceBundleFacetParams,
+    ) -> Result<SourceBundleContent, SkootError> {
+        #[derive(Template)]
+        #[template(path = "python.__init__.py", escape = "none")]
+        struct ModuleTemplateParams {
+            module: String,
+        }
 
-        let default_source_code_template_params = DefaultSourceCodeTemplateParams {};
-        let content = default_source_code_template_params.render()?;
+        #[derive(Template)]
+        #[template(path = "python.test.py", escape = "none")]
+        struct TestTemplateParams {
+            module: String,
+        }
+
+        #[allow(clippy::match_wildcard_for_single_variants)]
+        let module = match &self.ecosystem.ecosystem {
+            InitializedEcosystem::Python(python) => python.module(),
+            _ => unreachable!("Ecosystem should be Python"),
+        };
+        let module_template_params = ModuleTemplateParams {
+            module: module.clone(),
+        };
+        let test_template_params = TestTemplateParams {
+            module: module.clone(),
+        };
 
         Ok(SourceBundleContent {
-            source_files_content: vec![SourceFileContent {
-                name: "main.go".to_string(),
-                path: "./".to_string(),
-                content,
-            }],
+            source_files_content: vec![
+                SourceFileContent {
+                    name: "__init__.py".to_string(),
+                    path: format!("src/{module}/"),
+                    content: module_template_params.render()?,
+                    hash: None,
+                },
+                SourceFileContent {
+                    name: format!("test_{module}.py"),
+                    path: "tests/".to_string(),
+                    content: test_template_params.render()?,
+                    hash: None,
+                },
+            ],
             facet_type: SupportedFacetType::DefaultSourceCode,
         })
     }
@@ -774,6 +2547,67 @@ impl GoGithubSourceBundleContentHandler {
 pub struct FacetSetParamsGenerator {}
 
 impl FacetSetParamsGenerator {
+    /// The facets of a project's API bundle that are created by default.
+    const DEFAULT_API_BUNDLE_FACET_TYPES: [SupportedFacetType; 3] = {
+        use SupportedFacetType::{BranchProtection, CodeReview, VulnerabilityReporting};
+        [
+            BranchProtection,
+            VulnerabilityReporting,
+            // This updates the branch protection, so it has to come after it.
+            CodeReview,
+        ]
+    };
+
+    // TODO: Come up with a better solution than hard coding the default facets
+    /// The facets of a project's source bundle that are created by default.
+    const DEFAULT_SOURCE_BUNDLE_FACET_TYPES: [SupportedFacetType; 16] = {
+        use SupportedFacetType::{
+            Allstar, CodeReview, DefaultSourceCode, DependencyUpdateTool, GUACForwardingConfig, Gitignore, License,
+            PinnedDependencies, Readme, SBOMGenerator, SLSABuild, Scorecard, SecurityInsights, SecurityPolicy,
+            VulnerabilityScanner, SAST,
+        };
+        [
+            Readme,
+            License,
+            Gitignore,
+            SecurityPolicy,
+            SecurityInsights,
+            SLSABuild,
+            SBOMGenerator,
+            // StaticCodeAnalysis,
+            DependencyUpdateTool,
+            // TODO: Fuzzing right now requires a bunch of resources that are unavailable to most projects without
+            // some sort of manual intervention. This is disabled until some option becomes available.
+            // Fuzzing,
+            Scorecard,
+            // PublishPackages,
+            SAST,
+            VulnerabilityScanner,
+            // This forwards what the facets before it generate, and does nothing without a configured GUAC collector.
+            GUACForwardingConfig,
+            // The CODEOWNERS file of code review. The reviews themselves are required by the API bundle.
+            CodeReview,
+            Allstar,
+            // This pins the dependencies of the workflows written by the facets before it.
+            PinnedDependencies,
+            // These are at the end to allow Skootrs to push initial commits without needing
+            // code review or branches.
+            //BranchProtection, //TODO: Implement this
+            DefaultSourceCode,
+        ]
+    };
+
+    /// Returns the types of the facets a project is created with by default. These can be narrowed down with
+    /// the `facets` of the project's params.
+    #[must_use]
+    pub fn default_facet_types(&self) -> Vec<SupportedFacetType> {
+        [
+            &Self::DEFAULT_SOURCE_BUNDLE_FACET_TYPES[..],
+            &Self::DEFAULT_API_BUNDLE_FACET_TYPES[..],
+        ]
+        .concat()
+    }
+
     /// Generates the default set of facet params for a project.
     /// This includes things like generating default source bundle and API bundle facet params.
     ///
@@ -806,13 +2640,7 @@ impl FacetSetParamsGenerator {
         &self,
         common_params: &CommonFacetParams,
     ) -> Result<FacetSetParams, SkootError> {
-        use SupportedFacetType::{BranchProtection, VulnerabilityReporting};
-        let supported_facets = [
-            //CodeReview,
-            BranchProtection,
-            VulnerabilityReporting,
-        ];
-        let facets_params = supported_facets
+        let facets_params = Self::DEFAULT_API_BUNDLE_FACET_TYPES
             .iter()
             .map(|facet_type| {
                 FacetParams::APIBundle(APIBundleFacetParams {
@@ -825,7 +2653,6 @@ impl FacetSetParamsGenerator {
         Ok(FacetSetParams { facets_params })
     }
 
-    // TODO: Come up with a better solution than hard coding the default facets
     /// Generates the default set of source bundle facet params for a project.
     ///
     /// # Errors
@@ -835,45 +2662,1009 @@ impl FacetSetParamsGenerator {
         &self,
         common_params: &CommonFacetParams,
     ) -> Result<FacetSetParams, SkootError> {
+        let facets_params = Self::DEFAULT_SOURCE_BUNDLE_FACET_TYPES
+            .iter()
+            .map(|facet_type| {
+                FacetParams::SourceBundle(SourceBundleFacetParams {
+                    common: common_params.clone(),
+                    facet_type: facet_type.clone(),
+                })
+            })
+            .collect::<Vec<FacetParams>>();
+
+        Ok(FacetSetParams { facets_params })
+    }
+}
+
+/// The `FacetDetector` struct represents a service for detecting the facets that already exist in a project's
+/// source. This is used for projects that weren't created by Skootrs.
+pub struct FacetDetector {}
+
+impl FacetDetector {
+    /// Returns the source bundle facets that exist in the source, made up of the files found for each.
+    /// Facets that depend on the project's code like `DefaultSourceCode` are not detected.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the facet files exist but can't be read.
+    pub fn detect_source_bundle_facets<S: SourceService>(
+        &self,
+        source_service: &S,
+        source: &InitializedSource,
+    ) -> Result<Vec<InitializedFacet>, SkootError> {
         use SupportedFacetType::{
-            DefaultSourceCode, DependencyUpdateTool, Gitignore, License, Readme,
-            SLSABuild, Scorecard, SecurityInsights, SecurityPolicy, SAST,
+            Allstar, CodeReview, DependencyUpdateTool, Fuzzing, GUACForwardingConfig, Gitignore, License, Readme,
+            SBOMGenerator, SLSABuild, Scorecard, SecurityInsights, SecurityPolicy, VulnerabilityScanner, SAST,
         };
-        let supported_facets = [
+        let detectable_facets = [
             Readme,
             License,
             Gitignore,
             SecurityPolicy,
             SecurityInsights,
             SLSABuild,
-            // SBOMGenerator, // Handled by the SLSABuild facet
-            // StaticCodeAnalysis,
+            SBOMGenerator,
             DependencyUpdateTool,
-            // TODO: Fuzzing right now requires a bunch of resources that are unavailable to most projects without
-            // some sort of manual intervention. This is disabled until some option becomes available.
-            // Fuzzing,
+            Fuzzing,
             Scorecard,
-            // PublishPackages,
-            // PinnedDependencies,
             SAST,
-            // VulnerabilityScanner,
-            // GUACForwardingConfig,
-            // These are at the end to allow Skootrs to push initial commits without needing
-            // code review or branches.
-            // CodeReview, // TODO: Implement this
-            //BranchProtection, //TODO: Implement this
-            DefaultSourceCode,
+            VulnerabilityScanner,
+            CodeReview,
+            Allstar,
+            GUACForwardingConfig,
+        ];
+
+        let mut facets = Vec::new();
+        for facet_type in detectable_facets {
+            let mut source_files: Vec<SourceFileContent> = Vec::new();
+            for (path, name) in self.known_source_files(&facet_type) {
+                let mut names = vec![(*name).to_string()];
+                // Ecosystems outside of the root, or sharing a directory, get their own workflows, e.g.
+                // `releases-backend.yml` or `releases-npm.yml`.
+                if normalize_path(path) == ".github/workflows" {
+                    names.extend(self.suffixed_workflows(source, path, name)?);
+                }
+                for name in names {
+                    if source_files.iter().any(|file| file.path == *path && file.name == name) {
+                        continue;
+                    }
+                    match source_service.read_file(source, path, name.clone()) {
+                        Ok(content) => source_files.push(SourceFileContent {
+                            name,
+                            path: (*path).to_string(),
+                            hash: Some(content_hash(&content)),
+                            content,
+                        }),
+                        Err(error) if is_not_found(&error) => {}
+                        Err(error) => return Err(error),
+                    }
+                }
+            }
+
+            if !source_files.is_empty() {
+                info!("Detected {} facet", facet_type);
+                facets.push(InitializedFacet::SourceBundle(SourceBundleFacet {
+                    source_files,
+                    facet_type,
+                }));
+            }
+        }
+
+        Ok(facets)
+    }
+
+    /// Returns the names of the workflows in `path` of the source that are `name` with a suffix added, e.g.
+    /// `releases-backend.yml` for `releases.yml`, sorted so they are detected in a stable order.
+    fn suffixed_workflows(&self, source: &InitializedSource, path: &str, name: &str) -> Result<Vec<String>, SkootError> {
+        let Some((stem, extension)) = name.rsplit_once('.') else {
+            return Ok(Vec::new());
+        };
+        let (prefix, extension) = (format!("{stem}-"), format!(".{extension}"));
+        let entries = match fs::read_dir(Path::new(&source.path).join(path)) {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(error) => return Err(error.into()),
+        };
+        let mut names = Vec::new();
+        for entry in entries {
+            let file_name = entry?.file_name().to_string_lossy().to_string();
+            if file_name.len() > prefix.len() + extension.len()
+                && file_name.starts_with(&prefix)
+                && file_name.ends_with(&extension)
+            {
+                names.push(file_name);
+            }
+        }
+        names.sort();
+        Ok(names)
+    }
+
+    /// Returns the (path, name) pairs of the files that make up a source bundle facet. These are where
+    /// Skootrs writes the facet's files along with where projects commonly keep them.
+    const fn known_source_files(&self, facet_type: &SupportedFacetType) -> &'static [(&'static str, &'static str)] {
+        match facet_type {
+            SupportedFacetType::Readme => &[("./", "README.md"), ("./", "README")],
+            SupportedFacetType::License => &[
+                ("./", "LICENSE"),
+                ("./", "LICENSE.md"),
+                ("./", "LICENSE.txt"),
+                ("./", "COPYING"),
+            ],
+            SupportedFacetType::Gitignore => &[("./", ".gitignore")],
+            SupportedFacetType::SecurityPolicy => &[
+                ("./", "SECURITY.md"),
+                ("./.github", "SECURITY.md"),
+                ("./docs", "SECURITY.md"),
+            ],
+            SupportedFacetType::SecurityInsights => &[("./", "SECURITY-INSIGHTS.yml")],
+            SupportedFacetType::SLSABuild => &[
+                ("./.github/workflows", "releases.yml"),
+                ("./", ".goreleaser.yml"),
+            ],
+            SupportedFacetType::SBOMGenerator => &[("./.github/workflows", "sbom.yml")],
+            SupportedFacetType::Allstar => &[("./.allstar", "allstar.yaml")],
+            SupportedFacetType::GUACForwardingConfig => &[("./.github/workflows", "guac.yml")],
+            SupportedFacetType::VulnerabilityScanner => &[("./.github/workflows", "vulnerability-scanner.yml")],
+            SupportedFacetType::CodeReview => &[
+                ("./.github", "CODEOWNERS"),
+                ("./.gitlab", "CODEOWNERS"),
+                ("./.gitea", "CODEOWNERS"),
+                ("./", "CODEOWNERS"),
+                ("./docs", "CODEOWNERS"),
+            ],
+            SupportedFacetType::DependencyUpdateTool => &[
+                ("./.github", "dependabot.yml"),
+                ("./.github", "dependabot.yaml"),
+                ("./", "renovate.json"),
+            ],
+            SupportedFacetType::Fuzzing => &[
+                ("./.github/workflows", "cifuzz.yml"),
+                ("./fuzz", "Cargo.toml"),
+            ],
+            SupportedFacetType::Scorecard => &[
+                ("./.github/workflows", "scorecard.yml"),
+                ("./.github/workflows", "scorecards.yml"),
+            ],
+            SupportedFacetType::SAST => &[
+                ("./.github/workflows", "codeql.yml"),
+                ("./.github/workflows", "codeql-analysis.yml"),
+            ],
+            _ => &[],
+        }
+    }
+}
+
+/// The lock file of the commit SHAs and digests Skootrs pins Github Actions and Docker base images to.
+const PINNED_DEPENDENCIES_LOCK: &str = include_str!("../../templates/pinned-dependencies.toml");
+
+/// The reusable workflows of the SLSA generator have to be referenced by tag for their provenance to be
+/// verifiable, so they are never pinned to a commit SHA.
+const SLSA_GITHUB_GENERATOR: &str = "slsa-framework/slsa-github-generator";
+
+/// The `DependencyPinner` struct represents a service for pinning the dependencies of workflows and Dockerfiles.
+///
+/// Github Actions are pinned to commit SHAs and base images to digests. The SHAs and digests come from a lock
+/// file, so nothing has to be looked up over the network.
+pub struct DependencyPinner {
+    /// The commit SHAs of Github Actions by their repo and version, e.g. `actions/checkout` and `v4.1.1`.
+    actions: HashMap<(String, String), String>,
+    /// The digests of Docker images by their name and tag, e.g. `alpine` and `latest`.
+    images: HashMap<(String, String), String>,
+}
+
+impl DependencyPinner {
+    /// Returns a `DependencyPinner` using the lock file bundled with Skootrs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bundled lock file can't be parsed.
+    pub fn bundled() -> Result<Self, SkootError> {
+        Self::from_lock(PINNED_DEPENDENCIES_LOCK)
+    }
+
+    /// Returns a `DependencyPinner` using the SHAs and digests of a lock file. The lock file has an `actions`
+    /// and an `images` table, each with a table for every action or image that maps its versions to the commit
+    /// SHAs or digests they are pinned to.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the lock file isn't valid TOML or a SHA or digest isn't a string.
+    pub fn from_lock(lock: &str) -> Result<Self, SkootError> {
+        let lock = lock.parse::<Document>()?;
+        let entries = |table_name: &str| -> Result<HashMap<(String, String), String>, SkootError> {
+            let mut entries = HashMap::new();
+            let Some(table) = lock.get(table_name).and_then(|table| table.as_table_like()) else {
+                return Ok(entries);
+            };
+            for (name, versions) in table.iter() {
+                let versions = versions
+                    .as_table_like()
+                    .ok_or_else(|| format!("{name} in the {table_name} of the lock file isn't a table"))?;
+                for (version, pin) in versions.iter() {
+                    let pin = pin
+                        .as_str()
+                        .ok_or_else(|| format!("{name} {version} in the lock file isn't pinned to a string"))?;
+                    entries.insert((name.to_string(), version.to_string()), pin.to_string());
+                }
+            }
+            Ok(entries)
+        };
+
+        Ok(Self {
+            actions: entries("actions")?,
+            images: entries("images")?,
+        })
+    }
+
+    /// Returns the content of a workflow with the Github Actions it uses pinned to commit SHAs, e.g.
+    /// `uses: actions/checkout@v4.1.1` becomes `uses: actions/checkout@b4ffde65f46336ab88eb53be808477a3936bae11 # v4.1.1`.
+    /// Actions that are already pinned or aren't in the lock file are left as they are.
+    #[must_use]
+    pub fn pin_workflow(&self, content: &str) -> String {
+        self.pin_lines(content, |line| self.pin_action(line))
+    }
+
+    /// Returns the content of a Dockerfile with the base images it uses pinned to digests, e.g.
+    /// `FROM alpine:latest` becomes `FROM alpine:latest@sha256:...`. Images that are already pinned or aren't in
+    /// the lock file are left as they are.
+    #[must_use]
+    pub fn pin_dockerfile(&self, content: &str) -> String {
+        self.pin_lines(content, |line| self.pin_image(line))
+    }
+
+    fn pin_lines(&self, content: &str, pin: impl Fn(&str) -> Option<String>) -> String {
+        let mut pinned = content
+            .lines()
+            .map(|line| pin(line).unwrap_or_else(|| line.to_string()))
+            .collect::<Vec<String>>()
+            .join("\n");
+        if content.ends_with('\n') {
+            pinned.push('\n');
+        }
+        pinned
+    }
+
+    fn pin_action(&self, line: &str) -> Option<String> {
+        let (key, reference) = line.split_once("uses:")?;
+        // e.g. `    uses:` or `      - uses:`
+        if !key.trim_start().trim_start_matches('-').trim().is_empty() {
+            return None;
+        }
+        let reference = reference.split('#').next()?.trim().trim_matches(['"', '\'']);
+        // Local actions and Docker images don't have a version to pin.
+        let (action, version) = reference.rsplit_once('@')?;
+        if reference.starts_with("docker://") || is_commit_sha(version) {
+            return None;
+        }
+        // e.g. github/codeql-action from github/codeql-action/init
+        let repo = action.splitn(3, '/').take(2).collect::<Vec<&str>>().join("/");
+        if repo == SLSA_GITHUB_GENERATOR {
+            return None;
+        }
+        let Some(sha) = self.actions.get(&(repo, version.to_string())) else {
+            warn!("Unable to pin {reference} since it isn't in the lock file");
+            return None;
+        };
+
+        Some(format!("{key}uses: {action}@{sha} # {version}"))
+    }
+
+    fn pin_image(&self, line: &str) -> Option<String> {
+        let mut tokens = line.split_whitespace();
+        if !tokens.next()?.eq_ignore_ascii_case("FROM") {
+            return None;
+        }
+        // The image comes after any flags like `--platform`.
+        let image = tokens.find(|token| !token.starts_with("--"))?;
+        if image.contains('@') || image.contains('$') || image == "scratch" {
+            return None;
+        }
+        // The tag comes after the last `:` unless that's the port of the registry, e.g. localhost:5000/alpine.
+        let (name, tag) = match image.rsplit_once(':') {
+            Some((name, tag)) if !tag.contains('/') => (name, tag),
+            _ => (image, "latest"),
+        };
+        let Some(digest) = self.images.get(&(name.to_string(), tag.to_string())) else {
+            warn!("Unable to pin {image} since it isn't in the lock file");
+            return None;
+        };
+
+        Some(line.replacen(image, &format!("{name}:{tag}@{digest}"), 1))
+    }
+}
+
+/// Returns whether a Github Actions version is a full commit SHA.
+fn is_commit_sha(version: &str) -> bool {
+    version.len() == 40 && version.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+#[cfg(test)]
+mod tests {
+    use skootrs_model::skootrs::{CodeReviewParams, GuacForwardingConfig, InitializedGiteaRepo, InitializedGo, InitializedGradle, InitializedLocalRepo, InitializedNpm, InitializedPython};
+
+    use super::*;
+
+    fn cargo_source_bundle_params(crate_type: CargoCrateType, facet_type: SupportedFacetType) -> SourceBundleFacetParams {
+        let ecosystem = InitializedEcosystem::Cargo(InitializedCargo {
+            name: "test-crate".to_string(),
+            crate_type,
+            edition: "2021".to_string(),
+        });
+        source_bundle_params(vec![ecosystem.into()], facet_type)
+    }
+
+    fn source_bundle_params(
+        ecosystems: Vec<InitializedProjectEcosystem>,
+        facet_type: SupportedFacetType,
+    ) -> SourceBundleFacetParams {
+        SourceBundleFacetParams {
+            common: CommonFacetParams {
+                project_name: "test".to_string(),
+                source: InitializedSource {
+                    path: "test".to_string(),
+                },
+                repo: InitializedRepo::Local(InitializedLocalRepo {
+                    name: "test".to_string(),
+                    path: "/tmp/remotes/test.git".to_string(),
+                }),
+                ecosystems,
+                license: SupportedLicense::Apache2,
+                code_review: CodeReviewParams::default(),
+                facets: FacetSetParamsGenerator {}.default_facet_types(),
+                guac_forwarding: None,
+                default_branch: "main".to_string(),
+            },
+            facet_type,
+        }
+    }
+
+    #[test]
+    fn test_rust_source_bundle_content() {
+        let params = cargo_source_bundle_params(CargoCrateType::Library, SupportedFacetType::DefaultSourceCode);
+        let handler = RustGithubSourceBundleContentHandler { ecosystem: &params.common.ecosystems[0] };
+        let content = handler.generate_content(&params).unwrap();
+        assert_eq!(content.source_files_content[0].path, "src/");
+        assert_eq!(content.source_files_content[0].name, "lib.rs");
+
+        let params = cargo_source_bundle_params(CargoCrateType::Binary, SupportedFacetType::SLSABuild);
+        let handler = RustGithubSourceBundleContentHandler { ecosystem: &params.common.ecosystems[0] };
+        let content = handler.generate_content(&params).unwrap();
+        let release_workflow = &content.source_files_content[0].content;
+        assert!(release_workflow.contains("CRATE_NAME: test-crate"));
+        assert!(release_workflow.contains("BINARY: true"));
+        assert!(release_workflow.contains("${{ steps.hash.outputs.hashes }}"));
+
+        let params = cargo_source_bundle_params(CargoCrateType::Binary, SupportedFacetType::Fuzzing);
+        let handler = RustGithubSourceBundleContentHandler { ecosystem: &params.common.ecosystems[0] };
+        let content = handler.generate_content(&params).unwrap();
+        let fuzz_manifest = content
+            .source_files_content
+            .iter()
+            .find(|file| file.path == "fuzz/" && file.name == "Cargo.toml")
+            .unwrap();
+        assert!(fuzz_manifest.content.contains("name = \"test-crate-fuzz\""));
+        // Binary crates have no library for the fuzz targets to depend on.
+        assert!(!fuzz_manifest.content.contains("[dependencies.test-crate]"));
+    }
+
+    #[test]
+    fn test_license_content_uses_project_license() {
+        let mut params = cargo_source_bundle_params(CargoCrateType::Library, SupportedFacetType::License);
+        let content = DefaultSourceBundleContentHandler {}.generate_content(&params).unwrap();
+        assert!(content.source_files_content[0].content.contains("Apache License"));
+
+        params.common.license = SupportedLicense::Mit;
+        let content = DefaultSourceBundleContentHandler {}.generate_content(&params).unwrap();
+        assert_eq!(content.source_files_content[0].name, "LICENSE");
+        assert!(content.source_files_content[0].content.starts_with("MIT License"));
+        assert!(content.source_files_content[0].content.contains("test authors"));
+
+        params.common.license = SupportedLicense::Bsd3Clause;
+        let content = DefaultSourceBundleContentHandler {}.generate_content(&params).unwrap();
+        assert!(content.source_files_content[0].content.starts_with("BSD 3-Clause License"));
+    }
+
+    #[test]
+    fn test_sast_content_uses_ecosystem_language() {
+        let ecosystem = InitializedEcosystem::Npm(InitializedNpm {
+            name: "test".to_string(),
+            scope: None,
+            typescript: true,
+        });
+        let params = source_bundle_params(vec![ecosystem.into()], SupportedFacetType::SAST);
+
+        let content = DefaultSourceBundleContentHandler {}.generate_content(&params).unwrap();
+        let workflow = &content.source_files_content[0].content;
+        assert!(workflow.contains("language: [ 'javascript-typescript' ]"));
+        // Only Go needs its toolchain set up before the autobuild.
+        assert!(!workflow.contains("Set up Go"));
+        assert!(workflow.contains("${{ matrix.language }}"));
+    }
+
+    #[test]
+    fn test_maven_source_bundle_content() {
+        let ecosystem = InitializedEcosystem::Maven(InitializedMaven {
+            group_id: "com.example".to_string(),
+            artifact_id: "my-project".to_string(),
+        })
+        .into();
+        let handler = MavenGithubSourceBundleContentHandler { ecosystem: &ecosystem };
+        let mut params = source_bundle_params(vec![ecosystem.clone()], SupportedFacetType::Fuzzing);
+
+        let content = handler.generate_content(&params).unwrap();
+        let fuzz_pom = content
+            .source_files_content
+            .iter()
+            .find(|file| file.path == "fuzz/" && file.name == "pom.xml")
+            .unwrap();
+        assert!(fuzz_pom.content.contains("<artifactId>my-project-fuzz</artifactId>"));
+        assert!(content
+            .source_files_content
+            .iter()
+            .any(|file| file.path == "fuzz/src/main/java/com/example/" && file.name == "AppFuzzer.java"));
+
+        params.facet_type = SupportedFacetType::DefaultSourceCode;
+        let content = handler.generate_content(&params).unwrap();
+        assert_eq!(content.source_files_content[0].path, "src/main/java/com/example/");
+        assert!(content.source_files_content[0].content.starts_with("package com.example;"));
+
+        // Facets that aren't specific to an ecosystem aren't generated by its handler.
+        params.facet_type = SupportedFacetType::Allstar;
+        assert!(handler.generate_content(&params).is_err());
+
+        params.facet_type = SupportedFacetType::SecurityInsights;
+        let content = DefaultSourceBundleContentHandler {}.generate_content(&params).unwrap();
+        assert!(content.source_files_content[0].content.contains("/blob/main/pom.xml"));
+    }
+
+    #[test]
+    fn test_gradle_source_bundle_content() {
+        let ecosystem = InitializedEcosystem::Gradle(InitializedGradle {
+            group: "com.example".to_string(),
+            name: "my-project".to_string(),
+            gradle_version: "8.5".to_string(),
+            distribution_sha256_sum: "9d926787066a081739e8200858338b4a69e837c3a821a33aca9db09dd4a41026".to_string(),
+        })
+        .into();
+        let handler = GradleGithubSourceBundleContentHandler { ecosystem: &ecosystem };
+        let mut params = source_bundle_params(vec![ecosystem.clone()], SupportedFacetType::DefaultSourceCode);
+
+        let content = handler.generate_content(&params).unwrap();
+        assert_eq!(content.source_files_content[0].path, "src/main/java/com/example/my_project/");
+        assert!(content.source_files_content[0].content.starts_with("package com.example.my_project;"));
+        assert_eq!(content.source_files_content[1].path, "src/test/java/com/example/my_project/");
+
+        params.facet_type = SupportedFacetType::SLSABuild;
+        let content = handler.generate_content(&params).unwrap();
+        let release_workflow = &content.source_files_content[0].content;
+        assert!(release_workflow.contains("gradle-${version}-wrapper.jar.sha256"));
+        assert!(release_workflow.contains("${{ steps.hash.outputs.hashes }}"));
+    }
+
+    #[test]
+    fn test_python_source_bundle_content() {
+        let ecosystem = InitializedProjectEcosystem {
+            path: "tools/scripts".to_string(),
+            ecosystem: InitializedEcosystem::Python(InitializedPython {
+                name: "test-scripts".to_string(),
+                python_version: "3.9".to_string(),
+            }),
+        };
+        let handler = PythonGithubSourceBundleContentHandler { ecosystem: &ecosystem };
+        let params = source_bundle_params(vec![ecosystem.clone()], SupportedFacetType::SLSABuild);
+
+        let content = handler.generate_content(&params).unwrap();
+        let release_workflow = &content.source_files_content[0].content;
+        // Publishing goes through PyPA's action instead of exchanging the OIDC token by hand.
+        assert!(release_workflow
+            .contains("uses: pypa/gh-action-pypi-publish@81e9d935c883d0b210363ab89cf05f3894778450 # v1.8.14"));
+        assert!(release_workflow.contains("packages-dir: tools/scripts/dist/"));
+        assert!(!release_workflow.contains("twine"));
+    }
+
+    #[test]
+    fn test_source_bundle_content_for_multiple_ecosystems() {
+        let ecosystems = vec![
+            InitializedProjectEcosystem {
+                path: "backend".to_string(),
+                ecosystem: InitializedEcosystem::Go(InitializedGo {
+                    name: "test".to_string(),
+                    host: "github.com/testuser".to_string(),
+                    major_version: None,
+                }),
+            },
+            InitializedProjectEcosystem {
+                path: "./frontend/".to_string(),
+                ecosystem: InitializedEcosystem::Npm(InitializedNpm {
+                    name: "test".to_string(),
+                    scope: None,
+                    typescript: true,
+                }),
+            },
         ];
-        let facets_params = supported_facets
+        let mut params = source_bundle_params(ecosystems, SupportedFacetType::Gitignore);
+
+        let content = EcosystemsSourceBundleContentHandler {}.generate_content(&params).unwrap();
+        let paths: Vec<(&str, &str)> = content
+            .source_files_content
             .iter()
-            .map(|facet_type| {
-                FacetParams::SourceBundle(SourceBundleFacetParams {
-                    common: common_params.clone(),
-                    facet_type: facet_type.clone(),
-                })
+            .map(|file| (file.path.as_str(), file.name.as_str()))
+            .collect();
+        assert_eq!(paths, vec![("backend/", ".gitignore"), ("frontend/", ".gitignore")]);
+
+        params.facet_type = SupportedFacetType::SLSABuild;
+        let content = EcosystemsSourceBundleContentHandler {}.generate_content(&params).unwrap();
+        let go_workflow = content
+            .source_files_content
+            .iter()
+            .find(|file| file.name == "releases-backend.yml")
+            .unwrap();
+        assert!(go_workflow.content.contains("workdir: backend"));
+        assert!(content
+            .source_files_content
+            .iter()
+            .any(|file| file.path == "backend/" && file.name == ".goreleaser.yml"));
+        let npm_workflow = content
+            .source_files_content
+            .iter()
+            .find(|file| file.name == "releases-frontend.yml")
+            .unwrap();
+        assert!(npm_workflow.content.contains("working-directory: frontend"));
+
+        params.facet_type = SupportedFacetType::DependencyUpdateTool;
+        let content = DefaultSourceBundleContentHandler {}.generate_content(&params).unwrap();
+        let dependabot = &content.source_files_content[0].content;
+        assert!(dependabot.contains("package-ecosystem: gomod\n      directory: \"/backend\""));
+        assert!(dependabot.contains("package-ecosystem: npm\n      directory: \"/frontend\""));
+        assert!(dependabot.contains("package-ecosystem: \"github-actions\""));
+
+        params.facet_type = SupportedFacetType::SAST;
+        let content = DefaultSourceBundleContentHandler {}.generate_content(&params).unwrap();
+        let workflow = &content.source_files_content[0].content;
+        assert!(workflow.contains("language: [ 'go', 'javascript-typescript' ]"));
+        assert!(workflow.contains("Set up Go"));
+
+        params.facet_type = SupportedFacetType::SecurityInsights;
+        let content = DefaultSourceBundleContentHandler {}.generate_content(&params).unwrap();
+        let insights = &content.source_files_content[0].content;
+        assert!(insights.contains("/blob/main/backend/go.mod"));
+        assert!(insights.contains("/blob/main/frontend/package.json"));
+    }
+
+    #[test]
+    fn test_sbom_generator_content() {
+        let mut params = cargo_source_bundle_params(CargoCrateType::Binary, SupportedFacetType::SBOMGenerator);
+        params.common.ecosystems.extend([
+            InitializedEcosystem::Npm(InitializedNpm {
+                name: "test".to_string(),
+                scope: None,
+                typescript: true,
             })
-            .collect::<Vec<FacetParams>>();
+            .into(),
+            InitializedProjectEcosystem {
+                path: "tools/scripts".to_string(),
+                ecosystem: InitializedEcosystem::Python(InitializedPython {
+                    name: "test-scripts".to_string(),
+                    python_version: "3.9".to_string(),
+                }),
+            },
+        ]);
+
+        let content = DefaultSourceBundleContentHandler {}.generate_content(&params).unwrap();
+        assert_eq!(content.source_files_content.len(), 1);
+        assert_eq!(content.source_files_content[0].name, "sbom.yml");
+        let workflow = &content.source_files_content[0].content;
+        // The ecosystems in the root share a single SBOM of each format.
+        assert_eq!(workflow.matches("format: spdx-json").count(), 2);
+        assert_eq!(workflow.matches("format: cyclonedx-json").count(), 2);
+        assert!(workflow.contains("path: .\n"));
+        assert!(workflow.contains("output-file: sboms/test.spdx.json"));
+        assert!(workflow.contains("path: tools/scripts\n"));
+        assert!(workflow.contains("output-file: sboms/test-tools-scripts.cyclonedx.json"));
+        assert!(workflow.contains("gh release upload \"$GITHUB_REF_NAME\" sboms/*"));
+
+        params.facet_type = SupportedFacetType::SecurityInsights;
+        let content = DefaultSourceBundleContentHandler {}.generate_content(&params).unwrap();
+        let insights = &content.source_files_content[0].content;
+        assert!(insights.contains("/releases/latest/download/test.spdx.json"));
+        assert!(insights.contains("/releases/latest/download/test-tools-scripts.cyclonedx.json"));
+        // Only Go projects have SBOMs generated by goreleaser.
+        assert!(!insights.contains("goreleaser"));
+
+        params.common.facets.retain(|facet_type| *facet_type != SupportedFacetType::SBOMGenerator);
+        let content = DefaultSourceBundleContentHandler {}.generate_content(&params).unwrap();
+        assert!(!content.source_files_content[0].content.contains("Created by syft"));
+    }
 
-        Ok(FacetSetParams { facets_params })
+    #[test]
+    fn test_code_owner() {
+        let mut params = cargo_source_bundle_params(CargoCrateType::Binary, SupportedFacetType::CodeReview);
+        assert_eq!(code_owner(&params.common), None);
+
+        params.common.repo = InitializedRepo::Github(InitializedGithubRepo {
+            name: "test".to_string(),
+            organization: GithubUser::User("octocat".to_string()),
+        });
+        assert_eq!(code_owner(&params.common), Some("@octocat".to_string()));
+
+        params.common.repo = InitializedRepo::Github(InitializedGithubRepo {
+            name: "test".to_string(),
+            organization: GithubUser::Organization("kusaridev".to_string()),
+        });
+        assert_eq!(code_owner(&params.common), None);
+
+        params.common.code_review = CodeReviewParams {
+            owning_team: Some("@kusaridev/security".to_string()),
+            ..CodeReviewParams::default()
+        };
+        assert_eq!(code_owner(&params.common), Some("@kusaridev/security".to_string()));
+    }
+
+    #[test]
+    fn test_code_review_content() {
+        let mut params = cargo_source_bundle_params(CargoCrateType::Binary, SupportedFacetType::CodeReview);
+        // A local repo without an owning team has no one to own its code.
+        let content = DefaultSourceBundleContentHandler {}.generate_content(&params).unwrap();
+        assert!(content.source_files_content.is_empty());
+
+        params.common.repo = InitializedRepo::Github(InitializedGithubRepo {
+            name: "test".to_string(),
+            organization: GithubUser::Organization("kusaridev".to_string()),
+        });
+        params.common.code_review.owning_team = Some("kusaridev/security".to_string());
+        let content = DefaultSourceBundleContentHandler {}.generate_content(&params).unwrap();
+        assert_eq!(content.facet_type, SupportedFacetType::CodeReview);
+        assert_eq!(content.source_files_content.len(), 1);
+        assert_eq!(content.source_files_content[0].name, "CODEOWNERS");
+        assert_eq!(content.source_files_content[0].path, "./.github");
+        assert!(content.source_files_content[0].content.lines().any(|line| line == "* @kusaridev/security"));
+    }
+
+    #[test]
+    fn test_allstar_content() {
+        let mut params = cargo_source_bundle_params(CargoCrateType::Binary, SupportedFacetType::Allstar);
+        // Allstar is only available for Github.
+        let content = DefaultSourceBundleContentHandler {}.generate_content(&params).unwrap();
+        assert!(content.source_files_content.is_empty());
+
+        params.common.repo = InitializedRepo::Github(InitializedGithubRepo {
+            name: "test".to_string(),
+            organization: GithubUser::Organization("kusaridev".to_string()),
+        });
+        params.common.ecosystems.push(InitializedProjectEcosystem {
+            path: "tools".to_string(),
+            ecosystem: InitializedEcosystem::Gradle(InitializedGradle {
+                group: "com.kusaridev".to_string(),
+                name: "tools".to_string(),
+                gradle_version: "8.5".to_string(),
+                distribution_sha256_sum: "9d926787066a081739e8200858338b4a69e837c3a821a33aca9db09dd4a41026"
+                    .to_string(),
+            }),
+        });
+        params.common.code_review.required_approving_review_count = 2;
+        params.common.facets.retain(|facet_type| *facet_type != SupportedFacetType::SecurityPolicy);
+        let content = DefaultSourceBundleContentHandler {}.generate_content(&params).unwrap();
+        let file = |name: &str| {
+            content
+                .source_files_content
+                .iter()
+                .find(|file| file.name == name && file.path == "./.allstar")
+                .unwrap()
+                .content
+                .clone()
+        };
+        assert!(file("allstar.yaml").contains("optIn: true"));
+        let branch_protection = file("branch_protection.yaml");
+        assert!(branch_protection.contains("optIn: true"));
+        assert!(branch_protection.contains("requireApproval: true"));
+        assert!(branch_protection.contains("approvalCount: 2"));
+        // The organization itself can't own code, so code owner reviews aren't required without a team.
+        assert!(branch_protection.contains("requireCodeOwnerReviews: false"));
+        assert!(file("binary_artifacts.yaml").ends_with("ignorePaths:\n  - tools/gradle/wrapper/gradle-wrapper.jar"));
+        assert!(file("outside.yaml").contains("optIn: true"));
+        assert!(file("security.yaml").contains("optOut: true"));
+    }
+
+    #[test]
+    fn test_guac_forwarding_content() {
+        let mut params =
+            cargo_source_bundle_params(CargoCrateType::Binary, SupportedFacetType::GUACForwardingConfig);
+        // Nothing is forwarded without a GUAC collector to forward to.
+        let content = DefaultSourceBundleContentHandler {}.generate_content(&params).unwrap();
+        assert!(content.source_files_content.is_empty());
+
+        params.common.guac_forwarding = Some(GuacForwardingConfig {
+            endpoint: "https://guac.example.com/api/v1/documents".to_string(),
+            credentials_secret: "GUAC_TOKEN".to_string(),
+        });
+        let content = DefaultSourceBundleContentHandler {}.generate_content(&params).unwrap();
+        assert_eq!(content.source_files_content.len(), 1);
+        assert_eq!(content.source_files_content[0].name, "guac.yml");
+        assert_eq!(content.source_files_content[0].path, "./.github/workflows");
+        let workflow = &content.source_files_content[0].content;
+        assert!(workflow.contains("GUAC_COLLECTOR_ENDPOINT: \"https://guac.example.com/api/v1/documents\""));
+        assert!(workflow.contains("GUAC_COLLECTOR_TOKEN: ${{ secrets.GUAC_TOKEN }}"));
+        assert!(workflow.contains(
+            "--pattern '*.spdx.json' --pattern '*.sbom.json' --pattern '*.cyclonedx.json' --pattern '*.intoto.jsonl'"
+        ));
+        assert!(workflow.contains("https://api.securityscorecards.dev/projects/github.com/$GITHUB_REPOSITORY\" || {"));
+        // The documents are forwarded once the workflows that upload them have completed.
+        assert!(workflow.contains("workflow_run:\n    workflows:\n      - release\n      - sbom\n    types:\n      - completed"));
+        assert!(workflow.contains("if: github.event_name == 'workflow_dispatch' || github.event.workflow_run.conclusion == 'success'"));
+    }
+
+    #[test]
+    fn test_vulnerability_scanner_content() {
+        let mut params =
+            cargo_source_bundle_params(CargoCrateType::Binary, SupportedFacetType::VulnerabilityScanner);
+        params.common.ecosystems.push(InitializedProjectEcosystem {
+            path: "services/backend".to_string(),
+            ecosystem: InitializedEcosystem::Go(InitializedGo {
+                name: "backend".to_string(),
+                host: "github.com/kusaridev".to_string(),
+                major_version: None,
+            }),
+        });
+
+        let content = DefaultSourceBundleContentHandler {}.generate_content(&params).unwrap();
+        assert_eq!(content.source_files_content.len(), 1);
+        assert_eq!(content.source_files_content[0].name, "vulnerability-scanner.yml");
+        assert_eq!(content.source_files_content[0].path, "./.github/workflows");
+        let workflow = &content.source_files_content[0].content;
+        let jobs = serde_yaml::from_str::<serde_yaml::Value>(workflow).unwrap()["jobs"].clone();
+        assert_eq!(jobs.as_mapping().unwrap().len(), 2);
+        assert!(workflow.contains("--recursive . services/backend || status=$?"));
+        // Only the Go module is scanned with govulncheck.
+        assert_eq!(workflow.matches("govulncheck -format sarif").count(), 1);
+        assert!(workflow.contains("  govulncheck-services-backend:\n"));
+        assert!(workflow.contains("working-directory: services/backend\n"));
+        assert!(workflow.contains("category: govulncheck-services-backend"));
+
+        params.facet_type = SupportedFacetType::SecurityInsights;
+        let content = DefaultSourceBundleContentHandler {}.generate_content(&params).unwrap();
+        let insights: SecurityInsightsVersion100YamlSchema =
+            serde_yaml::from_str(&content.source_files_content[0].content).unwrap();
+        let tool_names: Vec<&str> = insights
+            .security_testing
+            .iter()
+            .map(|security_testing| security_testing.tool_name.as_str())
+            .collect();
+        assert_eq!(tool_names, ["OSV-Scanner", "govulncheck"]);
+
+        // The scanners aren't listed when their workflow isn't created.
+        params.common.facets.retain(|facet_type| *facet_type != SupportedFacetType::VulnerabilityScanner);
+        let content = DefaultSourceBundleContentHandler {}.generate_content(&params).unwrap();
+        let insights: SecurityInsightsVersion100YamlSchema =
+            serde_yaml::from_str(&content.source_files_content[0].content).unwrap();
+        assert!(insights.security_testing.is_empty());
+    }
+
+    /// Records when each facet starts and finishes initializing, yielding in between so facets initialized
+    /// concurrently would interleave.
+    struct RecordingFacetService {
+        events: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl RootFacetService for RecordingFacetService {
+        async fn initialize(&self, params: FacetParams) -> Result<InitializedFacet, SkootError> {
+            let FacetParams::APIBundle(params) = params else {
+                unreachable!("Only API bundle facets are initialized in this test")
+            };
+            self.events.lock().unwrap().push(format!("start {}", params.facet_type));
+            tokio::task::yield_now().await;
+            self.events.lock().unwrap().push(format!("end {}", params.facet_type));
+            Ok(InitializedFacet::APIBundle(APIBundleFacet {
+                facet_type: params.facet_type,
+                apis: vec![],
+            }))
+        }
+
+        async fn initialize_all(&self, params: FacetSetParams) -> Result<Vec<InitializedFacet>, SkootError> {
+            initialize_in_order(self, params).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_api_bundle_facets_are_initialized_in_order() {
+        let params = cargo_source_bundle_params(CargoCrateType::Binary, SupportedFacetType::BranchProtection);
+        let api_bundle_params = FacetSetParamsGenerator {}.generate_default_api_bundle(&params.common).unwrap();
+        let service = RecordingFacetService {
+            events: std::sync::Mutex::new(Vec::new()),
+        };
+
+        service.initialize_all(api_bundle_params).await.unwrap();
+
+        // Code review updates the protection of the branch, so it has to start after branch protection is done.
+        assert_eq!(
+            *service.events.lock().unwrap(),
+            [
+                "start BranchProtection",
+                "end BranchProtection",
+                "start VulnerabilityReporting",
+                "end VulnerabilityReporting",
+                "start CodeReview",
+                "end CodeReview",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dependency_pinner_pins_actions() {
+        let dependency_pinner = DependencyPinner::from_lock(
+            r#"
+            [actions."actions/checkout"]
+            "v4.1.1" = "b4ffde65f46336ab88eb53be808477a3936bae11"
+
+            [actions."github/codeql-action"]
+            "v3.23.2" = "b7bf0a3ed3ecfa44160715d7c442788f65f0f923"
+            "#,
+        )
+        .unwrap();
+        let workflow = "steps:
+      - name: Checkout
+        uses: actions/checkout@v4.1.1
+      - uses: \"github/codeql-action/init@v3.23.2\" # init
+      - uses: actions/setup-go@0c52d547c9bc32b1aa3301fd7a9cb496313a4491 # v5.0.0
+      - uses: ./.github/actions/local
+      - uses: actions/cache@v4
+    uses: slsa-framework/slsa-github-generator/.github/workflows/generator_generic_slsa3.yml@v1.9.0
+";
+
+        let pinned = dependency_pinner.pin_workflow(workflow);
+
+        assert_eq!(
+            pinned,
+            "steps:
+      - name: Checkout
+        uses: actions/checkout@b4ffde65f46336ab88eb53be808477a3936bae11 # v4.1.1
+      - uses: github/codeql-action/init@b7bf0a3ed3ecfa44160715d7c442788f65f0f923 # v3.23.2
+      - uses: actions/setup-go@0c52d547c9bc32b1aa3301fd7a9cb496313a4491 # v5.0.0
+      - uses: ./.github/actions/local
+      - uses: actions/cache@v4
+    uses: slsa-framework/slsa-github-generator/.github/workflows/generator_generic_slsa3.yml@v1.9.0
+"
+        );
+    }
+
+    #[test]
+    fn test_dependency_pinner_pins_images() {
+        let dependency_pinner = DependencyPinner::from_lock(
+            r#"
+            [images."alpine"]
+            "latest" = "sha256:c5b1261d6d3e43071626931fc004f70149baeba2c8ec672bd4f27761f8e1ad6b"
+            "#,
+        )
+        .unwrap();
+        let dockerfile = "FROM --platform=$BUILDPLATFORM alpine as certs
+FROM scratch
+COPY --from=certs /etc/ssl/certs/ca-certificates.crt /etc/ssl/certs/
+FROM localhost:5000/alpine:3.19";
+
+        let pinned = dependency_pinner.pin_dockerfile(dockerfile);
+
+        assert_eq!(
+            pinned,
+            "FROM --platform=$BUILDPLATFORM alpine:latest@sha256:c5b1261d6d3e43071626931fc004f70149baeba2c8ec672bd4f27761f8e1ad6b as certs
+FROM scratch
+COPY --from=certs /etc/ssl/certs/ca-certificates.crt /etc/ssl/certs/
+FROM localhost:5000/alpine:3.19"
+        );
+    }
+
+    #[test]
+    fn test_bundled_lock_pins_generated_workflows() {
+        let dependency_pinner = DependencyPinner::bundled().unwrap();
+        let params = cargo_source_bundle_params(CargoCrateType::Binary, SupportedFacetType::Scorecard);
+        let scorecard = DefaultSourceBundleContentHandler {}.generate_content(&params).unwrap();
+        let workflow = &scorecard.source_files_content[0].content;
+
+        // The generated workflows are already pinned to the SHAs in the lock file.
+        assert_eq!(&dependency_pinner.pin_workflow(workflow), workflow);
+        let unpinned = workflow.replace(
+            "actions/checkout@b4ffde65f46336ab88eb53be808477a3936bae11 # v4.1.1",
+            "actions/checkout@v4.1.1",
+        );
+        assert_ne!(&unpinned, workflow);
+        assert_eq!(&dependency_pinner.pin_workflow(&unpinned), workflow);
+    }
+
+    #[test]
+    fn test_gitignore_content_for_ecosystems_in_same_directory() {
+        let mut params = cargo_source_bundle_params(CargoCrateType::Binary, SupportedFacetType::Gitignore);
+        params.common.ecosystems.push(
+            InitializedEcosystem::Npm(InitializedNpm {
+                name: "test".to_string(),
+                scope: None,
+                typescript: false,
+            })
+            .into(),
+        );
+
+        let content = EcosystemsSourceBundleContentHandler {}.generate_content(&params).unwrap();
+        assert_eq!(content.source_files_content.len(), 1);
+        assert_eq!(content.source_files_content[0].path, "./");
+        assert!(content.source_files_content[0].content.contains("target"));
+        assert!(content.source_files_content[0].content.contains("node_modules"));
+
+        // Each ecosystem releases with its own workflow, named after the ecosystem if it shares one.
+        params.facet_type = SupportedFacetType::SLSABuild;
+        let content = EcosystemsSourceBundleContentHandler {}.generate_content(&params).unwrap();
+        let workflows: Vec<&str> = content
+            .source_files_content
+            .iter()
+            .filter(|file| normalize_path(&file.path) == ".github/workflows")
+            .map(|file| file.name.as_str())
+            .collect();
+        assert_eq!(workflows, ["releases.yml", "releases-npm.yml"]);
+    }
+
+    #[tokio::test]
+    async fn test_gitea_vulnerability_reporting_not_applicable() {
+        let params = APIBundleFacetParams {
+            common: CommonFacetParams {
+                project_name: "test".to_string(),
+                source: InitializedSource {
+                    path: "test".to_string(),
+                },
+                repo: InitializedRepo::Gitea(InitializedGiteaRepo {
+                    name: "test".to_string(),
+                    owner: "testuser".to_string(),
+                    host_url: "http://localhost:3000".to_string(),
+                }),
+                ecosystems: vec![InitializedEcosystem::Go(InitializedGo {
+                    name: "test".to_string(),
+                    host: "localhost:3000/testuser".to_string(),
+                    major_version: None,
+                })
+                .into()],
+                license: SupportedLicense::Apache2,
+                code_review: CodeReviewParams::default(),
+                facets: FacetSetParamsGenerator {}.default_facet_types(),
+                guac_forwarding: None,
+                default_branch: "main".to_string(),
+            },
+            facet_type: SupportedFacetType::VulnerabilityReporting,
+        };
+
+        let api_bundle_facet = APIBundleFacetService::initialize(&LocalFacetService {}, params)
+            .await
+            .unwrap();
+
+        assert_eq!(api_bundle_facet.facet_type, SupportedFacetType::VulnerabilityReporting);
+        assert!(api_bundle_facet.apis.is_empty());
+    }
+
+    #[test]
+    fn test_content_follows_default_branch() {
+        let mut params = cargo_source_bundle_params(CargoCrateType::Binary, SupportedFacetType::SecurityInsights);
+        params.common.default_branch = "trunk".to_string();
+        let handler = DefaultSourceBundleContentHandler {};
+
+        let insights = &handler.generate_content(&params).unwrap().source_files_content[0].content;
+        assert!(insights.contains("/blob/trunk/Cargo.toml"));
+        assert!(insights.contains("/blob/trunk/LICENSE"));
+        assert!(insights.contains("/blob/trunk/SECURITY.md"));
+        assert!(!insights.contains("/blob/main/"));
+
+        for facet_type in [
+            SupportedFacetType::Scorecard,
+            SupportedFacetType::SAST,
+            SupportedFacetType::VulnerabilityScanner,
+        ] {
+            params.facet_type = facet_type;
+            let workflow = &handler.generate_content(&params).unwrap().source_files_content[0].content;
+            assert!(workflow.contains(r#"branches: [ "trunk" ]"#));
+            assert!(!workflow.contains(r#""main""#));
+        }
+
+        params.facet_type = SupportedFacetType::SBOMGenerator;
+        let sbom = &handler.generate_content(&params).unwrap().source_files_content[0].content;
+        assert!(sbom.lines().any(|line| line == "      - trunk"));
+
+        params.facet_type = SupportedFacetType::SLSABuild;
+        let handler = RustGithubSourceBundleContentHandler { ecosystem: &params.common.ecosystems[0] };
+        let release = &handler.generate_content(&params).unwrap().source_files_content[0].content;
+        assert!(release.lines().any(|line| line == "      - trunk"));
+        assert!(!release.lines().any(|line| line == "      - main"));
     }
-}
\ No newline at end of file
+}
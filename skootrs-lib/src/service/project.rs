@@ -15,24 +15,34 @@
 
 #![allow(clippy::module_name_repetitions)]
 
-use std::error::Error;
+use std::{error::Error, path::Path};
 
-use crate::service::facet::{FacetSetParamsGenerator, RootFacetService};
+use crate::service::facet::{normalize_path, FacetDetector, FacetSetParamsGenerator, RootFacetService};
 use skootrs_model::skootrs::{
-    facet::CommonFacetParams, InitializedProject, InitializedSource, ProjectParams,
+    facet::{
+        CommonFacetParams, FacetParams, InitializedFacet, SourceFileContent, SourceFileDrift, SourceFileDriftStatus,
+        SupportedFacetType,
+    },
+    InitializedProject, InitializedProjectEcosystem, InitializedRepo,
+    validate_ecosystem_paths, validate_project_ecosystems, CodeReviewParams, EcosystemDrift, InitializedSource, ProjectImportParams,
+    ProjectParams, SkootError, SupportedLicense,
 };
 
 use super::{
-    ecosystem::EcosystemService,
+    ecosystem::{EcosystemDetector, EcosystemService},
     repo::RepoService,
-    source::SourceService,
+    source::{content_hash, is_not_found, SourceService, DEFAULT_BRANCH},
 };
 use tracing::debug;
 
+/// The name of the file in the root of a project's repo that holds the state of the Skootrs project.
+pub const IN_REPO_STATE_FILE: &str = ".skootrs";
+
 /// The `ProjectService` trait provides an interface for initializing and managing a Skootrs project.
 pub trait ProjectService {
-    /// Initializes a Skootrs project.
-    ///h
+    /// Initializes a Skootrs project. The project's state is written to the `.skootrs` file of its repo and
+    /// committed along with the rest of the initial source.
+    ///
     /// # Errors
     ///
     /// Returns an error if the project can't be initialized for any reason.
@@ -40,6 +50,47 @@ pub trait ProjectService {
         &self,
         params: ProjectParams,
     ) -> impl std::future::Future<Output = Result<InitializedProject, Box<dyn Error + Send + Sync>>> + Send;
+
+    /// Imports an existing repository that wasn't created by Skootrs as a Skootrs project. The repo is cloned
+    /// locally and the facets Skootrs manages that already exist in it are recorded, along with the default
+    /// facets that are missing. The import itself doesn't write to the repo, storing the project's state, e.g.
+    /// committing and pushing it in the repo's `.skootrs` file, is left to the caller.
+    ///
+    /// Note: API bundle facets like branch protection can't be detected from the source so they are always
+    /// recorded as missing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repo URL isn't supported, the repo can't be cloned, or the facets can't be detected.
+    fn import(
+        &self,
+        params: ProjectImportParams,
+    ) -> impl std::future::Future<Output = Result<InitializedProject, SkootError>> + Send;
+
+    /// Checks the source files of a project's facets for changes made out of band of Skootrs. The hashes
+    /// recorded when the files were written are compared with the files in the `source` working copy, which
+    /// can be the project's local working copy or a fresh clone of the remote.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the files exist but can't be read.
+    fn drift(
+        &self,
+        project: &InitializedProject,
+        source: &InitializedSource,
+    ) -> Result<Vec<SourceFileDrift>, SkootError>;
+
+    /// Checks that the ecosystems recorded for a project still match the manifests in the `source` working
+    /// copy, e.g. that a Go module wasn't renamed or a package.json deleted out of band of Skootrs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the manifests exist but can't be read.
+    fn ecosystem_drift(
+        &self,
+        project: &InitializedProject,
+        source: &InitializedSource,
+    ) -> Result<Vec<EcosystemDrift>, SkootError>;
 }
 
 /// The `LocalProjectService` struct provides an implementation of the `ProjectService` trait for initializing
@@ -52,6 +103,16 @@ pub struct LocalProjectService<RS: RepoService, ES: EcosystemService, SS: Source
     pub facet_service: FS,
 }
 
+impl<RS: RepoService, ES: EcosystemService, SS: SourceService, FS: RootFacetService> LocalProjectService<RS, ES, SS, FS> {
+    /// Writes the state of `project` to the `.skootrs` file of its working copy and returns what was written.
+    fn write_state(&self, project: &InitializedProject) -> Result<String, SkootError> {
+        let state = serde_json::to_string_pretty(project)?;
+        self.source_service
+            .write_file(project.source.clone(), "./", IN_REPO_STATE_FILE.to_string(), &state)?;
+        Ok(state)
+    }
+}
+
 impl<RS, ES, SS, FS> ProjectService for LocalProjectService<RS, ES, SS, FS>
 where
     RS: RepoService + Send + Sync,
@@ -63,6 +124,7 @@ where
         &self,
         params: ProjectParams,
     ) -> Result<InitializedProject, Box<dyn Error + Send + Sync>> {
+        validate_project_ecosystems(&params.ecosystems)?;
         debug!("Starting repo initialization");
         let initialized_repo = self
             .repo_service
@@ -73,9 +135,26 @@ where
             .source_service
             .initialize(params.source_params.clone(), initialized_repo.clone())?;
         debug!("Starting ecosystem initialization");
-        let initialized_ecosystem = self
-            .ecosystem_service
-            .initialize(params.ecosystem_params.clone(), initialized_source.clone())?;
+        let initialized_ecosystems = params
+            .ecosystems
+            .iter()
+            .map(|ecosystem_params| {
+                // Each ecosystem is initialized in its own directory of the project's source.
+                let ecosystem_source = InitializedSource {
+                    path: Path::new(&initialized_source.path)
+                        .join(ecosystem_params.directory())
+                        .to_string_lossy()
+                        .to_string(),
+                };
+                let ecosystem = self
+                    .ecosystem_service
+                    .initialize(ecosystem_params.ecosystem_params.clone(), ecosystem_source, &params.license)?;
+                Ok(InitializedProjectEcosystem {
+                    path: ecosystem_params.path.clone(),
+                    ecosystem,
+                })
+            })
+            .collect::<Result<Vec<_>, SkootError>>()?;
         debug!("Starting facet initialization");
         // TODO: This is ugly and this should probably be configured somewhere better, preferably outside of code.
         let facet_set_params_generator = FacetSetParamsGenerator {};
@@ -83,37 +162,225 @@ where
             project_name: params.name.clone(),
             source: initialized_source.clone(),
             repo: initialized_repo.clone(),
-            ecosystem: initialized_ecosystem.clone(),
+            ecosystems: initialized_ecosystems.clone(),
+            license: params.license.clone(),
+            code_review: params.code_review.clone(),
+            facets: params
+                .facets
+                .clone()
+                .unwrap_or_else(|| facet_set_params_generator.default_facet_types()),
+            guac_forwarding: params.guac_forwarding.clone(),
+            default_branch: params.default_branch.clone(),
         };
+        let guac_collector_endpoint = common_params
+            .guac_forwarding
+            .as_ref()
+            .filter(|_| common_params.facets.contains(&SupportedFacetType::GUACForwardingConfig))
+            .map(|guac_forwarding| guac_forwarding.endpoint.clone());
         //let facet_set_params = facet_set_params_generator.generate_default(&common_params)?;
-        let source_facet_set_params =
+        let mut source_facet_set_params =
             facet_set_params_generator.generate_default_source_bundle_facet_params(&common_params)?;
-        let api_facet_set_params =
+        let mut api_facet_set_params =
             facet_set_params_generator.generate_default_api_bundle(&common_params)?;
+        if let Some(facets) = &params.facets {
+            source_facet_set_params.retain_facet_types(facets);
+            api_facet_set_params.retain_facet_types(facets);
+        }
         let initialized_source_facets = self
             .facet_service
             .initialize_all(source_facet_set_params)
             .await?;
-        // TODO: Figure out how to better order commits and pushes
+        let initialized_source_facets = apply_pinned_source_files(initialized_source_facets);
+        let mut initialized_project = InitializedProject {
+            repo: initialized_repo,
+            ecosystems: initialized_ecosystems,
+            source: initialized_source,
+            facets: initialized_source_facets,
+            missing_facets: Vec::new(),
+            guac_collector_endpoint,
+        };
+        // The state is committed with the source so the project never exists without it.
+        let initial_state = self.write_state(&initialized_project)?;
         self.source_service.commit_and_push_changes(
-            initialized_source.clone(),
+            initialized_project.source.clone(),
             "Initialized project".to_string(),
         )?;
         let initialized_api_facets = self
             .facet_service
             .initialize_all(api_facet_set_params)
             .await?;
-        let initialized_facets = [initialized_source_facets, initialized_api_facets].concat();
+        initialized_project.facets.extend(initialized_api_facets);
+        if self.write_state(&initialized_project)? != initial_state {
+            self.source_service.commit_and_push_changes(
+                initialized_project.source.clone(),
+                "Updated Skootrs project state".to_string(),
+            )?;
+        }
 
         debug!("Completed project initialization");
 
+        Ok(initialized_project)
+    }
+
+    async fn import(&self, params: ProjectImportParams) -> Result<InitializedProject, SkootError> {
+        validate_ecosystem_paths(&params.ecosystems)?;
+        let initialized_repo = InitializedRepo::from_url(&params.repo_url, &params.forge_hosts)?;
+        debug!("Cloning {} for import", initialized_repo.full_url());
+        let initialized_source = self
+            .repo_service
+            .clone_local(initialized_repo.clone(), params.source_params.parent_path)?;
+        // The ecosystems already exist in the repo so they are recorded as is instead of being initialized.
+        let initialized_ecosystems: Vec<InitializedProjectEcosystem> = if params.ecosystems.is_empty() {
+            debug!("Starting ecosystem detection");
+            EcosystemDetector {}
+                .detect(&self.source_service, &initialized_source)?
+                .into_iter()
+                .map(InitializedProjectEcosystem::from)
+                .collect()
+        } else {
+            params.ecosystems.into_iter().map(InitializedProjectEcosystem::from).collect()
+        };
+        if initialized_ecosystems.is_empty() {
+            return Err(format!("No supported ecosystem detected in {}", initialized_repo.full_url()).into());
+        }
+
+        debug!("Starting facet detection");
+        let facets = FacetDetector {}
+            .detect_source_bundle_facets(&self.source_service, &initialized_source)?;
+        let project_name = match &initialized_repo {
+            InitializedRepo::Github(g) => g.name.clone(),
+            InitializedRepo::Gitlab(g) => g.name.clone(),
+            InitializedRepo::Gitea(g) => g.name.clone(),
+            InitializedRepo::Local(l) => l.name.clone(),
+        };
+        let common_params = CommonFacetParams {
+            project_name,
+            source: initialized_source.clone(),
+            repo: initialized_repo.clone(),
+            ecosystems: initialized_ecosystems.clone(),
+            // The facets are only used to list the missing ones, so the license, code review, the facets
+            // they are set up to match, where releases are forwarded to, and the branch don't matter here.
+            license: SupportedLicense::default(),
+            code_review: CodeReviewParams::default(),
+            facets: Vec::new(),
+            guac_forwarding: None,
+            default_branch: DEFAULT_BRANCH.to_string(),
+        };
+        let facet_set_params_generator = FacetSetParamsGenerator {};
+        let default_facets_params = [
+            facet_set_params_generator.generate_default_source_bundle_facet_params(&common_params)?,
+            facet_set_params_generator.generate_default_api_bundle(&common_params)?,
+        ];
+        let mut missing_facets: Vec<SupportedFacetType> = Vec::new();
+        let missing_facet_types = default_facets_params
+            .into_iter()
+            .flat_map(|facet_set_params| facet_set_params.facets_params)
+            .filter_map(|facet_params| match facet_params {
+                FacetParams::SourceBundle(p) => Some(p.facet_type),
+                FacetParams::APIBundle(p) => Some(p.facet_type),
+                FacetParams::SourceFile(_) => None,
+            })
+            // The project's own code isn't something Skootrs can look for.
+            .filter(|facet_type| *facet_type != SupportedFacetType::DefaultSourceCode)
+            .filter(|facet_type| {
+                !facets.iter().any(|facet| match facet {
+                    InitializedFacet::SourceBundle(f) => f.facet_type == *facet_type,
+                    InitializedFacet::APIBundle(f) => f.facet_type == *facet_type,
+                    InitializedFacet::SourceFile(f) => f.facet_type == *facet_type,
+                })
+            });
+        // Code review is both a source bundle and an API bundle facet, so it is only reported as missing once.
+        for facet_type in missing_facet_types {
+            if !missing_facets.contains(&facet_type) {
+                missing_facets.push(facet_type);
+            }
+        }
+
+        debug!("Completed project import");
+
         Ok(InitializedProject {
             repo: initialized_repo,
-            ecosystem: initialized_ecosystem,
+            ecosystems: initialized_ecosystems,
             source: initialized_source,
-            facets: initialized_facets,
+            facets,
+            missing_facets,
+            guac_collector_endpoint: None,
         })
     }
+
+    fn drift(
+        &self,
+        project: &InitializedProject,
+        source: &InitializedSource,
+    ) -> Result<Vec<SourceFileDrift>, SkootError> {
+        let mut drifted_files = Vec::new();
+        for facet in &project.facets {
+            let InitializedFacet::SourceBundle(source_bundle_facet) = facet else {
+                continue;
+            };
+            for source_file in &source_bundle_facet.source_files {
+                // State from before hashes were recorded still has the content that was written.
+                let recorded_hash = source_file
+                    .hash
+                    .clone()
+                    .unwrap_or_else(|| content_hash(&source_file.content));
+                let status = match self.source_service.read_file(source, &source_file.path, source_file.name.clone()) {
+                    Ok(content) if content_hash(&content) == recorded_hash => continue,
+                    Ok(_) => SourceFileDriftStatus::Changed,
+                    Err(error) if is_not_found(&error) => SourceFileDriftStatus::Deleted,
+                    Err(error) => return Err(error),
+                };
+                debug!("{} in {} is {:?}", source_file.name, source_file.path, status);
+                drifted_files.push(SourceFileDrift {
+                    name: source_file.name.clone(),
+                    path: source_file.path.clone(),
+                    facet_type: source_bundle_facet.facet_type.clone(),
+                    status,
+                });
+            }
+        }
+
+        Ok(drifted_files)
+    }
+
+    fn ecosystem_drift(
+        &self,
+        project: &InitializedProject,
+        source: &InitializedSource,
+    ) -> Result<Vec<EcosystemDrift>, SkootError> {
+        EcosystemDetector {}.check(&self.source_service, source, &project.ecosystems)
+    }
+}
+
+/// Returns the facets with the files the `PinnedDependencies` facet rewrote updated to their pinned content, so
+/// the files aren't reported as drifted from the facets that first wrote them.
+fn apply_pinned_source_files(mut facets: Vec<InitializedFacet>) -> Vec<InitializedFacet> {
+    let pinned_source_files: Vec<SourceFileContent> = facets
+        .iter()
+        .filter_map(|facet| match facet {
+            InitializedFacet::SourceBundle(f) if f.facet_type == SupportedFacetType::PinnedDependencies => {
+                Some(f.source_files.clone())
+            }
+            _ => None,
+        })
+        .flatten()
+        .collect();
+    for facet in &mut facets {
+        let InitializedFacet::SourceBundle(source_bundle_facet) = facet else {
+            continue;
+        };
+        for source_file in &mut source_bundle_facet.source_files {
+            let pinned_source_file = pinned_source_files.iter().find(|pinned| {
+                pinned.name == source_file.name && normalize_path(&pinned.path) == normalize_path(&source_file.path)
+            });
+            if let Some(pinned_source_file) = pinned_source_file {
+                source_file.content.clone_from(&pinned_source_file.content);
+                source_file.hash.clone_from(&pinned_source_file.hash);
+            }
+        }
+    }
+
+    facets
 }
 
 #[cfg(test)]
@@ -122,8 +389,11 @@ mod tests {
         facet::{
             APIBundleFacet, APIContent, FacetParams, FacetSetParams, InitializedFacet,
             SourceBundleFacet, SourceFileContent, SupportedFacetType,
-        }, EcosystemParams, GithubRepoParams, GithubUser, GoParams, InitializedEcosystem, InitializedGithubRepo, InitializedGo, InitializedMaven, InitializedRepo, RepoParams, SkootError, SourceParams
+        }, EcosystemParams, ForgeHosts, GithubRepoParams, GithubUser, GitlabRepoParams, GoParams, GuacForwardingConfig, InitializedEcosystem, InitializedGiteaRepo, InitializedGithubRepo, InitializedGitlabRepo, InitializedGo, InitializedLocalRepo, GitAuthor, InitializedMaven, InitializedRepo, LocalRepoParams, NpmParams, ProjectEcosystemParams, RepoParams, RepoVisibility, SkootError, SourceParams
     };
+    use tempdir::TempDir;
+
+    use crate::service::{facet::LocalFacetService, repo::LocalRepoService, source::LocalSourceService};
 
     use super::*;
     struct MockRepoService;
@@ -134,20 +404,32 @@ mod tests {
     impl RepoService for MockRepoService {
         fn initialize(&self, params: RepoParams) -> impl std::future::Future<Output = Result<InitializedRepo, SkootError>> + Send {
             async {
-                let inner_params = match params {
-                    RepoParams::Github(g) => g,
+                let initialized_repo = match params {
+                    RepoParams::Github(g) => InitializedRepo::Github(InitializedGithubRepo {
+                        name: g.name,
+                        organization: g.organization,
+                    }),
+                    RepoParams::Gitlab(g) => InitializedRepo::Gitlab(InitializedGitlabRepo {
+                        name: g.name,
+                        namespace: g.namespace,
+                        host_url: g.host_url,
+                    }),
+                    RepoParams::Gitea(g) => InitializedRepo::Gitea(InitializedGiteaRepo {
+                        name: g.name,
+                        owner: g.owner,
+                        host_url: g.host_url,
+                    }),
+                    RepoParams::Local(l) => InitializedRepo::Local(InitializedLocalRepo {
+                        path: l.path(),
+                        name: l.name,
+                    }),
                 };
-    
+
                 // Special case for testing error handling
-                if inner_params.name == "error" {
+                if initialized_repo.full_url().ends_with("/error") {
                     return Err("Error".into())
                 }
-    
-                let initialized_repo = InitializedRepo::Github(InitializedGithubRepo {
-                    name: inner_params.name,
-                    organization: inner_params.organization,
-                });
-    
+
                 Ok(initialized_repo)
             }
         }
@@ -157,20 +439,38 @@ mod tests {
             initialized_repo: InitializedRepo,
             path: String,
         ) -> Result<InitializedSource, SkootError> {
-            let inner_repo = match initialized_repo {
-                InitializedRepo::Github(g) => g,
+            let name = match initialized_repo {
+                InitializedRepo::Github(g) => g.name,
+                InitializedRepo::Gitlab(g) => g.name,
+                InitializedRepo::Gitea(g) => g.name,
+                InitializedRepo::Local(l) => l.name,
             };
 
-            if inner_repo.name == "error" {
+            if name == "error" {
                 return Err("Error".into());
             }
 
             let initialized_source = InitializedSource {
-                path: format!("{}/{}", path, inner_repo.name),
+                path: format!("{path}/{name}"),
             };
 
             Ok(initialized_source)
         }
+
+        fn fetch_file_content<P: AsRef<std::path::Path> + Send>(
+            &self,
+            _initialized_repo: &InitializedRepo,
+            path: P,
+        ) -> impl std::future::Future<Output = Result<String, SkootError>> + Send {
+            let name = path.as_ref().to_string_lossy().to_string();
+            async move {
+                if name == "error" {
+                    return Err("Error".into());
+                }
+
+                Ok("Worked".to_string())
+            }
+        }
     }
 
     impl EcosystemService for MockEcosystemService {
@@ -178,6 +478,7 @@ mod tests {
             &self,
             params: EcosystemParams,
             _source: InitializedSource,
+            _license: &SupportedLicense,
         ) -> Result<InitializedEcosystem, SkootError> {
             let initialized_ecosystem = match params {
                 EcosystemParams::Go(g) => {
@@ -187,6 +488,7 @@ mod tests {
                     InitializedEcosystem::Go(InitializedGo{
                         name: g.name,
                         host: g.host,
+                        major_version: None,
                     })
                 }
                 EcosystemParams::Maven(m) => {
@@ -198,6 +500,7 @@ mod tests {
                         artifact_id: m.artifact_id,
                     })
                 }
+                other => InitializedEcosystem::from(other),
             };
 
             Ok(initialized_ecosystem)
@@ -216,6 +519,9 @@ mod tests {
 
             let repo_name = match initialized_repo {
                 InitializedRepo::Github(g) => g.name,
+                InitializedRepo::Gitlab(g) => g.name,
+                InitializedRepo::Gitea(g) => g.name,
+                InitializedRepo::Local(l) => l.name,
             };
 
             let initialized_source = InitializedSource {
@@ -283,6 +589,7 @@ mod tests {
                                 name: "README.md".to_string(),
                                 path: "./".to_string(),
                                 content: s.common.project_name.clone(),
+                                hash: None,
                             }],
                             facet_type: SupportedFacetType::Readme,
                         };
@@ -332,15 +639,24 @@ mod tests {
             repo_params: RepoParams::Github(GithubRepoParams { 
                 name: "test".to_string(),
                 description: "foobar".to_string(), 
-                organization: GithubUser::User("testuser".to_string())
+                organization: GithubUser::User("testuser".to_string()),
+                visibility: RepoVisibility::Public,
             }), 
-            ecosystem_params: EcosystemParams::Go(GoParams { 
+            ecosystems: vec![EcosystemParams::Go(GoParams { 
                 name: "test".to_string(), 
                 host: "github.com".to_string() 
-            }),
+            }).into()],
             source_params: SourceParams { 
                 parent_path: "test".to_string() 
-            }
+            },
+            facets: None,
+            license: SupportedLicense::Apache2,
+            code_review: CodeReviewParams::default(),
+            guac_forwarding: Some(GuacForwardingConfig {
+                endpoint: "https://guac.example.com/api/v1/documents".to_string(),
+                credentials_secret: "GUAC_COLLECTOR_TOKEN".to_string(),
+            }),
+            default_branch: "main".to_string(),
         };
 
         let local_project_service = LocalProjectService {
@@ -356,14 +672,551 @@ mod tests {
         let initialized_project = result.unwrap();
 
         assert!(initialized_project.repo.full_url() == "https://github.com/testuser/test");
-        let module = match initialized_project.ecosystem {
+        assert_eq!(
+            initialized_project.guac_collector_endpoint.as_deref(),
+            Some("https://guac.example.com/api/v1/documents")
+        );
+        let module = match &initialized_project.ecosystems[0].ecosystem {
             InitializedEcosystem::Go(g) => g,
             _ => panic!("Wrong ecosystem type"),
         };
         assert!(module.name == "test");
         assert!(initialized_project.source.path == "test/test");
-        // TODO: This just pulls in the default set of facets which has a length of 12.
+        // TODO: This just pulls in the default set of facets which has a length of 19.
         // This should be more configurable.
-        assert_eq!(initialized_project.facets.len(), 12);
+        assert_eq!(initialized_project.facets.len(), 19);
+    }
+
+    #[tokio::test]
+    async fn test_initialize_gitlab_project() {
+        let project_params = ProjectParams {
+            name: "test".to_string(),
+            repo_params: RepoParams::Gitlab(GitlabRepoParams {
+                name: "test".to_string(),
+                description: "foobar".to_string(),
+                namespace: "testgroup/testsubgroup".to_string(),
+                host_url: "https://gitlab.example.com/".to_string(),
+                visibility: RepoVisibility::Private,
+            }),
+            ecosystems: vec![EcosystemParams::Go(GoParams {
+                name: "test".to_string(),
+                host: "gitlab.example.com/testgroup/testsubgroup".to_string(),
+            }).into()],
+            source_params: SourceParams {
+                parent_path: "test".to_string(),
+            },
+            facets: Some(vec![SupportedFacetType::Readme, SupportedFacetType::BranchProtection]),
+            license: SupportedLicense::Apache2,
+            code_review: CodeReviewParams::default(),
+            guac_forwarding: None,
+            default_branch: "main".to_string(),
+        };
+
+        let local_project_service = LocalProjectService {
+            repo_service: MockRepoService,
+            ecosystem_service: MockEcosystemService,
+            source_service: MockSourceService,
+            facet_service: MockFacetService,
+        };
+
+        let initialized_project = local_project_service.initialize(project_params).await.unwrap();
+
+        assert_eq!(
+            initialized_project.repo.full_url(),
+            "https://gitlab.example.com/testgroup/testsubgroup/test"
+        );
+        assert_eq!(initialized_project.source.path, "test/test");
+        assert_eq!(initialized_project.facets.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_initialize_project_with_multiple_ecosystems() {
+        let project_params = ProjectParams {
+            name: "test".to_string(),
+            repo_params: RepoParams::Github(GithubRepoParams {
+                name: "test".to_string(),
+                description: "foobar".to_string(),
+                organization: GithubUser::User("testuser".to_string()),
+                visibility: RepoVisibility::Public,
+            }),
+            ecosystems: vec![
+                ProjectEcosystemParams {
+                    path: "backend".to_string(),
+                    ecosystem_params: EcosystemParams::Go(GoParams {
+                        name: "test".to_string(),
+                        host: "github.com/testuser".to_string(),
+                    }),
+                },
+                ProjectEcosystemParams {
+                    path: "./frontend/".to_string(),
+                    ecosystem_params: EcosystemParams::Npm(NpmParams {
+                        name: "test".to_string(),
+                        scope: None,
+                        typescript: true,
+                    }),
+                },
+            ],
+            source_params: SourceParams {
+                parent_path: "test".to_string(),
+            },
+            facets: None,
+            license: SupportedLicense::Apache2,
+            code_review: CodeReviewParams::default(),
+            guac_forwarding: None,
+            default_branch: "main".to_string(),
+        };
+
+        let local_project_service = LocalProjectService {
+            repo_service: MockRepoService,
+            ecosystem_service: MockEcosystemService,
+            source_service: MockSourceService,
+            facet_service: MockFacetService,
+        };
+
+        let initialized_project = local_project_service.initialize(project_params).await.unwrap();
+
+        let manifests: Vec<String> = initialized_project
+            .ecosystems
+            .iter()
+            .map(InitializedProjectEcosystem::dependency_manifest)
+            .collect();
+        assert_eq!(manifests, vec!["backend/go.mod", "frontend/package.json"]);
+    }
+
+    #[test]
+    fn test_deserialize_project_with_single_ecosystem() {
+        let project: InitializedProject = serde_json::from_str(
+            r#"{
+                "repo": {"Github": {"name": "test", "organization": {"User": "testuser"}}},
+                "ecosystem": {"Go": {"name": "test", "host": "github.com"}},
+                "source": {"path": "test/test"},
+                "facets": []
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(project.ecosystems.len(), 1);
+        assert_eq!(project.ecosystems[0].path, "./");
+        assert_eq!(project.ecosystems[0].dependency_manifest(), "go.mod");
+
+        let params: ProjectParams = serde_json::from_str(
+            r#"{
+                "name": "test",
+                "repo_params": {"Github": {"name": "test", "description": "foobar", "organization": {"User": "testuser"}}},
+                "ecosystem_params": {"Go": {"name": "test", "host": "github.com"}},
+                "source_params": {"parent_path": "test"}
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(params.ecosystems.len(), 1);
+        assert_eq!(params.ecosystems[0].directory(), "");
+    }
+
+    #[test]
+    fn test_deserialize_project_rejects_invalid_ecosystem_paths() {
+        let project_params = |ecosystems: &str| {
+            serde_json::from_str::<ProjectParams>(&format!(
+                r#"{{
+                    "name": "test",
+                    "repo_params": {{"Github": {{"name": "test", "description": "foobar", "organization": {{"User": "testuser"}}}}}},
+                    "ecosystems": {ecosystems},
+                    "source_params": {{"parent_path": "test"}}
+                }}"#
+            ))
+        };
+        let go = r#"{"Go": {"name": "test", "host": "github.com"}}"#;
+
+        assert!(project_params(&format!(r#"[{{"path": "backend", "ecosystem_params": {go}}}]"#)).is_ok());
+        assert!(project_params("[]").is_err());
+        assert!(project_params(&format!(r#"[{{"path": "/etc", "ecosystem_params": {go}}}]"#)).is_err());
+        assert!(project_params(&format!(r#"[{{"path": "backend/../..", "ecosystem_params": {go}}}]"#)).is_err());
+        assert!(project_params(&format!(
+            r#"[{{"path": "backend", "ecosystem_params": {go}}}, {{"path": "./backend/", "ecosystem_params": {go}}}]"#
+        ))
+        .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_initialize_project_rejects_ecosystem_outside_of_source() {
+        let project_params = ProjectParams {
+            name: "test".to_string(),
+            repo_params: RepoParams::Github(GithubRepoParams {
+                name: "test".to_string(),
+                description: "foobar".to_string(),
+                organization: GithubUser::User("testuser".to_string()),
+                visibility: RepoVisibility::Public,
+            }),
+            ecosystems: vec![ProjectEcosystemParams {
+                path: "../outside".to_string(),
+                ecosystem_params: EcosystemParams::Go(GoParams {
+                    name: "test".to_string(),
+                    host: "github.com/testuser".to_string(),
+                }),
+            }],
+            source_params: SourceParams {
+                parent_path: "test".to_string(),
+            },
+            facets: None,
+            license: SupportedLicense::Apache2,
+            code_review: CodeReviewParams::default(),
+            guac_forwarding: None,
+            default_branch: "main".to_string(),
+        };
+
+        let local_project_service = LocalProjectService {
+            repo_service: MockRepoService,
+            ecosystem_service: MockEcosystemService,
+            source_service: MockSourceService,
+            facet_service: MockFacetService,
+        };
+
+        assert!(local_project_service.initialize(project_params).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_initialize_local_project() {
+        let temp_dir = TempDir::new("test").unwrap();
+        let path = temp_dir.path().to_str().unwrap();
+        let project_params = ProjectParams {
+            name: "test".to_string(),
+            repo_params: RepoParams::Local(LocalRepoParams {
+                name: "test".to_string(),
+                parent_path: format!("{path}/remotes"),
+            }),
+            ecosystems: vec![EcosystemParams::Go(GoParams {
+                name: "test".to_string(),
+                host: "localhost".to_string(),
+            }).into()],
+            source_params: SourceParams {
+                parent_path: path.to_string(),
+            },
+            facets: None,
+            license: SupportedLicense::Apache2,
+            code_review: CodeReviewParams::default(),
+            guac_forwarding: None,
+            default_branch: "main".to_string(),
+        };
+
+        // Everything but the ecosystem, which needs the Go toolchain, runs for real against the bare repo.
+        let local_project_service = LocalProjectService {
+            repo_service: LocalRepoService::default(),
+            ecosystem_service: MockEcosystemService,
+            source_service: LocalSourceService {
+                author: Some(GitAuthor {
+                    name: "test".to_string(),
+                    email: "test@example.com".to_string(),
+                }),
+                branch: None,
+                forge_hosts: ForgeHosts::default(),
+            },
+            facet_service: LocalFacetService {},
+        };
+
+        let initialized_project = local_project_service.initialize(project_params).await.unwrap();
+
+        assert_eq!(
+            initialized_project.repo.full_url(),
+            format!("file://{path}/remotes/test.git")
+        );
+        assert_eq!(initialized_project.source.path, format!("{path}/test"));
+        assert!(temp_dir.path().join("test/README.md").exists());
+        assert!(temp_dir.path().join("test/SECURITY.md").exists());
+        let pushed_readme = LocalRepoService::default()
+            .fetch_file_content(&initialized_project.repo, "README.md")
+            .await
+            .unwrap();
+        assert_eq!(
+            pushed_readme,
+            std::fs::read_to_string(temp_dir.path().join("test/README.md")).unwrap()
+        );
+        for facet in &initialized_project.facets {
+            if let InitializedFacet::APIBundle(api_bundle_facet) = facet {
+                assert!(api_bundle_facet.apis.is_empty());
+            }
+        }
+        // The project's state is pushed along with its source, including the results of the API facets.
+        let pushed_state = LocalRepoService::default()
+            .fetch_file_content(&initialized_project.repo, IN_REPO_STATE_FILE)
+            .await
+            .unwrap();
+        assert_eq!(pushed_state, serde_json::to_string_pretty(&initialized_project).unwrap());
+        // The workflows rewritten by pinning their dependencies still match what the other facets recorded.
+        let pinned_files = initialized_project
+            .facets
+            .iter()
+            .find_map(|facet| match facet {
+                InitializedFacet::SourceBundle(f) if f.facet_type == SupportedFacetType::PinnedDependencies => {
+                    Some(&f.source_files)
+                }
+                _ => None,
+            })
+            .unwrap();
+        assert!(pinned_files.iter().any(|file| file.name == "scorecard.yml"));
+        assert!(pinned_files.iter().any(|file| file.name == "Dockerfile.goreleaser"));
+        let drift = local_project_service
+            .drift(&initialized_project, &initialized_project.source)
+            .unwrap();
+        assert!(drift.is_empty());
+    }
+
+    #[test]
+    fn test_drift() {
+        let temp_dir = TempDir::new("test").unwrap();
+        let source = InitializedSource {
+            path: temp_dir.path().to_str().unwrap().to_string(),
+        };
+        let source_service = LocalSourceService::default();
+        let source_files = ["README.md", "LICENSE", "SECURITY.md"]
+            .iter()
+            .map(|name| {
+                let content = format!("{name} content");
+                source_service
+                    .write_file(source.clone(), "./", (*name).to_string(), &content)
+                    .unwrap();
+                SourceFileContent {
+                    name: (*name).to_string(),
+                    path: "./".to_string(),
+                    hash: Some(content_hash(&content)),
+                    content,
+                }
+            })
+            .collect();
+        let project = InitializedProject {
+            repo: InitializedRepo::Github(InitializedGithubRepo {
+                name: "test".to_string(),
+                organization: GithubUser::User("testuser".to_string()),
+            }),
+            ecosystems: vec![InitializedEcosystem::Go(InitializedGo {
+                name: "test".to_string(),
+                host: "github.com".to_string(),
+                major_version: None,
+            }).into()],
+            source: source.clone(),
+            facets: vec![InitializedFacet::SourceBundle(SourceBundleFacet {
+                source_files,
+                facet_type: SupportedFacetType::Readme,
+            })],
+            missing_facets: Vec::new(),
+            guac_collector_endpoint: None,
+        };
+
+        let local_project_service = LocalProjectService {
+            repo_service: MockRepoService,
+            ecosystem_service: MockEcosystemService,
+            source_service,
+            facet_service: MockFacetService,
+        };
+
+        let drift = local_project_service.drift(&project, &source).unwrap();
+        assert!(drift.is_empty());
+
+        std::fs::write(temp_dir.path().join("LICENSE"), "changed").unwrap();
+        std::fs::remove_file(temp_dir.path().join("SECURITY.md")).unwrap();
+        let drift = local_project_service.drift(&project, &source).unwrap();
+        assert_eq!(drift.len(), 2);
+        assert_eq!(drift[0].name, "LICENSE");
+        assert_eq!(drift[0].status, SourceFileDriftStatus::Changed);
+        assert_eq!(drift[1].name, "SECURITY.md");
+        assert_eq!(drift[1].status, SourceFileDriftStatus::Deleted);
+    }
+
+    #[tokio::test]
+    async fn test_import_project() {
+        let temp_dir = TempDir::new("test").unwrap();
+        let source = InitializedSource {
+            path: temp_dir.path().join("test").to_str().unwrap().to_string(),
+        };
+        std::fs::create_dir(&source.path).unwrap();
+        let source_service = LocalSourceService::default();
+        source_service
+            .write_file(source.clone(), "./", "README.md".to_string(), "# test")
+            .unwrap();
+        source_service
+            .write_file(source.clone(), "./.github", "SECURITY.md".to_string(), "Report it")
+            .unwrap();
+        for name in ["releases-npm.yml", "releases-backend.yml"] {
+            source_service
+                .write_file(source.clone(), "./.github/workflows", name.to_string(), "on: push")
+                .unwrap();
+        }
+
+        let local_project_service = LocalProjectService {
+            repo_service: MockRepoService,
+            ecosystem_service: MockEcosystemService,
+            source_service,
+            facet_service: MockFacetService,
+        };
+        let import_params = ProjectImportParams {
+            repo_url: "https://github.com/testuser/test".to_string(),
+            ecosystems: vec![EcosystemParams::Go(GoParams {
+                name: "test".to_string(),
+                host: "github.com/testuser".to_string(),
+            }).into()],
+            source_params: SourceParams {
+                parent_path: temp_dir.path().to_str().unwrap().to_string(),
+            },
+            forge_hosts: ForgeHosts::default(),
+        };
+
+        let project = local_project_service.import(import_params).await.unwrap();
+        assert_eq!(project.source.path, source.path);
+        assert_eq!(project.repo.full_url(), "https://github.com/testuser/test");
+        let facet_types: Vec<SupportedFacetType> = project
+            .facets
+            .iter()
+            .map(|facet| match facet {
+                InitializedFacet::SourceBundle(f) => f.facet_type.clone(),
+                _ => panic!("Only source bundle facets should be detected"),
+            })
+            .collect();
+        assert_eq!(
+            facet_types,
+            vec![SupportedFacetType::Readme, SupportedFacetType::SecurityPolicy, SupportedFacetType::SLSABuild]
+        );
+        let release_workflows = project
+            .facets
+            .iter()
+            .find_map(|facet| match facet {
+                InitializedFacet::SourceBundle(f) if f.facet_type == SupportedFacetType::SLSABuild => {
+                    Some(f.source_files.iter().map(|file| file.name.as_str()).collect::<Vec<_>>())
+                }
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(release_workflows, vec!["releases-backend.yml", "releases-npm.yml"]);
+        assert!(project.missing_facets.contains(&SupportedFacetType::License));
+        assert!(project.missing_facets.contains(&SupportedFacetType::BranchProtection));
+        assert!(!project.missing_facets.contains(&SupportedFacetType::Readme));
+        assert!(!project.missing_facets.contains(&SupportedFacetType::DefaultSourceCode));
+    }
+
+    #[tokio::test]
+    async fn test_import_project_detects_ecosystem() {
+        let temp_dir = TempDir::new("test").unwrap();
+        let source = InitializedSource {
+            path: temp_dir.path().join("test").to_str().unwrap().to_string(),
+        };
+        std::fs::create_dir(&source.path).unwrap();
+        let source_service = LocalSourceService::default();
+        source_service
+            .write_file(source.clone(), "./", "go.mod".to_string(), "module github.com/testuser/test\n\ngo 1.21\n")
+            .unwrap();
+
+        let local_project_service = LocalProjectService {
+            repo_service: MockRepoService,
+            ecosystem_service: MockEcosystemService,
+            source_service,
+            facet_service: MockFacetService,
+        };
+        let import_params = ProjectImportParams {
+            repo_url: "https://github.com/testuser/test".to_string(),
+            ecosystems: Vec::new(),
+            source_params: SourceParams {
+                parent_path: temp_dir.path().to_str().unwrap().to_string(),
+            },
+            forge_hosts: ForgeHosts::default(),
+        };
+
+        let project = local_project_service.import(import_params).await.unwrap();
+        assert_eq!(project.ecosystems.len(), 1);
+        assert_eq!(project.ecosystems[0].dependency_manifest(), "go.mod");
+        match &project.ecosystems[0].ecosystem {
+            InitializedEcosystem::Go(g) => assert_eq!(g.module(), "github.com/testuser/test"),
+            _ => panic!("The Go ecosystem should be detected"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_import_project_from_configured_gitea_host() {
+        let temp_dir = TempDir::new("test").unwrap();
+        let source = InitializedSource {
+            path: temp_dir.path().join("test").to_str().unwrap().to_string(),
+        };
+        std::fs::create_dir(&source.path).unwrap();
+        let source_service = LocalSourceService::default();
+        source_service
+            .write_file(source.clone(), "./", "go.mod".to_string(), "module localhost/testuser/test\n\ngo 1.21\n")
+            .unwrap();
+
+        let local_project_service = LocalProjectService {
+            repo_service: MockRepoService,
+            ecosystem_service: MockEcosystemService,
+            source_service,
+            facet_service: MockFacetService,
+        };
+        let import_params = ProjectImportParams {
+            repo_url: "http://localhost:3000/testuser/test".to_string(),
+            ecosystems: Vec::new(),
+            source_params: SourceParams {
+                parent_path: temp_dir.path().to_str().unwrap().to_string(),
+            },
+            forge_hosts: ForgeHosts {
+                gitea: vec!["localhost:3000".to_string()],
+                ..ForgeHosts::default()
+            },
+        };
+
+        let project = local_project_service.import(import_params).await.unwrap();
+        match &project.repo {
+            InitializedRepo::Gitea(g) => {
+                assert_eq!(g.host_url, "http://localhost:3000");
+                assert_eq!(g.owner, "testuser");
+                assert_eq!(g.name, "test");
+            }
+            _ => panic!("A repo on a configured Gitea host should be recognized as a Gitea repo"),
+        }
+        assert_eq!(project.repo.full_url(), "http://localhost:3000/testuser/test");
+
+        let unconfigured_params = ProjectImportParams {
+            repo_url: "http://localhost:3000/testuser/test".to_string(),
+            ecosystems: Vec::new(),
+            source_params: SourceParams {
+                parent_path: temp_dir.path().to_str().unwrap().to_string(),
+            },
+            forge_hosts: ForgeHosts::default(),
+        };
+        assert!(local_project_service.import(unconfigured_params).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_import_project_from_configured_gitlab_host() {
+        let temp_dir = TempDir::new("test").unwrap();
+        let source = InitializedSource {
+            path: temp_dir.path().join("test").to_str().unwrap().to_string(),
+        };
+        std::fs::create_dir(&source.path).unwrap();
+        let source_service = LocalSourceService::default();
+        source_service
+            .write_file(source.clone(), "./", "go.mod".to_string(), "module git.example.com/testgroup/test\n\ngo 1.21\n")
+            .unwrap();
+
+        let local_project_service = LocalProjectService {
+            repo_service: MockRepoService,
+            ecosystem_service: MockEcosystemService,
+            source_service,
+            facet_service: MockFacetService,
+        };
+        let import_params = ProjectImportParams {
+            repo_url: "https://git.example.com/testgroup/security/test".to_string(),
+            ecosystems: Vec::new(),
+            source_params: SourceParams {
+                parent_path: temp_dir.path().to_str().unwrap().to_string(),
+            },
+            forge_hosts: ForgeHosts {
+                gitlab: vec!["https://git.example.com/".to_string()],
+                ..ForgeHosts::default()
+            },
+        };
+
+        let project = local_project_service.import(import_params).await.unwrap();
+        match &project.repo {
+            InitializedRepo::Gitlab(g) => {
+                assert_eq!(g.host_url, "https://git.example.com");
+                assert_eq!(g.namespace, "testgroup/security");
+                assert_eq!(g.name, "test");
+            }
+            _ => panic!("A repo on a configured Gitlab host should be recognized as a Gitlab repo"),
+        }
     }
 }
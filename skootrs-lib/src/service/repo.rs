@@ -16,12 +16,13 @@
 
 #![allow(clippy::module_name_repetitions)]
 
-use std::{error::Error, process::Command, str::FromStr, sync::Arc};
+use std::{error::Error, path::Path, str::FromStr, sync::Arc};
 
 use chrono::Utc;
 use tracing::{info, debug};
 
-use skootrs_model::{skootrs::{GithubRepoParams, GithubUser, InitializedGithubRepo, InitializedRepo, InitializedSource, RepoParams, SkootError}, cd_events::repo_created::{RepositoryCreatedEvent, RepositoryCreatedEventContext, RepositoryCreatedEventContextId, RepositoryCreatedEventContextVersion, RepositoryCreatedEventSubject, RepositoryCreatedEventSubjectContent, RepositoryCreatedEventSubjectContentName, RepositoryCreatedEventSubjectContentUrl, RepositoryCreatedEventSubjectId}};
+use super::source::{clone_repo, DEFAULT_BRANCH};
+use skootrs_model::{skootrs::{GiteaRepoParams, GithubRepoParams, GithubUser, GitlabRepoParams, InitializedGiteaRepo, InitializedGithubRepo, InitializedGitlabRepo, InitializedLocalRepo, InitializedRepo, InitializedSource, LocalRepoParams, RepoParams, RepoVisibility, SkootError}, cd_events::repo_created::{RepositoryCreatedEvent, RepositoryCreatedEventContext, RepositoryCreatedEventContextId, RepositoryCreatedEventContextVersion, RepositoryCreatedEventSubject, RepositoryCreatedEventSubjectContent, RepositoryCreatedEventSubjectContentName, RepositoryCreatedEventSubjectContentUrl, RepositoryCreatedEventSubjectId}};
 
 /// The `RepoService` trait provides an interface for initializing and managing a project's source code
 /// repository. This repo is usually something like Github or Gitlab.
@@ -40,37 +41,109 @@ pub trait RepoService {
     ///
     /// Returns an error if the source code repository can't be cloned to the local machine.
     fn clone_local(&self, initialized_repo: InitializedRepo, path: String) -> Result<InitializedSource, SkootError>;
+
+    /// Fetches the content of a file from a project's remote repository without needing a local clone.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be fetched from the remote repository.
+    fn fetch_file_content<P: AsRef<Path> + Send>(&self, initialized_repo: &InitializedRepo, path: P) -> impl std::future::Future<Output = Result<String, SkootError>> + Send;
 }
 
 /// The `LocalRepoService` struct provides an implementation of the `RepoService` trait for initializing
 /// and managing a project's source code repository from the local machine. This doesn't mean the repo is
 /// local, but that the operations like API calls are run from the local machine.
-#[derive(Debug)]
-pub struct LocalRepoService {}
+#[derive(Debug, Default, Clone)]
+pub struct LocalRepoService {
+    /// The branch files are fetched from and local repos are created with. If it isn't set, `DEFAULT_BRANCH`
+    /// is used.
+    pub branch: Option<String>,
+}
 
-impl RepoService for LocalRepoService {
-    async fn initialize(&self, params: RepoParams) -> Result<InitializedRepo, SkootError> {
-        // TODO: The octocrab initialization should be done in a better place and be parameterized
+impl LocalRepoService {
+    fn branch(&self) -> &str {
+        self.branch.as_deref().unwrap_or(DEFAULT_BRANCH)
+    }
+
+    // TODO: The octocrab initialization should be done in a better place and be parameterized
+    fn init_github_client() -> Result<Arc<octocrab::Octocrab>, SkootError> {
         let o: octocrab::Octocrab = octocrab::Octocrab::builder()
             .personal_token(
                     std::env::var("GITHUB_TOKEN").expect("GITHUB_TOKEN env var must be populated"),
             )
             .build()?;
         octocrab::initialise(o);
+        Ok(octocrab::instance())
+    }
+}
+
+impl RepoService for LocalRepoService {
+    async fn initialize(&self, params: RepoParams) -> Result<InitializedRepo, SkootError> {
         match params {
             RepoParams::Github(g) => {
                 let github_repo_handler = GithubRepoHandler {
-                    client: octocrab::instance(),
+                    client: Self::init_github_client()?,
                 };
                 Ok(InitializedRepo::Github(github_repo_handler.create(g).await?))
             },
+            RepoParams::Gitlab(g) => {
+                let gitlab_repo_handler = GitlabRepoHandler {
+                    client: ForgeClient::gitlab(&g.host_url())?,
+                };
+                Ok(InitializedRepo::Gitlab(gitlab_repo_handler.create(g).await?))
+            },
+            RepoParams::Gitea(g) => {
+                let gitea_repo_handler = GiteaRepoHandler {
+                    client: ForgeClient::gitea(&g.host_url())?,
+                };
+                Ok(InitializedRepo::Gitea(gitea_repo_handler.create(g).await?))
+            },
+            RepoParams::Local(l) => {
+                Ok(InitializedRepo::Local(LocalBareRepoHandler::create(&l, self.branch())?))
+            },
         }
     }
 
     fn clone_local(&self, initialized_repo: InitializedRepo, path: String) -> Result<InitializedSource, Box<dyn Error + Send + Sync>> {
+        match initialized_repo {
+            InitializedRepo::Github(ref g) => {
+                GithubRepoHandler::clone_local(g, &path, &initialized_repo)
+            },
+            InitializedRepo::Gitlab(ref g) => {
+                GitlabRepoHandler::clone_local(g, &path, &initialized_repo)
+            },
+            InitializedRepo::Gitea(ref g) => {
+                GiteaRepoHandler::clone_local(g, &path, &initialized_repo)
+            },
+            InitializedRepo::Local(ref l) => {
+                LocalBareRepoHandler::clone_local(l, &path, &initialized_repo)
+            },
+        }
+    }
+
+    async fn fetch_file_content<P: AsRef<Path> + Send>(&self, initialized_repo: &InitializedRepo, path: P) -> Result<String, SkootError> {
+        let path = path.as_ref().to_string_lossy().to_string();
         match initialized_repo {
             InitializedRepo::Github(g) => {
-                GithubRepoHandler::clone_local(&g, &path)
+                let github_repo_handler = GithubRepoHandler {
+                    client: Self::init_github_client()?,
+                };
+                github_repo_handler.fetch_file_content(g, &path, self.branch()).await
+            },
+            InitializedRepo::Gitlab(g) => {
+                let gitlab_repo_handler = GitlabRepoHandler {
+                    client: ForgeClient::gitlab(&g.host_url())?,
+                };
+                gitlab_repo_handler.fetch_file_content(g, &path, self.branch()).await
+            },
+            InitializedRepo::Gitea(g) => {
+                let gitea_repo_handler = GiteaRepoHandler {
+                    client: ForgeClient::gitea(&g.host_url())?,
+                };
+                gitea_repo_handler.fetch_file_content(g, &path, self.branch()).await
+            },
+            InitializedRepo::Local(l) => {
+                LocalBareRepoHandler::fetch_file_content(l, &path, self.branch())
             },
         }
     }
@@ -87,7 +160,7 @@ impl GithubRepoHandler {
         let new_repo = NewGithubRepoParams {
             name: github_params.name.clone(),
             description: github_params.description.clone(),
-            private: false,
+            private: github_params.visibility == RepoVisibility::Private,
             has_issues: true,
             has_projects: true,
             has_wiki: true,
@@ -103,28 +176,12 @@ impl GithubRepoHandler {
         };
 
         info!("Github Repo Created: {}", github_params.name);
-        let rce = RepositoryCreatedEvent {
-             context: RepositoryCreatedEventContext {
-                id: RepositoryCreatedEventContextId::from_str(format!("{}/{}", github_params.organization.get_name(), github_params.name.clone()).as_str())?,
-                source: "skootrs.github.creator".into(),
-                timestamp: Utc::now(),
-                type_: skootrs_model::cd_events::repo_created::RepositoryCreatedEventContextType::DevCdeventsRepositoryCreated011,
-                version: RepositoryCreatedEventContextVersion::from_str("0.3.0")?,
-            }, 
-             custom_data: None,
-             custom_data_content_type: None,
-             subject: RepositoryCreatedEventSubject {
-                content: RepositoryCreatedEventSubjectContent{
-                    name: RepositoryCreatedEventSubjectContentName::from_str(github_params.name.as_str())?,
-                    owner: Some(github_params.organization.get_name()),
-                    url: RepositoryCreatedEventSubjectContentUrl::from_str(github_params.full_url().as_str())?,
-                    view_url: Some(github_params.full_url()),
-                },
-                id: RepositoryCreatedEventSubjectId::from_str(format!("{}/{}", github_params.organization.get_name(), github_params.name.clone()).as_str())?,
-                source: Some("skootrs.github.creator".into()),
-                type_: skootrs_model::cd_events::repo_created::RepositoryCreatedEventSubjectType::Repository,
-            } 
-        };
+        let rce = repo_created_event(
+            &github_params.organization.get_name(),
+            &github_params.name,
+            &github_params.full_url(),
+            "skootrs.github.creator",
+        )?;
 
         // TODO: Turn this into an event
         info!("{}", serde_json::to_string(&rce)?);
@@ -135,19 +192,335 @@ impl GithubRepoHandler {
         })
     }
 
-    fn clone_local(initialized_github_repo: &InitializedGithubRepo, path: &str) -> Result<InitializedSource, SkootError> {
+    fn clone_local(initialized_github_repo: &InitializedGithubRepo, path: &str, initialized_repo: &InitializedRepo) -> Result<InitializedSource, SkootError> {
         debug!("Cloning {}", initialized_github_repo.full_url());
-        let clone_url = initialized_github_repo.full_url();
-        let _output = Command::new("git")
-            .arg("clone")
-            .arg(clone_url)
-            .current_dir(path)
-            .output()?;
+        clone_repo(&initialized_github_repo.full_url(), Path::new(path).join(&initialized_github_repo.name), initialized_repo)?;
 
         Ok(InitializedSource{
             path: format!("{}/{}", path, initialized_github_repo.name),
         })
     }
+
+    async fn fetch_file_content(&self, initialized_github_repo: &InitializedGithubRepo, path: &str, branch: &str) -> Result<String, SkootError> {
+        debug!("Fetching {path} from {}", initialized_github_repo.full_url());
+        let content_items = self.client
+            .repos(initialized_github_repo.organization.get_name(), &initialized_github_repo.name)
+            .get_content()
+            .path(path)
+            .r#ref(branch)
+            .send()
+            .await?;
+
+        content_items
+            .items
+            .first()
+            .and_then(octocrab::models::repos::Content::decoded_content)
+            .ok_or_else(|| SkootError::from(format!("Failed to get content of {path} from {}", initialized_github_repo.full_url())))
+    }
+}
+
+/// The `GitlabRepoHandler` struct represents a handler for initializing and managing Gitlab repos.
+#[derive(Debug)]
+struct GitlabRepoHandler {
+    client: ForgeClient,
+}
+
+impl GitlabRepoHandler {
+    async fn create(&self, gitlab_params: GitlabRepoParams) -> Result<InitializedGitlabRepo, SkootError> {
+        // Gitlab creates projects in a namespace by its ID so the group, subgroup, or user path needs
+        // to be looked up first.
+        let namespace = self
+            .client
+            .get(&format!("/namespaces/{}", urlencoding::encode(&gitlab_params.namespace)))
+            .await?;
+        let namespace_id = namespace["id"]
+            .as_u64()
+            .ok_or_else(|| SkootError::from(format!("Failed to get ID of Gitlab namespace {}", gitlab_params.namespace)))?;
+        let new_project = NewGitlabProjectParams {
+            name: gitlab_params.name.clone(),
+            path: gitlab_params.name.clone(),
+            namespace_id,
+            description: gitlab_params.description.clone(),
+            visibility: match gitlab_params.visibility {
+                RepoVisibility::Public => "public".to_string(),
+                RepoVisibility::Private => "private".to_string(),
+            },
+            issues_enabled: true,
+            wiki_enabled: true,
+        };
+
+        let _response = self.client.post("/projects", &new_project).await?;
+
+        info!("Gitlab Repo Created: {}", gitlab_params.name);
+        let rce = repo_created_event(
+            &gitlab_params.namespace,
+            &gitlab_params.name,
+            &gitlab_params.full_url(),
+            "skootrs.gitlab.creator",
+        )?;
+
+        // TODO: Turn this into an event
+        info!("{}", serde_json::to_string(&rce)?);
+
+        Ok(InitializedGitlabRepo {
+            name: gitlab_params.name.clone(),
+            namespace: gitlab_params.namespace.clone(),
+            host_url: gitlab_params.host_url(),
+        })
+    }
+
+    fn clone_local(initialized_gitlab_repo: &InitializedGitlabRepo, path: &str, initialized_repo: &InitializedRepo) -> Result<InitializedSource, SkootError> {
+        debug!("Cloning {}", initialized_gitlab_repo.full_url());
+        clone_repo(&initialized_gitlab_repo.full_url(), Path::new(path).join(&initialized_gitlab_repo.name), initialized_repo)?;
+
+        Ok(InitializedSource{
+            path: format!("{}/{}", path, initialized_gitlab_repo.name),
+        })
+    }
+
+    async fn fetch_file_content(&self, initialized_gitlab_repo: &InitializedGitlabRepo, path: &str, branch: &str) -> Result<String, SkootError> {
+        debug!("Fetching {path} from {}", initialized_gitlab_repo.full_url());
+        let endpoint = format!(
+            "{}/repository/files/{}/raw?ref={}",
+            ForgeClient::gitlab_project_endpoint(initialized_gitlab_repo),
+            urlencoding::encode(path),
+            urlencoding::encode(branch),
+        );
+        self.client.get_raw(&endpoint).await
+    }
+}
+
+/// The `GiteaRepoHandler` struct represents a handler for initializing and managing Gitea repos. Forgejo
+/// has the same API so it's handled here as well.
+#[derive(Debug)]
+struct GiteaRepoHandler {
+    client: ForgeClient,
+}
+
+impl GiteaRepoHandler {
+    async fn create(&self, gitea_params: GiteaRepoParams) -> Result<InitializedGiteaRepo, SkootError> {
+        let new_repo = NewGiteaRepoParams {
+            name: gitea_params.name.clone(),
+            description: gitea_params.description.clone(),
+            private: gitea_params.visibility == RepoVisibility::Private,
+        };
+
+        // Like Github, Gitea has different calls for creating a repo that belongs to the authenticated user or
+        // an organization the user has access to.
+        let user = self.client.get("/user").await?;
+        let endpoint = if user["login"].as_str() == Some(gitea_params.owner.as_str()) {
+            "/user/repos".to_string()
+        } else {
+            format!("/orgs/{}/repos", gitea_params.owner)
+        };
+        let _response = self.client.post(&endpoint, &new_repo).await?;
+
+        info!("Gitea Repo Created: {}", gitea_params.name);
+        let rce = repo_created_event(
+            &gitea_params.owner,
+            &gitea_params.name,
+            &gitea_params.full_url(),
+            "skootrs.gitea.creator",
+        )?;
+
+        // TODO: Turn this into an event
+        info!("{}", serde_json::to_string(&rce)?);
+
+        Ok(InitializedGiteaRepo {
+            name: gitea_params.name.clone(),
+            owner: gitea_params.owner.clone(),
+            host_url: gitea_params.host_url(),
+        })
+    }
+
+    fn clone_local(initialized_gitea_repo: &InitializedGiteaRepo, path: &str, initialized_repo: &InitializedRepo) -> Result<InitializedSource, SkootError> {
+        debug!("Cloning {}", initialized_gitea_repo.full_url());
+        clone_repo(&initialized_gitea_repo.full_url(), Path::new(path).join(&initialized_gitea_repo.name), initialized_repo)?;
+
+        Ok(InitializedSource{
+            path: format!("{}/{}", path, initialized_gitea_repo.name),
+        })
+    }
+
+    async fn fetch_file_content(&self, initialized_gitea_repo: &InitializedGiteaRepo, path: &str, branch: &str) -> Result<String, SkootError> {
+        debug!("Fetching {path} from {}", initialized_gitea_repo.full_url());
+        let endpoint = format!(
+            "/repos/{}/{}/raw/{}?ref={}",
+            initialized_gitea_repo.owner,
+            initialized_gitea_repo.name,
+            urlencoding::encode(path),
+            urlencoding::encode(branch),
+        );
+        self.client.get_raw(&endpoint).await
+    }
+}
+
+/// The `LocalBareRepoHandler` struct represents a handler for initializing and managing bare repos on the
+/// local filesystem. These take the place of a hosted remote so nothing here needs network access or tokens.
+#[derive(Debug)]
+struct LocalBareRepoHandler {}
+
+impl LocalBareRepoHandler {
+    fn create(local_params: &LocalRepoParams, branch: &str) -> Result<InitializedLocalRepo, SkootError> {
+        // The repo is recorded by its absolute path, since a relative one doesn't make a valid `file://` URL.
+        std::fs::create_dir_all(&local_params.parent_path)?;
+        let local_params = LocalRepoParams {
+            name: local_params.name.clone(),
+            parent_path: std::fs::canonicalize(&local_params.parent_path)?
+                .to_string_lossy()
+                .to_string(),
+        };
+        let path = local_params.path();
+        git2::Repository::init_opts(
+            &path,
+            git2::RepositoryInitOptions::new()
+                .bare(true)
+                .initial_head(branch),
+        )?;
+
+        info!("Local Repo Created: {}", path);
+        let initialized_local_repo = InitializedLocalRepo {
+            name: local_params.name,
+            path,
+        };
+        let rce = repo_created_event(
+            "local",
+            &initialized_local_repo.name,
+            &initialized_local_repo.full_url(),
+            "skootrs.local.creator",
+        )?;
+
+        // TODO: Turn this into an event
+        info!("{}", serde_json::to_string(&rce)?);
+
+        Ok(initialized_local_repo)
+    }
+
+    fn clone_local(initialized_local_repo: &InitializedLocalRepo, path: &str, initialized_repo: &InitializedRepo) -> Result<InitializedSource, SkootError> {
+        debug!("Cloning {}", initialized_local_repo.full_url());
+        clone_repo(&initialized_local_repo.path, Path::new(path).join(&initialized_local_repo.name), initialized_repo)?;
+
+        Ok(InitializedSource{
+            path: format!("{}/{}", path, initialized_local_repo.name),
+        })
+    }
+
+    fn fetch_file_content(initialized_local_repo: &InitializedLocalRepo, path: &str, branch: &str) -> Result<String, SkootError> {
+        debug!("Fetching {path} from {}", initialized_local_repo.full_url());
+        let repo = git2::Repository::open_bare(&initialized_local_repo.path)?;
+        let object = repo.revparse_single(&format!("{branch}:{}", path.trim_start_matches("./")))?;
+        let blob = object
+            .as_blob()
+            .ok_or_else(|| SkootError::from(format!("{path} in {} is not a file", initialized_local_repo.full_url())))?;
+
+        Ok(String::from_utf8(blob.content().to_vec())?)
+    }
+}
+
+/// The `ForgeClient` struct is a minimal client for the REST APIs of forges like Gitlab and Gitea. These can be
+/// self-hosted so unlike the Github client there isn't a single global instance.
+#[derive(Debug, Clone)]
+pub(crate) struct ForgeClient {
+    client: reqwest::Client,
+    api_url: String,
+}
+
+impl ForgeClient {
+    // TODO: Like the Github client, the tokens should be parameterized instead of coming from the environment.
+    /// Creates a client for the Gitlab instance at `host_url` authenticated with the `GITLAB_TOKEN` env var.
+    pub(crate) fn gitlab(host_url: &str) -> Result<Self, SkootError> {
+        let token = std::env::var("GITLAB_TOKEN")
+            .map_err(|_| SkootError::from("GITLAB_TOKEN env var must be populated"))?;
+        Self::new(
+            format!("{}/api/v4", host_url.trim_end_matches('/')),
+            "PRIVATE-TOKEN",
+            &token,
+        )
+    }
+
+    /// Creates a client for the Gitea instance at `host_url` authenticated with the `GITEA_TOKEN` env var.
+    pub(crate) fn gitea(host_url: &str) -> Result<Self, SkootError> {
+        let token = std::env::var("GITEA_TOKEN")
+            .map_err(|_| SkootError::from("GITEA_TOKEN env var must be populated"))?;
+        Self::new(
+            format!("{}/api/v1", host_url.trim_end_matches('/')),
+            "Authorization",
+            &format!("token {token}"),
+        )
+    }
+
+    fn new(api_url: String, auth_header: &'static str, auth_value: &str) -> Result<Self, SkootError> {
+        let mut auth_header_value = reqwest::header::HeaderValue::from_str(auth_value)?;
+        auth_header_value.set_sensitive(true);
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(auth_header, auth_header_value);
+        let client = reqwest::Client::builder().default_headers(headers).build()?;
+
+        Ok(Self { client, api_url })
+    }
+
+    /// Returns the API endpoint for a project. Gitlab identifies projects by their URL encoded full path.
+    pub(crate) fn gitlab_project_endpoint(repo: &InitializedGitlabRepo) -> String {
+        format!("/projects/{}", urlencoding::encode(&repo.path_with_namespace()))
+    }
+
+    pub(crate) async fn get(&self, endpoint: &str) -> Result<serde_json::Value, SkootError> {
+        let response = self.client.get(format!("{}{endpoint}", self.api_url)).send().await?;
+        Ok(response.error_for_status()?.json().await?)
+    }
+
+    pub(crate) async fn get_raw(&self, endpoint: &str) -> Result<String, SkootError> {
+        let response = self.client.get(format!("{}{endpoint}", self.api_url)).send().await?;
+        Ok(response.error_for_status()?.text().await?)
+    }
+
+    pub(crate) async fn post<B: serde::Serialize + Sync>(&self, endpoint: &str, body: &B) -> Result<serde_json::Value, SkootError> {
+        let response = self.client.post(format!("{}{endpoint}", self.api_url)).json(body).send().await?;
+        Ok(response.error_for_status()?.json().await?)
+    }
+
+    pub(crate) async fn put<B: serde::Serialize + Sync>(&self, endpoint: &str, body: &B) -> Result<serde_json::Value, SkootError> {
+        let response = self.client.put(format!("{}{endpoint}", self.api_url)).json(body).send().await?;
+        Ok(response.error_for_status()?.json().await?)
+    }
+
+    pub(crate) async fn patch<B: serde::Serialize + Sync>(&self, endpoint: &str, body: &B) -> Result<serde_json::Value, SkootError> {
+        let response = self.client.patch(format!("{}{endpoint}", self.api_url)).json(body).send().await?;
+        Ok(response.error_for_status()?.json().await?)
+    }
+
+    /// Sends a DELETE to the endpoint. This returns the status instead of an error for unsuccessful responses
+    /// since deleting something that doesn't exist is usually fine.
+    pub(crate) async fn delete(&self, endpoint: &str) -> Result<reqwest::StatusCode, SkootError> {
+        let response = self.client.delete(format!("{}{endpoint}", self.api_url)).send().await?;
+        Ok(response.status())
+    }
+}
+
+/// Returns the CD event for a newly created repo.
+fn repo_created_event(owner: &str, name: &str, url: &str, source: &str) -> Result<RepositoryCreatedEvent, SkootError> {
+    Ok(RepositoryCreatedEvent {
+        context: RepositoryCreatedEventContext {
+            id: RepositoryCreatedEventContextId::from_str(format!("{owner}/{name}").as_str())?,
+            source: source.into(),
+            timestamp: Utc::now(),
+            type_: skootrs_model::cd_events::repo_created::RepositoryCreatedEventContextType::DevCdeventsRepositoryCreated011,
+            version: RepositoryCreatedEventContextVersion::from_str("0.3.0")?,
+        },
+        custom_data: None,
+        custom_data_content_type: None,
+        subject: RepositoryCreatedEventSubject {
+            content: RepositoryCreatedEventSubjectContent{
+                name: RepositoryCreatedEventSubjectContentName::from_str(name)?,
+                owner: Some(owner.to_string()),
+                url: RepositoryCreatedEventSubjectContentUrl::from_str(url)?,
+                view_url: Some(url.to_string()),
+            },
+            id: RepositoryCreatedEventSubjectId::from_str(format!("{owner}/{name}").as_str())?,
+            source: Some(source.into()),
+            type_: skootrs_model::cd_events::repo_created::RepositoryCreatedEventSubjectType::Repository,
+        }
+    })
 }
 
 /// This is needed to easily send over Github new repo parameters to the post.
@@ -162,10 +535,33 @@ struct NewGithubRepoParams {
     has_wiki: bool,
 }
 
+/// This is needed to easily send over Gitea new repo parameters to the post.
+#[derive(serde::Serialize)]
+struct NewGiteaRepoParams {
+    name: String,
+    description: String,
+    private: bool,
+}
+
+/// This is needed to easily send over Gitlab new project parameters to the post.
+#[derive(serde::Serialize)]
+struct NewGitlabProjectParams {
+    name: String,
+    path: String,
+    namespace_id: u64,
+    description: String,
+    visibility: String,
+    issues_enabled: bool,
+    wiki_enabled: bool,
+}
+
 #[cfg(test)]
 mod tests {
+    use skootrs_model::skootrs::{ForgeHosts, GitAuthor};
     use tempdir::TempDir;
 
+    use crate::service::source::{LocalSourceService, SourceService};
+
     use super::*;
 
     // TODO: Mock out, or create test to create a repo/delete a repo
@@ -179,7 +575,8 @@ mod tests {
 
         let temp_dir = TempDir::new("test").unwrap();
         let path = temp_dir.path().to_str().unwrap();
-        let result = GithubRepoHandler::clone_local(&initialized_github_repo, path);
+        let initialized_repo = InitializedRepo::Github(initialized_github_repo.clone());
+        let result = GithubRepoHandler::clone_local(&initialized_github_repo, path, &initialized_repo);
         assert!(result.is_ok());
 
         let initialized_source = result.unwrap();
@@ -188,4 +585,82 @@ mod tests {
             format!("{}/{}", path, initialized_github_repo.name)
         );
     }
+
+    #[test]
+    fn test_gitlab_project_endpoint() {
+        let initialized_gitlab_repo = InitializedGitlabRepo {
+            name: "skootrs".to_string(),
+            namespace: "kusaridev/security".to_string(),
+            host_url: "https://gitlab.example.com".to_string(),
+        };
+
+        assert_eq!(
+            ForgeClient::gitlab_project_endpoint(&initialized_gitlab_repo),
+            "/projects/kusaridev%2Fsecurity%2Fskootrs"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_local_bare_repo() {
+        let temp_dir = TempDir::new("test").unwrap();
+        let path = temp_dir.path().to_str().unwrap();
+        let repo_service = LocalRepoService::default();
+
+        let initialized_repo = repo_service
+            .initialize(RepoParams::Local(LocalRepoParams {
+                name: "skootrs".to_string(),
+                parent_path: format!("{path}/remotes"),
+            }))
+            .await
+            .unwrap();
+        assert_eq!(initialized_repo.full_url(), format!("file://{path}/remotes/skootrs.git"));
+
+        let initialized_source = repo_service
+            .clone_local(initialized_repo.clone(), path.to_string())
+            .unwrap();
+        assert_eq!(initialized_source.path, format!("{path}/skootrs"));
+
+        std::fs::write(format!("{}/README.md", initialized_source.path), "# skootrs").unwrap();
+        let source_service = LocalSourceService {
+            author: Some(GitAuthor {
+                name: "test".to_string(),
+                email: "test@example.com".to_string(),
+            }),
+            branch: None,
+            forge_hosts: ForgeHosts::default(),
+        };
+        source_service
+            .commit_and_push_changes(initialized_source, "test".to_string())
+            .unwrap();
+
+        let content = repo_service
+            .fetch_file_content(&initialized_repo, "./README.md")
+            .await
+            .unwrap();
+        assert_eq!(content, "# skootrs");
+    }
+
+    #[tokio::test]
+    async fn test_local_bare_repo_relative_parent_path() {
+        // Tests are run from the package's directory, so this is relative to the current directory.
+        let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let temp_dir = TempDir::new_in(manifest_dir, "test").unwrap();
+        let relative_path = temp_dir.path().strip_prefix(manifest_dir).unwrap().join("remotes");
+        let path = temp_dir.path().canonicalize().unwrap();
+        let repo_service = LocalRepoService::default();
+
+        let initialized_repo = repo_service
+            .initialize(RepoParams::Local(LocalRepoParams {
+                name: "skootrs".to_string(),
+                parent_path: relative_path.to_str().unwrap().to_string(),
+            }))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            initialized_repo.full_url(),
+            format!("file://{}/remotes/skootrs.git", path.display())
+        );
+        assert!(InitializedRepo::from_url(&initialized_repo.full_url(), &ForgeHosts::default()).is_ok());
+    }
 }
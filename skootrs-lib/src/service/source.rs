@@ -15,13 +15,98 @@
 
 #![allow(clippy::module_name_repetitions)]
 
-use std::{error::Error, fs, path::Path, process::Command};
+use std::{error::Error, fs, path::Path};
 
+use git2::{
+    build::RepoBuilder, Cred, CredentialType, ErrorCode, FetchOptions, IndexAddOption, PushOptions,
+    RemoteCallbacks, Repository, Signature,
+};
+use sha2::{Digest, Sha256};
 use tracing::{debug, info};
 
-use skootrs_model::skootrs::{InitializedRepo, InitializedSource, SkootError, SourceParams};
+use skootrs_model::skootrs::{ForgeHosts, GitAuthor, InitializedRepo, InitializedSource, SkootError, SourceParams};
 
 use super::repo::{LocalRepoService, RepoService};
+
+/// Returns the hex encoded SHA-256 hash of the contents of a source file. This is what gets recorded
+/// for the files Skootrs writes so that out of band changes to them can be detected.
+pub fn content_hash<C: AsRef<[u8]>>(contents: C) -> String {
+    format!("{:x}", Sha256::digest(contents))
+}
+
+/// The branch changes are committed and pushed to when one isn't configured.
+pub const DEFAULT_BRANCH: &str = "main";
+
+/// Clones the repo at `url` into `path`.
+///
+/// HTTPS remotes authenticate with the API token of `repo`'s forge if one is set, e.g. `GITHUB_TOKEN`, and
+/// fall back to the user's git credential helpers or SSH agent.
+///
+/// # Errors
+///
+/// Returns an error if the repo can't be cloned.
+pub fn clone_repo<P: AsRef<Path>>(url: &str, path: P, repo: &InitializedRepo) -> Result<(), SkootError> {
+    let mut fetch_options = FetchOptions::new();
+    fetch_options.remote_callbacks(remote_callbacks(forge_token_var(repo)));
+    RepoBuilder::new()
+        .fetch_options(fetch_options)
+        .clone(url, path.as_ref())?;
+    Ok(())
+}
+
+/// Returns the callbacks used to authenticate with a remote, trying the token in `token_var` first if
+/// there is one.
+fn remote_callbacks<'a>(token_var: Option<(&'static str, &'static str)>) -> RemoteCallbacks<'a> {
+    let mut callbacks = RemoteCallbacks::new();
+    let mut attempts = 0;
+    callbacks.credentials(move |url, username_from_url, allowed_types| {
+        // libgit2 keeps asking for credentials for as long as authentication fails.
+        attempts += 1;
+        if attempts > 3 {
+            return Err(git2::Error::from_str(&format!("Failed to authenticate with {url}")));
+        }
+
+        if allowed_types.contains(CredentialType::USER_PASS_PLAINTEXT) {
+            let token = token_var
+                .and_then(|(username, var)| std::env::var(var).ok().map(|token| (username, token)));
+            if let (1, Some((username, token))) = (attempts, token) {
+                return Cred::userpass_plaintext(username, &token);
+            }
+            if let Ok(cred) = git2::Config::open_default()
+                .and_then(|config| Cred::credential_helper(&config, url, username_from_url))
+            {
+                return Ok(cred);
+            }
+        }
+        if allowed_types.contains(CredentialType::SSH_KEY) {
+            return Cred::ssh_key_from_agent(username_from_url.unwrap_or("git"));
+        }
+
+        Cred::default()
+    });
+    callbacks
+}
+
+/// Returns the username to use for HTTPS remotes of `repo` along with the env var of the API token Skootrs
+/// uses for its forge, if it has one.
+const fn forge_token_var(repo: &InitializedRepo) -> Option<(&'static str, &'static str)> {
+    match repo {
+        InitializedRepo::Github(_) => Some(("x-access-token", "GITHUB_TOKEN")),
+        InitializedRepo::Gitlab(_) => Some(("oauth2", "GITLAB_TOKEN")),
+        // Gitea and Forgejo ignore the username when a token is used as the password.
+        InitializedRepo::Gitea(_) => Some(("oauth2", "GITEA_TOKEN")),
+        InitializedRepo::Local(_) => None,
+    }
+}
+
+/// Returns whether an error from reading a source file is because the file doesn't exist.
+#[must_use]
+pub fn is_not_found(error: &SkootError) -> bool {
+    error
+        .downcast_ref::<std::io::Error>()
+        .is_some_and(|e| e.kind() == std::io::ErrorKind::NotFound)
+}
+
 /// The `SourceService` trait provides an interface for and managing a project's source code.
 /// This code is usually something a local git repo. The service differs from the repo service
 /// in that it's focused on the files and not the repo itself.
@@ -77,8 +162,26 @@ pub trait SourceService {
 
 /// The `LocalSourceService` struct provides an implementation of the `SourceService` trait for initializing
 /// and managing a project's source files from the local machine.
-#[derive(Debug)]
-pub struct LocalSourceService {}
+#[derive(Debug, Default, Clone)]
+pub struct LocalSourceService {
+    /// The identity used for commits. If it isn't set, the identity from the user's git config is used.
+    pub author: Option<GitAuthor>,
+    /// The branch changes are committed and pushed to. If it isn't set, `DEFAULT_BRANCH` is used.
+    pub branch: Option<String>,
+    /// The hosts of self-hosted forges, used to pick the API token changes are pushed with.
+    pub forge_hosts: ForgeHosts,
+}
+
+impl LocalSourceService {
+    fn signature(&self, repo: &Repository) -> Result<Signature<'static>, SkootError> {
+        match &self.author {
+            Some(author) => Ok(Signature::now(&author.name, &author.email)?),
+            None => repo
+                .signature()
+                .map_err(|e| format!("No git author is configured for Skootrs or in the git config: {e}").into()),
+        }
+    }
+}
 
 impl SourceService for LocalSourceService {
     /// Returns `Ok(())` if changes are committed and pushed back to the remote  if successful,
@@ -88,7 +191,7 @@ impl SourceService for LocalSourceService {
         params: SourceParams,
         initialized_repo: InitializedRepo,
     ) -> Result<InitializedSource, SkootError> {
-        let repo_service = LocalRepoService {};
+        let repo_service = LocalRepoService::default();
         repo_service.clone_local(initialized_repo, params.parent_path)
     }
 
@@ -97,24 +200,57 @@ impl SourceService for LocalSourceService {
         source: InitializedSource,
         message: String,
     ) -> Result<(), Box<dyn Error + Send + Sync>> {
-        let _output = Command::new("git")
-            .arg("add")
-            .arg(".")
-            .current_dir(&source.path)
-            .output()?;
-
-        let _output = Command::new("git")
-            .arg("commit")
-            .arg("-m")
-            .arg(message)
-            .current_dir(&source.path)
-            .output()?;
-        info!("Committed changes for {}", source.path);
-
-        let _output = Command::new("git")
-            .arg("push")
-            .current_dir(&source.path)
-            .output()?;
+        let repo = Repository::open(&source.path)?;
+        let branch = self.branch.as_deref().unwrap_or(DEFAULT_BRANCH);
+        let branch_ref = format!("refs/heads/{branch}");
+
+        let mut index = repo.index()?;
+        index.add_all(["*"], IndexAddOption::DEFAULT, None)?;
+        // add_all doesn't stage deleted files
+        index.update_all(["*"], None)?;
+        index.write()?;
+        let tree = repo.find_tree(index.write_tree()?)?;
+
+        // The branch won't exist yet for a fresh clone of an empty repo.
+        let parent = match repo.find_reference(&branch_ref) {
+            Ok(reference) => Some(reference.peel_to_commit()?),
+            Err(e) if e.code() == ErrorCode::NotFound => {
+                repo.head().ok().and_then(|head| head.peel_to_commit().ok())
+            }
+            Err(e) => return Err(e.into()),
+        };
+        if parent.as_ref().is_some_and(|parent| parent.tree_id() == tree.id()) {
+            info!("No changes to commit for {}", source.path);
+        } else {
+            let signature = self.signature(&repo)?;
+            let parents = parent.iter().collect::<Vec<_>>();
+            repo.commit(Some(&branch_ref), &signature, &signature, &message, &tree, &parents)?;
+            info!("Committed changes for {} to {}", source.path, branch);
+        }
+        repo.set_head(&branch_ref)?;
+
+        let mut remote = repo.find_remote("origin")?;
+        let token_var = remote
+            .url()
+            .and_then(|url| InitializedRepo::from_url(url, &self.forge_hosts).ok())
+            .as_ref()
+            .and_then(forge_token_var);
+        let mut rejections = Vec::new();
+        {
+            let mut callbacks = remote_callbacks(token_var);
+            callbacks.push_update_reference(|reference, status| {
+                if let Some(status) = status {
+                    rejections.push(format!("{reference}: {status}"));
+                }
+                Ok(())
+            });
+            let mut push_options = PushOptions::new();
+            push_options.remote_callbacks(callbacks);
+            remote.push(&[format!("{branch_ref}:{branch_ref}")], Some(&mut push_options))?;
+        }
+        if !rejections.is_empty() {
+            return Err(format!("Failed to push changes for {}: {}", source.path, rejections.join(", ")).into());
+        }
         info!("Pushed changes for {}", source.path);
         Ok(())
     }
@@ -159,7 +295,7 @@ mod tests {
 
     #[test]
     fn test_initialize() {
-        let source_service = LocalSourceService {};
+        let source_service = LocalSourceService::default();
         let temp_dir = TempDir::new("test").unwrap();
         let parent_path = temp_dir.path().to_str().unwrap();
         let params = SourceParams {
@@ -176,9 +312,26 @@ mod tests {
         assert_eq!(initialized_source.path, format!("{}/{}", parent_path, "skootrs"));
     }
 
+    #[test]
+    fn test_forge_token_var() {
+        let forge_hosts = ForgeHosts {
+            gitlab: vec!["git.example.com".to_string()],
+            gitea: vec!["localhost:3000".to_string()],
+        };
+        let token_var = |url: &str| forge_token_var(&InitializedRepo::from_url(url, &forge_hosts).unwrap());
+        assert_eq!(
+            token_var("https://github.com/kusaridev/skootrs.git"),
+            Some(("x-access-token", "GITHUB_TOKEN"))
+        );
+        assert_eq!(token_var("https://git.example.com/kusaridev/skootrs.git"), Some(("oauth2", "GITLAB_TOKEN")));
+        assert_eq!(token_var("http://localhost:3000/kusaridev/skootrs.git"), Some(("oauth2", "GITEA_TOKEN")));
+        assert_eq!(token_var("https://codeberg.org/kusaridev/skootrs.git"), Some(("oauth2", "GITEA_TOKEN")));
+        assert_eq!(token_var("file:///tmp/skootrs.git"), None);
+    }
+
     #[test]
     fn test_write_file() {
-        let source_service = LocalSourceService {};
+        let source_service = LocalSourceService::default();
         let temp_dir = TempDir::new("test").unwrap();
         let initialized_source = InitializedSource {
             path: temp_dir.path().to_str().unwrap().to_string(),
@@ -196,7 +349,7 @@ mod tests {
 
     #[test]
     fn test_read_file() {
-        let source_service = LocalSourceService {};
+        let source_service = LocalSourceService::default();
         let temp_dir = TempDir::new("test").unwrap();
         let initialized_source = InitializedSource {
             path: temp_dir.path().to_str().unwrap().to_string(),
@@ -211,4 +364,12 @@ mod tests {
         let file_contents = source_service.read_file(&initialized_source, path, name).unwrap();
         assert_eq!(file_contents, "File contents");
     }
+
+    #[test]
+    fn test_content_hash() {
+        assert_eq!(
+            content_hash("File contents"),
+            "69423babe8e61aab549f347bcc8b9d77b7dcaca198fb0597bde0b5f97f968e38"
+        );
+    }
 }
@@ -0,0 +1,6 @@
+"""The {{ module }} package."""
+
+
+def greet(name: str) -> str:
+    """Returns a greeting for `name`."""
+    return f"hello {name}"
@@ -0,0 +1,5 @@
+from {{ module }} import greet
+
+
+def test_greet():
+    assert greet("world") == "hello world"
@@ -26,7 +26,7 @@ use serde::{Serialize, Deserialize};
 #[cfg(feature = "openapi")]
 use utoipa::ToSchema;
 
-use super::{InitializedSource, InitializedRepo, InitializedEcosystem};
+use super::{InitializedSource, InitializedRepo, InitializedProjectEcosystem, SupportedLicense, CodeReviewParams, GuacForwardingConfig};
 
 /// Represents a facet that has been initialized. This is an enum of
 /// the various supported facets like API based, and Source file bundle
@@ -49,6 +49,18 @@ pub enum FacetParams {
     APIBundle(APIBundleFacetParams),
 }
 
+impl FacetParams {
+    /// Returns the type of the facet the params are for.
+    #[must_use]
+    pub const fn facet_type(&self) -> &SupportedFacetType {
+        match self {
+            Self::SourceFile(p) => &p.facet_type,
+            Self::SourceBundle(p) => &p.facet_type,
+            Self::APIBundle(p) => &p.facet_type,
+        }
+    }
+}
+
 /// This is required to create an ordering of what facets get applied.
 /// There could be issues like a security feature being enabled before
 /// some other feature, which could lead to it being blocked.
@@ -60,16 +72,36 @@ pub struct FacetSetParams {
     pub facets_params: Vec<FacetParams>,
 }
 
+impl FacetSetParams {
+    /// Removes the params for any facets whose type isn't in `facet_types`, keeping the order of the rest.
+    pub fn retain_facet_types(&mut self, facet_types: &[SupportedFacetType]) {
+        self.facets_params
+            .retain(|facet_params| facet_types.contains(facet_params.facet_type()));
+    }
+}
+
 /// Represents the common parameters that are shared across all facets.
 /// This is mostly the context of the project, like the project name,
-/// source, repo, and ecosystem.
+/// source, repo, and ecosystems.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 pub struct CommonFacetParams {
     pub project_name: String,
     pub source: InitializedSource,
     pub repo: InitializedRepo,
-    pub ecosystem: InitializedEcosystem,
+    pub ecosystems: Vec<InitializedProjectEcosystem>,
+    #[serde(default)]
+    pub license: SupportedLicense,
+    #[serde(default)]
+    pub code_review: CodeReviewParams,
+    /// The types of the facets applied to the project, so facets can be set up to match the others.
+    #[serde(default)]
+    pub facets: Vec<SupportedFacetType>,
+    #[serde(default)]
+    pub guac_forwarding: Option<GuacForwardingConfig>,
+    /// The branch the project is developed on.
+    #[serde(default = "super::default_branch")]
+    pub default_branch: String,
 }
 
 /// (DEPRECATED) Represents a source file facet which is a facet that 
@@ -99,7 +131,30 @@ pub struct SourceFileContent {
     // TODO: Since the content can change out of band of Skootrs
     // should we even store the content in the database?
     pub content: String,
-    // TODO: Add a hash of the content to verify it hasn't changed
+    /// The hex encoded SHA-256 hash of the content as it was written
+    /// to the repo. This is used to detect if the file was changed out
+    /// of band of Skootrs.
+    #[serde(default)]
+    pub hash: Option<String>,
+}
+
+/// Represents a source file of a facet that no longer matches what
+/// Skootrs wrote to the repo.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct SourceFileDrift {
+    pub name: String,
+    pub path: String,
+    pub facet_type: SupportedFacetType,
+    pub status: SourceFileDriftStatus,
+}
+
+/// Represents how a source file has drifted from what Skootrs wrote to the repo.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub enum SourceFileDriftStatus {
+    Changed,
+    Deleted,
 }
 
 /// Represents a source bundle facet which is a facet that is based
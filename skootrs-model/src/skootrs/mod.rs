@@ -15,12 +15,12 @@
 
 pub mod facet;
 
-use std::error::Error;
+use std::{error::Error, path::{Component, Path}};
 
-use serde::{Serialize, Deserialize};
+use serde::{Serialize, Deserialize, Deserializer};
 use utoipa::ToSchema;
 
-use self::facet::InitializedFacet;
+use self::facet::{InitializedFacet, SupportedFacetType};
 
 pub type SkootError = Box<dyn Error + Send + Sync>;
 
@@ -34,9 +34,26 @@ pub type SkootError = Box<dyn Error + Send + Sync>;
 /// which falls under service.
 // TODO: These categories of structs should be moved to their own modules.
 /// Consts for the supported ecosystems, repos, etc. for convenient use by things like the CLI.
-pub const SUPPORTED_ECOSYSTEMS: [&str; 2] = [
+pub const SUPPORTED_ECOSYSTEMS: [&str; 6] = [
     "Go",
-    "Maven"
+    "Maven",
+    "Gradle",
+    "Cargo",
+    "Npm",
+    "Python",
+];
+
+pub const SUPPORTED_REPO_HOSTS: [&str; 4] = [
+    "Github",
+    "Gitlab",
+    "Gitea",
+    "Local",
+];
+
+pub const SUPPORTED_LICENSES: [&str; 3] = [
+    "Apache-2.0",
+    "MIT",
+    "BSD-3-Clause",
 ];
 
 // TODO: These should be their own structs, but they're currently not any different from the params structs.
@@ -47,9 +64,20 @@ pub const SUPPORTED_ECOSYSTEMS: [&str; 2] = [
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 pub struct InitializedProject {
     pub repo: InitializedRepo,
-    pub ecosystem: InitializedEcosystem,
+    /// The ecosystems of the project along with the directories they live in. Projects stored before
+    /// Skootrs supported multiple ecosystems have a single `ecosystem` which is read as one at the root.
+    #[serde(alias = "ecosystem", deserialize_with = "one_or_many::<_, _, InitializedEcosystem>")]
+    pub ecosystems: Vec<InitializedProjectEcosystem>,
     pub source: InitializedSource,
     pub facets: Vec<InitializedFacet>,
+    /// The facets Skootrs would manage for the project that it couldn't find. This is only populated for
+    /// projects that were imported instead of created by Skootrs.
+    #[serde(default)]
+    pub missing_facets: Vec<SupportedFacetType>,
+    /// The GUAC collector the project's releases forward their supply chain documents to, so where they
+    /// are sent can be audited.
+    #[serde(default)]
+    pub guac_collector_endpoint: Option<String>,
 }
 
 /// Represents the parameters for creating a project.
@@ -58,15 +86,298 @@ pub struct InitializedProject {
 pub struct ProjectParams {
     pub name: String,
     pub repo_params: RepoParams,
-    pub ecosystem_params: EcosystemParams,
+    /// The ecosystems to initialize along with the directories to initialize them in. A single
+    /// `ecosystem_params` is also accepted and is initialized at the root of the project.
+    #[serde(alias = "ecosystem_params", deserialize_with = "project_ecosystems")]
+    pub ecosystems: Vec<ProjectEcosystemParams>,
     pub source_params: SourceParams,
+    /// The facets to create the project with. If this isn't set, Skootrs' default set of facets is used.
+    #[serde(default)]
+    pub facets: Option<Vec<SupportedFacetType>>,
+    /// The license to create the project with.
+    #[serde(default)]
+    pub license: SupportedLicense,
+    /// How changes to the project are reviewed.
+    #[serde(default)]
+    pub code_review: CodeReviewParams,
+    /// Where the project's releases forward their supply chain documents to. This comes from the Skootrs
+    /// config instead of being prompted for.
+    #[serde(default)]
+    pub guac_forwarding: Option<GuacForwardingConfig>,
+    /// The branch the project is developed on, which its branch protection and workflows are set up for.
+    /// This comes from the Skootrs config instead of being prompted for.
+    #[serde(default = "default_branch")]
+    pub default_branch: String,
+}
+
+/// Returns the branch projects are developed on if none is configured.
+pub(crate) fn default_branch() -> String {
+    "main".to_string()
+}
+
+/// Represents the licenses Skootrs can create a project with.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub enum SupportedLicense {
+    #[default]
+    Apache2,
+    Mit,
+    Bsd3Clause,
+}
+
+impl SupportedLicense {
+    /// Returns the SPDX identifier of the license, e.g. `Apache-2.0`.
+    #[must_use] pub fn spdx_id(&self) -> String {
+        match self {
+            Self::Apache2 => "Apache-2.0".to_string(),
+            Self::Mit => "MIT".to_string(),
+            Self::Bsd3Clause => "BSD-3-Clause".to_string(),
+        }
+    }
+}
+
+/// Represents how changes to a project are reviewed before they are merged.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(default)]
+pub struct CodeReviewParams {
+    /// The number of approving reviews a change needs before it can be merged.
+    pub required_approving_review_count: u8,
+    /// Whether approvals are dismissed when new commits are pushed to a change.
+    pub dismiss_stale_reviews: bool,
+    /// Whether a change needs to be approved by one of its code owners.
+    pub require_code_owner_reviews: bool,
+    /// The team that owns the project, e.g. `kusaridev/security`. If this isn't set, the user or group that
+    /// owns the repo is the code owner.
+    pub owning_team: Option<String>,
+}
+
+impl Default for CodeReviewParams {
+    fn default() -> Self {
+        Self {
+            required_approving_review_count: 1,
+            dismiss_stale_reviews: true,
+            require_code_owner_reviews: true,
+            owning_team: None,
+        }
+    }
+}
+
+/// Represents the parameters for importing an existing repository that wasn't created by Skootrs as a project.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ProjectImportParams {
+    /// The URL of the existing repository, e.g. `https://github.com/kusaridev/skootrs`.
+    pub repo_url: String,
+    /// The ecosystems of the repo along with the directories they live in. If this is empty, the
+    /// ecosystems are detected from the manifests at the root of the repo.
+    #[serde(default, deserialize_with = "imported_ecosystems")]
+    pub ecosystems: Vec<ProjectEcosystemParams>,
+    pub source_params: SourceParams,
+    /// The hosts of self-hosted forges used to recognize `repo_url`, e.g. a Gitea instance on `localhost:3000`.
+    #[serde(default)]
+    pub forge_hosts: ForgeHosts,
+}
+
+/// Either a list of values or a single one, for fields that used to hold a single value.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OneOrMany<T, U> {
+    Many(Vec<T>),
+    One(U),
+}
+
+/// Deserializes a list of values from either a list or a single value, so data written before a field
+/// became a list can still be read.
+fn one_or_many<'de, D, T, U>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+    U: Deserialize<'de> + Into<T>,
+{
+    Ok(match OneOrMany::<T, U>::deserialize(deserializer)? {
+        OneOrMany::Many(values) => values,
+        OneOrMany::One(value) => vec![value.into()],
+    })
+}
+
+/// Deserializes the ecosystems of a new project, rejecting any that can't be initialized in its source.
+fn project_ecosystems<'de, D>(deserializer: D) -> Result<Vec<ProjectEcosystemParams>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let ecosystems = one_or_many::<_, _, EcosystemParams>(deserializer)?;
+    validate_project_ecosystems(&ecosystems).map_err(serde::de::Error::custom)?;
+    Ok(ecosystems)
+}
+
+/// Deserializes the ecosystems of an imported project, rejecting any outside of its source.
+fn imported_ecosystems<'de, D>(deserializer: D) -> Result<Vec<ProjectEcosystemParams>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let ecosystems = Vec::<ProjectEcosystemParams>::deserialize(deserializer)?;
+    validate_ecosystem_paths(&ecosystems).map_err(serde::de::Error::custom)?;
+    Ok(ecosystems)
+}
+
+/// Checks that a new project has at least one ecosystem, and that each of them is in its own directory
+/// of the project's source.
+///
+/// # Errors
+///
+/// Returns an error if there are no ecosystems, or if any of their paths aren't valid.
+pub fn validate_project_ecosystems(ecosystems: &[ProjectEcosystemParams]) -> Result<(), SkootError> {
+    if ecosystems.is_empty() {
+        return Err("A project needs at least one ecosystem".into());
+    }
+    validate_ecosystem_paths(ecosystems)
+}
+
+/// Checks that each ecosystem is in its own directory of the project's source, so initializing them can't
+/// write outside of the source or over each other.
+///
+/// # Errors
+///
+/// Returns an error if a path is absolute, has a `..` in it, or is the path of another ecosystem.
+pub fn validate_ecosystem_paths(ecosystems: &[ProjectEcosystemParams]) -> Result<(), SkootError> {
+    let mut directories: Vec<&str> = Vec::new();
+    for ecosystem in ecosystems {
+        let is_in_source = Path::new(&ecosystem.path)
+            .components()
+            .all(|component| matches!(component, Component::Normal(_) | Component::CurDir));
+        if !is_in_source {
+            return Err(format!(
+                "The ecosystem path {} has to be relative to the project without any `..` in it",
+                ecosystem.path
+            )
+            .into());
+        }
+        let directory = ecosystem.directory();
+        if directories.contains(&directory) {
+            return Err(format!("There is more than one ecosystem in the path {}", ecosystem.path).into());
+        }
+        directories.push(directory);
+    }
+    Ok(())
+}
+
+fn default_ecosystem_path() -> String {
+    "./".to_string()
+}
+
+/// Returns the directory of an ecosystem relative to the root of the project's source, without any
+/// leading `./` or trailing `/`. The root of the project is an empty string.
+fn ecosystem_directory(path: &str) -> &str {
+    let directory = path.trim_start_matches("./").trim_matches('/');
+    if directory == "." {
+        ""
+    } else {
+        directory
+    }
+}
+
+/// Represents the parameters for initializing an ecosystem in a directory of a project, e.g. a Go
+/// backend in `backend` and a TypeScript frontend in `frontend` of the same repo.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ProjectEcosystemParams {
+    /// The directory of the ecosystem relative to the root of the project's source. Defaults to the root.
+    #[serde(default = "default_ecosystem_path")]
+    pub path: String,
+    pub ecosystem_params: EcosystemParams,
+}
+
+impl ProjectEcosystemParams {
+    /// Returns the directory of the ecosystem relative to the root of the project's source, e.g. `backend`.
+    /// This is an empty string for an ecosystem at the root.
+    #[must_use] pub fn directory(&self) -> &str {
+        ecosystem_directory(&self.path)
+    }
+}
+
+/// Puts the ecosystem at the root of the project.
+impl From<EcosystemParams> for ProjectEcosystemParams {
+    fn from(ecosystem_params: EcosystemParams) -> Self {
+        Self {
+            path: default_ecosystem_path(),
+            ecosystem_params,
+        }
+    }
+}
+
+/// Represents an initialized ecosystem along with the directory of the project it lives in.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct InitializedProjectEcosystem {
+    /// The directory of the ecosystem relative to the root of the project's source.
+    #[serde(default = "default_ecosystem_path")]
+    pub path: String,
+    pub ecosystem: InitializedEcosystem,
+}
+
+impl InitializedProjectEcosystem {
+    /// Returns the directory of the ecosystem relative to the root of the project's source, e.g. `backend`.
+    /// This is an empty string for an ecosystem at the root.
+    #[must_use] pub fn directory(&self) -> &str {
+        ecosystem_directory(&self.path)
+    }
+
+    /// Returns the path of the ecosystem's dependency manifest relative to the root of the project's
+    /// source, e.g. `backend/go.mod`.
+    #[must_use] pub fn dependency_manifest(&self) -> String {
+        self.path_in_ecosystem(&self.ecosystem.dependency_manifest())
+    }
+
+    /// Returns the path relative to the root of the project's source of a path in the ecosystem's directory.
+    #[must_use] pub fn path_in_ecosystem(&self, path: &str) -> String {
+        match (self.directory(), path.trim_start_matches("./")) {
+            ("", _) => path.to_string(),
+            (directory, "") => format!("{directory}/"),
+            (directory, path) => format!("{directory}/{path}"),
+        }
+    }
+}
+
+/// Represents an ecosystem recorded for a project that no longer matches the manifests in its directory.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct EcosystemDrift {
+    /// The directory of the ecosystem relative to the root of the project's source.
+    pub path: String,
+    pub recorded: InitializedEcosystem,
+    /// The ecosystem of the same kind detected from the manifests in the directory, if there is one.
+    pub detected: Option<InitializedEcosystem>,
+}
+
+/// Puts the ecosystem at the root of the project.
+impl From<InitializedEcosystem> for InitializedProjectEcosystem {
+    fn from(ecosystem: InitializedEcosystem) -> Self {
+        Self {
+            path: default_ecosystem_path(),
+            ecosystem,
+        }
+    }
+}
+
+/// Records the params of an ecosystem in a directory as is. See `From<EcosystemParams> for InitializedEcosystem`.
+impl From<ProjectEcosystemParams> for InitializedProjectEcosystem {
+    fn from(params: ProjectEcosystemParams) -> Self {
+        Self {
+            path: params.path,
+            ecosystem: params.ecosystem_params.into(),
+        }
+    }
 }
 
 /// Represents an initialized repository along with its host.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 pub enum InitializedRepo {
-    Github(InitializedGithubRepo)
+    Github(InitializedGithubRepo),
+    Gitlab(InitializedGitlabRepo),
+    Gitea(InitializedGiteaRepo),
+    Local(InitializedLocalRepo),
 }
 
 impl InitializedRepo {
@@ -74,6 +385,9 @@ impl InitializedRepo {
     #[must_use] pub fn host_url(&self) -> String {
         match self {
             Self::Github(x) => x.host_url(),
+            Self::Gitlab(x) => x.host_url(),
+            Self::Gitea(x) => x.host_url(),
+            Self::Local(x) => x.host_url(),
         }
     }
 
@@ -81,10 +395,134 @@ impl InitializedRepo {
     #[must_use] pub fn full_url(&self) -> String {
         match self {
             Self::Github(x) => x.full_url(),
+            Self::Gitlab(x) => x.full_url(),
+            Self::Gitea(x) => x.full_url(),
+            Self::Local(x) => x.full_url(),
         }
     }
 }
 
+impl TryFrom<String> for InitializedRepo {
+    type Error = SkootError;
+
+    /// Parses a repo URL into an `InitializedRepo`, only recognizing the well known forge hosts. See
+    /// `InitializedRepo::from_url`.
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::from_url(&value, &ForgeHosts::default())
+    }
+}
+
+impl InitializedRepo {
+    /// Parses a repo URL like `https://github.com/kusaridev/skootrs` into an `InitializedRepo`.
+    ///
+    /// Note: The URL doesn't say whether the owner of a Github repo is a user or an organization so
+    /// it is treated as an organization. This only matters for API calls that create repos.
+    ///
+    /// Gitlab repos are recognized by a host of `gitlab.com` or a self-hosted host starting with `gitlab.`,
+    /// e.g. `https://gitlab.example.com/group/subgroup/project`. Gitea and Forgejo repos are recognized by
+    /// a host of `codeberg.org`, a self-hosted host starting with `gitea.` or `forgejo.`, or one of the
+    /// Gitea hosts in `forge_hosts`, e.g. `http://localhost:3000/owner/project`. Local repos are
+    /// recognized by a `file://` URL to the bare repository, e.g. `file:///srv/git/skootrs.git`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL isn't a repo URL of a supported forge.
+    pub fn from_url(value: &str, forge_hosts: &ForgeHosts) -> Result<Self, SkootError> {
+        if let Some(path) = value.strip_prefix("file://") {
+            let path = path.trim_end_matches('/');
+            return match Path::new(path).file_name().and_then(|name| name.to_str()) {
+                Some(name) if path.starts_with('/') => Ok(Self::Local(InitializedLocalRepo {
+                    name: name.strip_suffix(".git").unwrap_or(name).to_string(),
+                    path: path.to_string(),
+                })),
+                _ => Err(format!("Invalid local repo URL: {value}").into()),
+            };
+        }
+
+        let trimmed = value.trim_end_matches('/');
+        let trimmed = trimmed.strip_suffix(".git").unwrap_or(trimmed);
+        let Some((scheme, host, path)) = trimmed
+            .split_once("://")
+            .filter(|(scheme, _)| *scheme == "https" || *scheme == "http")
+            .and_then(|(scheme, rest)| rest.split_once('/').map(|(host, path)| (scheme, host, path)))
+        else {
+            return Err(format!("Unsupported repo URL: {value}").into());
+        };
+
+        if host == "github.com" {
+            return match path.split('/').collect::<Vec<_>>().as_slice() {
+                [owner, name] if !owner.is_empty() && !name.is_empty() => {
+                    Ok(Self::Github(InitializedGithubRepo {
+                        name: (*name).to_string(),
+                        organization: GithubUser::Organization((*owner).to_string()),
+                    }))
+                }
+                _ => Err(format!("Invalid Github repo URL: {value}").into()),
+            };
+        }
+
+        if host == "gitlab.com" || host.starts_with("gitlab.") || ForgeHosts::contains(&forge_hosts.gitlab, host) {
+            return match path.rsplit_once('/') {
+                Some((namespace, name))
+                    if !name.is_empty() && !namespace.split('/').any(str::is_empty) =>
+                {
+                    Ok(Self::Gitlab(InitializedGitlabRepo {
+                        name: name.to_string(),
+                        namespace: namespace.to_string(),
+                        host_url: format!("{scheme}://{host}"),
+                    }))
+                }
+                _ => Err(format!("Invalid Gitlab repo URL: {value}").into()),
+            };
+        }
+
+        if host == "codeberg.org"
+            || host.starts_with("gitea.")
+            || host.starts_with("forgejo.")
+            || ForgeHosts::contains(&forge_hosts.gitea, host)
+        {
+            return match path.split('/').collect::<Vec<_>>().as_slice() {
+                [owner, name] if !owner.is_empty() && !name.is_empty() => {
+                    Ok(Self::Gitea(InitializedGiteaRepo {
+                        name: (*name).to_string(),
+                        owner: (*owner).to_string(),
+                        host_url: format!("{scheme}://{host}"),
+                    }))
+                }
+                _ => Err(format!("Invalid Gitea repo URL: {value}").into()),
+            };
+        }
+
+        Err(format!("Unsupported repo URL: {value}").into())
+    }
+}
+
+/// The hosts of self-hosted forges that can't be recognized from their name alone, e.g. a Gitea instance
+/// running on `localhost:3000`.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(default)]
+pub struct ForgeHosts {
+    /// The hosts of Gitlab instances, including the port if there is one, e.g. `git.example.com`.
+    pub gitlab: Vec<String>,
+    /// The hosts of Gitea and Forgejo instances, including the port if there is one, e.g. `localhost:3000`.
+    pub gitea: Vec<String>,
+}
+
+impl ForgeHosts {
+    /// Returns whether `host` is one of `hosts`. The configured hosts may also be given as base URLs,
+    /// e.g. `http://localhost:3000/`.
+    fn contains(hosts: &[String], host: &str) -> bool {
+        hosts.iter().any(|configured| {
+            let configured = configured
+                .split_once("://")
+                .map_or(configured.as_str(), |(_, rest)| rest)
+                .trim_end_matches('/');
+            configured.eq_ignore_ascii_case(host)
+        })
+    }
+}
+
 /// Represents an initialized Github repository.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
@@ -110,20 +548,179 @@ impl InitializedGithubRepo {
     }
 }
 
+/// Represents an initialized Gitlab repository.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct InitializedGitlabRepo {
+    pub name: String,
+    /// The full path of the group, subgroup, or user the repo belongs to, e.g. `kusaridev/security`.
+    pub namespace: String,
+    /// The URL of the Gitlab instance, e.g. `https://gitlab.com` or a self-hosted instance.
+    pub host_url: String,
+}
+
+impl InitializedGitlabRepo {
+    /// Returns the URL of the Gitlab instance.
+    #[must_use] pub fn host_url(&self) -> String {
+        self.host_url.trim_end_matches('/').to_string()
+    }
+
+    /// Returns the full URL to the Gitlab repo.
+    #[must_use] pub fn full_url(&self) -> String {
+        format!("{}/{}/{}", self.host_url(), self.namespace, self.name)
+    }
+
+    /// Returns the full path of the repo, e.g. `kusaridev/security/skootrs`. This is used to identify
+    /// the project in the Gitlab API.
+    #[must_use] pub fn path_with_namespace(&self) -> String {
+        format!("{}/{}", self.namespace, self.name)
+    }
+}
+
+/// Represents an initialized Gitea repository. This also covers Forgejo, which has the same API.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct InitializedGiteaRepo {
+    pub name: String,
+    /// The user or organization the repo belongs to.
+    pub owner: String,
+    /// The URL of the Gitea instance, e.g. `https://codeberg.org` or `http://localhost:3000`.
+    pub host_url: String,
+}
+
+impl InitializedGiteaRepo {
+    /// Returns the URL of the Gitea instance.
+    #[must_use] pub fn host_url(&self) -> String {
+        self.host_url.trim_end_matches('/').to_string()
+    }
+
+    /// Returns the full URL to the Gitea repo.
+    #[must_use] pub fn full_url(&self) -> String {
+        format!("{}/{}/{}", self.host_url(), self.owner, self.name)
+    }
+}
+
+/// Represents an initialized local repository.
+///
+/// This is a bare git repository on disk that takes the place of a remote hosted on a service like Github.
+/// This is useful for air-gapped environments and testing.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct InitializedLocalRepo {
+    pub name: String,
+    /// The absolute path to the bare repository, e.g. `/srv/git/skootrs.git`.
+    pub path: String,
+}
+
+impl InitializedLocalRepo {
+    /// Returns the URL of the directory containing the bare repository.
+    #[must_use] pub fn host_url(&self) -> String {
+        let parent = Path::new(&self.path)
+            .parent()
+            .map_or(String::new(), |p| p.to_string_lossy().to_string());
+        format!("file://{parent}")
+    }
+
+    /// Returns the full URL to the bare repository.
+    #[must_use] pub fn full_url(&self) -> String {
+        format!("file://{}", self.path)
+    }
+}
+
 /// Represents an initialized ecosystem. The enum is used to represent the different types of ecosystems
 /// that are supported by Skootrs currently.
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 pub enum InitializedEcosystem {
     Go(InitializedGo),
-    Maven(InitializedMaven)
+    Maven(InitializedMaven),
+    Gradle(InitializedGradle),
+    Cargo(InitializedCargo),
+    Npm(InitializedNpm),
+    Python(InitializedPython),
+}
+
+impl InitializedEcosystem {
+    /// Returns the name of the kind of ecosystem, e.g. `go` or `npm`.
+    #[must_use] pub const fn kind(&self) -> &'static str {
+        match self {
+            Self::Go(_) => "go",
+            Self::Maven(_) => "maven",
+            Self::Gradle(_) => "gradle",
+            Self::Cargo(_) => "cargo",
+            Self::Npm(_) => "npm",
+            Self::Python(_) => "python",
+        }
+    }
+
+    /// Returns the path of the file in the project's repo that lists its dependencies, e.g. `go.mod` for Go.
+    #[must_use] pub fn dependency_manifest(&self) -> String {
+        match self {
+            Self::Go(_) => "go.mod".to_string(),
+            Self::Maven(_) => "pom.xml".to_string(),
+            Self::Gradle(_) => "build.gradle.kts".to_string(),
+            Self::Cargo(_) => "Cargo.toml".to_string(),
+            Self::Npm(_) => "package.json".to_string(),
+            Self::Python(_) => "pyproject.toml".to_string(),
+        }
+    }
+}
+
+/// Records the params of an ecosystem as is. This is for ecosystems that already exist, like the ones in
+/// imported projects, since initializing an ecosystem doesn't change anything about its params.
+impl From<EcosystemParams> for InitializedEcosystem {
+    fn from(params: EcosystemParams) -> Self {
+        match params {
+            EcosystemParams::Go(g) => Self::Go(InitializedGo {
+                name: g.name,
+                host: g.host,
+                major_version: None,
+            }),
+            EcosystemParams::Maven(m) => Self::Maven(InitializedMaven {
+                group_id: m.group_id,
+                artifact_id: m.artifact_id,
+            }),
+            EcosystemParams::Gradle(g) => Self::Gradle(InitializedGradle {
+                group: g.group,
+                name: g.name,
+                gradle_version: g.gradle_version,
+                distribution_sha256_sum: g.distribution_sha256_sum,
+            }),
+            EcosystemParams::Cargo(c) => Self::Cargo(InitializedCargo {
+                name: c.name,
+                crate_type: c.crate_type,
+                edition: c.edition,
+            }),
+            EcosystemParams::Npm(n) => Self::Npm(InitializedNpm {
+                name: n.name,
+                scope: n.scope,
+                typescript: n.typescript,
+            }),
+            EcosystemParams::Python(p) => Self::Python(InitializedPython {
+                name: p.name,
+                python_version: p.python_version,
+            }),
+        }
+    }
 }
 
 /// Represents the parameters for creating a repository.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 pub enum RepoParams {
-    Github(GithubRepoParams)
+    Github(GithubRepoParams),
+    Gitlab(GitlabRepoParams),
+    Gitea(GiteaRepoParams),
+    Local(LocalRepoParams),
+}
+
+/// Represents who can see a repo created in a repo host.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub enum RepoVisibility {
+    #[default]
+    Public,
+    Private,
 }
 
 /// Represents the parameters for initializing an ecosystem.
@@ -131,7 +728,11 @@ pub enum RepoParams {
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 pub enum EcosystemParams {
     Go(GoParams),
-    Maven(MavenParams)
+    Maven(MavenParams),
+    Gradle(GradleParams),
+    Cargo(CargoParams),
+    Npm(NpmParams),
+    Python(PythonParams),
 }
 
 /// Represents a Github user which is really just whether or not a repo belongs to  a user or organization.
@@ -161,6 +762,8 @@ pub struct GithubRepoParams {
     pub name: String,
     pub description: String,
     pub organization: GithubUser,
+    #[serde(default)]
+    pub visibility: RepoVisibility,
 }
 
 impl GithubRepoParams {
@@ -178,6 +781,73 @@ impl GithubRepoParams {
     }
 }
 
+/// Represents the parameters for creating a Gitlab repository.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct GitlabRepoParams {
+    pub name: String,
+    pub description: String,
+    /// The full path of the group, subgroup, or user to create the repo in, e.g. `kusaridev/security`.
+    pub namespace: String,
+    /// The URL of the Gitlab instance, e.g. `https://gitlab.com` or a self-hosted instance.
+    pub host_url: String,
+    #[serde(default)]
+    pub visibility: RepoVisibility,
+}
+
+impl GitlabRepoParams {
+    #[must_use] pub fn host_url(&self) -> String {
+        self.host_url.trim_end_matches('/').to_string()
+    }
+
+    #[must_use] pub fn full_url(&self) -> String {
+        format!("{}/{}/{}", self.host_url(), self.namespace, self.name)
+    }
+}
+
+/// Represents the parameters for creating a Gitea repository. This also covers Forgejo, which has the same API.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct GiteaRepoParams {
+    pub name: String,
+    pub description: String,
+    /// The user or organization to create the repo under. If it's the user the token belongs to, the repo is
+    /// created as a user repo, otherwise it's created in the organization.
+    pub owner: String,
+    /// The URL of the Gitea instance, e.g. `https://codeberg.org` or `http://localhost:3000`.
+    pub host_url: String,
+    #[serde(default)]
+    pub visibility: RepoVisibility,
+}
+
+impl GiteaRepoParams {
+    #[must_use] pub fn host_url(&self) -> String {
+        self.host_url.trim_end_matches('/').to_string()
+    }
+
+    #[must_use] pub fn full_url(&self) -> String {
+        format!("{}/{}/{}", self.host_url(), self.owner, self.name)
+    }
+}
+
+/// Represents the parameters for creating a local bare repository.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct LocalRepoParams {
+    pub name: String,
+    /// The path of the directory to create the bare repository in. The repository is created at
+    /// `<parent_path>/<name>.git`, and a relative path is resolved from the current directory.
+    pub parent_path: String,
+}
+
+impl LocalRepoParams {
+    /// Returns the path of the bare repository. This is relative to `parent_path`, so it's only absolute if
+    /// `parent_path` is.
+    #[must_use] pub fn path(&self) -> String {
+        format!("{}/{}.git", self.parent_path.trim_end_matches('/'), self.name)
+    }
+}
+
 /// Represents the parameters for initializing a source code repository.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
@@ -192,6 +862,14 @@ impl SourceParams {
     }
 }
 
+/// Represents the identity used for the commits Skootrs makes to a project's source.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct GitAuthor {
+    pub name: String,
+    pub email: String,
+}
+
 /// Struct representing a working copy of source code.
 #[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
 pub struct InitializedSource {
@@ -208,6 +886,53 @@ pub struct MavenParams {
     pub artifact_id: String,
 }
 
+/// Represents the Gradle ecosystem for JVM projects built with the Kotlin DSL.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct GradleParams {
+    /// The group of the Gradle project, e.g. `com.kusaridev`.
+    pub group: String,
+    /// The name of the root project.
+    pub name: String,
+    /// The version of Gradle the wrapper is pinned to, e.g. `8.5`.
+    pub gradle_version: String,
+    /// The SHA-256 checksum of the Gradle distribution the wrapper downloads. Gradle publishes it at
+    /// `https://services.gradle.org/distributions/gradle-{version}-bin.zip.sha256`.
+    pub distribution_sha256_sum: String,
+}
+
+impl GradleParams {
+    /// Returns the Java package of the project in the format "{group}.{name}".
+    #[must_use] pub fn package(&self) -> String {
+        gradle_package_name(&self.group, &self.name)
+    }
+}
+
+/// Represents an initialized Gradle project.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct InitializedGradle {
+    /// The group of the Gradle project, e.g. `com.kusaridev`.
+    pub group: String,
+    /// The name of the root project.
+    pub name: String,
+    /// The version of Gradle the wrapper is pinned to, e.g. `8.5`.
+    pub gradle_version: String,
+    /// The SHA-256 checksum of the Gradle distribution the wrapper downloads.
+    pub distribution_sha256_sum: String,
+}
+
+impl InitializedGradle {
+    /// Returns the Java package of the project in the format "{group}.{name}".
+    #[must_use] pub fn package(&self) -> String {
+        gradle_package_name(&self.group, &self.name)
+    }
+}
+
+fn gradle_package_name(group: &str, name: &str) -> String {
+    format!("{group}.{}", name.to_lowercase().replace('-', "_"))
+}
+
 /// Represents the Go ecosystem.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
@@ -219,24 +944,36 @@ pub struct GoParams {
 }
 
 /// Represents an initialized go module.
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 pub struct InitializedGo {
     /// The name of the Go module.
     pub name: String,
-    /// The host of the Go module.
+    /// The host of the Go module. This is empty for a module path without a host, e.g. `example`.
     pub host: String,
+    /// The major version suffix of the module path, e.g. `v2` for `github.com/kusaridev/skootrs/v2`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub major_version: Option<String>,
 }
 
 impl InitializedGo {
-    /// Returns the module name in the format "{host}/{name}".
+    /// Returns the module name in the format "{host}/{name}", followed by the major version suffix if
+    /// there is one.
     #[must_use] pub fn module(&self) -> String {
-        format!("{}/{}", self.host, self.name)
+        let module = if self.host.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{}/{}", self.host, self.name)
+        };
+        match &self.major_version {
+            Some(major_version) => format!("{module}/{major_version}"),
+            None => module,
+        }
     }
 }
 
 /// Represents an initialized Maven project.
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 pub struct InitializedMaven {
     /// The group ID of the Maven project.
@@ -252,17 +989,269 @@ impl GoParams {
     }
 }
 
-/// A set of configuration options for Skootrs.
+/// Represents the Cargo ecosystem for Rust projects.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct CargoParams {
+    /// The name of the crate.
+    pub name: String,
+    /// Whether the crate is a binary or a library.
+    pub crate_type: CargoCrateType,
+    /// The Rust edition of the crate, e.g. `2021`.
+    pub edition: String,
+}
+
+/// Represents an initialized Cargo crate.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct InitializedCargo {
+    /// The name of the crate.
+    pub name: String,
+    /// Whether the crate is a binary or a library.
+    pub crate_type: CargoCrateType,
+    /// The Rust edition of the crate, e.g. `2021`.
+    pub edition: String,
+}
+
+/// The types of crates Skootrs can create.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub enum CargoCrateType {
+    /// A crate with a `src/main.rs` that builds an executable.
+    Binary,
+    /// A crate with a `src/lib.rs` that builds a library.
+    Library,
+}
+
+/// Represents the npm ecosystem for Node.js projects.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct NpmParams {
+    /// The name of the package, without the scope.
+    pub name: String,
+    /// The scope of the package without the leading `@`, e.g. `kusaridev`.
+    pub scope: Option<String>,
+    /// Whether the package is written in TypeScript instead of JavaScript.
+    pub typescript: bool,
+}
+
+impl NpmParams {
+    /// Returns the package name in the format "@{scope}/{name}", or just the name if there isn't a scope.
+    #[must_use] pub fn package_name(&self) -> String {
+        npm_package_name(self.scope.as_deref(), &self.name)
+    }
+}
+
+/// Represents an initialized npm package.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct InitializedNpm {
+    /// The name of the package, without the scope.
+    pub name: String,
+    /// The scope of the package without the leading `@`, e.g. `kusaridev`.
+    pub scope: Option<String>,
+    /// Whether the package is written in TypeScript instead of JavaScript.
+    pub typescript: bool,
+}
+
+impl InitializedNpm {
+    /// Returns the package name in the format "@{scope}/{name}", or just the name if there isn't a scope.
+    #[must_use] pub fn package_name(&self) -> String {
+        npm_package_name(self.scope.as_deref(), &self.name)
+    }
+}
+
+fn npm_package_name(scope: Option<&str>, name: &str) -> String {
+    scope.map_or_else(
+        || name.to_string(),
+        |scope| format!("@{}/{name}", scope.trim_start_matches('@')),
+    )
+}
+
+/// Represents the Python ecosystem for projects packaged with a `pyproject.toml`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct PythonParams {
+    /// The name of the distribution package, e.g. `my-project`.
+    pub name: String,
+    /// The minimum version of Python the project supports, e.g. `3.9`.
+    pub python_version: String,
+}
+
+impl PythonParams {
+    /// Returns the name of the import package, e.g. `my_project` for the `my-project` distribution.
+    #[must_use] pub fn module(&self) -> String {
+        python_module_name(&self.name)
+    }
+}
+
+/// Represents an initialized Python project.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct InitializedPython {
+    /// The name of the distribution package, e.g. `my-project`.
+    pub name: String,
+    /// The minimum version of Python the project supports, e.g. `3.9`.
+    pub python_version: String,
+}
+
+impl InitializedPython {
+    /// Returns the name of the import package, e.g. `my_project` for the `my-project` distribution.
+    #[must_use] pub fn module(&self) -> String {
+        python_module_name(&self.name)
+    }
+}
+
+fn python_module_name(name: &str) -> String {
+    name.to_lowercase().replace(['-', '.'], "_")
+}
+
+/// A set of configuration options for Skootrs. Any options missing from a config source fall back to
+/// their defaults.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(default)]
 pub struct SkootrsConfig {
+    /// The directory projects are cloned into.
     pub local_project_path: String,
+    /// The path of the state store the Skootrs daemon serves projects from and the CLI records the projects
+    /// it creates and imports in.
+    pub state_store_path: String,
+    /// The backend of the state store at `state_store_path`.
+    pub state_store: StateStoreBackend,
+    /// The path of the local reference cache of the projects the CLI knows about. If this isn't set, the cache
+    /// is kept in the user cache directory.
+    pub reference_cache_path: Option<String>,
+    /// The organization, group, or owner to suggest when creating a project in a repo host.
+    pub default_organization: Option<String>,
+    /// The facets new projects are created with. If this isn't set, Skootrs' default set of facets is used.
+    pub default_facets: Option<Vec<SupportedFacetType>>,
+    /// The branch Skootrs commits and pushes changes to.
+    pub default_branch: String,
+    /// The base URL to suggest for self-hosted repo hosts like Gitlab and Gitea, e.g. `https://gitlab.example.com`.
+    pub forge_base_url: Option<String>,
+    /// The format of the logs Skootrs writes to stdout.
+    pub log_format: LogFormat,
+    /// How the ecosystems of new projects are scaffolded.
+    pub ecosystem_scaffolding: EcosystemScaffolding,
+    /// The GUAC collector the releases of new projects forward their SBOMs, SLSA attestations, and Scorecard
+    /// results to. The `GUACForwardingConfig` facet doesn't forward anything if this isn't set.
+    pub guac_forwarding: Option<GuacForwardingConfig>,
+    /// The hosts of self-hosted forges Skootrs can't recognize from their name, e.g. `localhost:3000`.
+    pub forge_hosts: ForgeHosts,
+    /// The identity Skootrs commits changes to projects with. If this isn't set, the identity from the user's
+    /// git config is used.
+    pub git_author: Option<GitAuthor>,
 }
 
 impl Default for SkootrsConfig {
     fn default() -> Self {
         Self {
-            local_project_path: "/tmp".into()
+            local_project_path: "/tmp".into(),
+            state_store_path: "state.db".into(),
+            state_store: StateStoreBackend::default(),
+            reference_cache_path: None,
+            default_organization: None,
+            default_facets: None,
+            default_branch: default_branch(),
+            forge_base_url: None,
+            log_format: LogFormat::default(),
+            ecosystem_scaffolding: EcosystemScaffolding::default(),
+            guac_forwarding: None,
+            forge_hosts: ForgeHosts::default(),
+            git_author: None,
+        }
+    }
+}
+
+/// The supported formats for the logs Skootrs writes. These are parsed case-insensitively, e.g. `pretty`.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(try_from = "String")]
+pub enum LogFormat {
+    /// Structured JSON logs in the Bunyan format.
+    #[default]
+    Bunyan,
+    /// Human readable logs.
+    Pretty,
+}
+
+impl TryFrom<String> for LogFormat {
+    type Error = SkootError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.to_ascii_lowercase().as_str() {
+            "bunyan" => Ok(Self::Bunyan),
+            "pretty" => Ok(Self::Pretty),
+            _ => Err(format!("Unknown log format {value}, expected Bunyan or Pretty").into()),
+        }
+    }
+}
+
+/// The ways Skootrs can scaffold the ecosystem of a new project. These are parsed case-insensitively, e.g.
+/// `template`.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(try_from = "String")]
+pub enum EcosystemScaffolding {
+    /// Runs the ecosystem's own tools, e.g. `go mod init` or `mvn archetype:generate`, which need to be
+    /// installed on the host running Skootrs.
+    #[default]
+    Toolchain,
+    /// Renders the ecosystem's manifests, e.g. `go.mod` or `pom.xml`, from templates so nothing needs to be
+    /// installed.
+    Template,
+}
+
+impl TryFrom<String> for EcosystemScaffolding {
+    type Error = SkootError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.to_ascii_lowercase().as_str() {
+            "toolchain" => Ok(Self::Toolchain),
+            "template" => Ok(Self::Template),
+            _ => Err(format!("Unknown ecosystem scaffolding {value}, expected Toolchain or Template").into()),
         }
     }
-}
\ No newline at end of file
+}
+
+/// The backends the state store of Skootrs projects can be kept in. These are parsed case-insensitively,
+/// e.g. `jsondir`.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(try_from = "String")]
+pub enum StateStoreBackend {
+    /// A `SurrealDB` database stored in the `state_store_path` directory.
+    #[default]
+    Surreal,
+    /// A directory of JSON files, one per project, at `state_store_path`.
+    JsonDir,
+}
+
+impl TryFrom<String> for StateStoreBackend {
+    type Error = SkootError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.to_ascii_lowercase().as_str() {
+            "surreal" => Ok(Self::Surreal),
+            "jsondir" => Ok(Self::JsonDir),
+            _ => Err(format!("Unknown state store backend {value}, expected Surreal or JsonDir").into()),
+        }
+    }
+}
+
+/// The GUAC collector a project's releases forward their supply chain documents to.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct GuacForwardingConfig {
+    /// The endpoint of the collector the documents are posted to, e.g. `https://guac.example.com/api/v1/documents`.
+    pub endpoint: String,
+    /// The name of the CI secret holding the token the collector authenticates requests with. The token itself
+    /// is never written to the project.
+    #[serde(default = "default_guac_credentials_secret")]
+    pub credentials_secret: String,
+}
+
+fn default_guac_credentials_secret() -> String {
+    "GUAC_COLLECTOR_TOKEN".to_string()
+}
@@ -15,10 +15,10 @@
 
 use actix_web::{Responder, web::{ServiceConfig, Data, Json, self}, HttpResponse};
 use serde::{Serialize, Deserialize};
-use skootrs_statestore::SurrealProjectStateStore;
+use skootrs_statestore::ProjectStateStore;
 use utoipa::ToSchema;
 
-use skootrs_model::skootrs::ProjectParams;
+use skootrs_model::skootrs::{ProjectParams, SkootrsConfig};
 use skootrs_lib::service::{ecosystem::LocalEcosystemService, facet::LocalFacetService, project::{LocalProjectService, ProjectService}, repo::LocalRepoService, source::LocalSourceService};
 
 /// An Error response for the REST API
@@ -33,13 +33,14 @@ pub(super) enum ErrorResponse {
 }
 
 /// Configures the services and routes for the Skootrs REST API
-pub(super) fn configure(store: Data<SurrealProjectStateStore>) -> impl FnOnce(&mut ServiceConfig) {
+pub(super) fn configure<S: ProjectStateStore + Send + Sync + 'static>(store: Data<S>, skootrs_config: Data<SkootrsConfig>) -> impl FnOnce(&mut ServiceConfig) {
     |config: &mut ServiceConfig| {
         config
             .app_data(store)
+            .app_data(skootrs_config)
             .service(web::resource("/projects")
-                .route(web::post().to(create_project))
-                .route(web::get().to(list_projects))
+                .route(web::post().to(create_project::<S>))
+                .route(web::get().to(list_projects::<S>))
             );
     }
 }
@@ -48,12 +49,15 @@ pub(super) fn configure(store: Data<SurrealProjectStateStore>) -> impl FnOnce(&m
 /// 
 /// Example: 
 /// {
-/// "ecosystem_params": {
-///    "Go": {
-///      "host": "github.com/mlieberman85",
-///      "name": "test-new-api-2"
+/// "ecosystems": [{
+///    "path": "./",
+///    "ecosystem_params": {
+///      "Go": {
+///        "host": "github.com/mlieberman85",
+///        "name": "test-new-api-2"
+///      }
 ///    }
-///  },
+///  }],
 ///  "name": "test-new-api-2",
 ///  "repo_params": {
 ///    "Github": { "name": "test-new-api-2", "description": "asdf", "organization": { "User": "mlieberman85" } }
@@ -69,16 +73,31 @@ pub(super) fn configure(store: Data<SurrealProjectStateStore>) -> impl FnOnce(&m
         (status = 409, description = "Project unable to be created", body = ErrorResponse, example = json!(ErrorResponse::InitializationError("Unable to create repo".into())))
     )
 )]
-pub(super) async fn create_project(params: Json<ProjectParams>, project_store: Data<SurrealProjectStateStore>) -> Result<impl Responder, actix_web::Error> {
+pub(super) async fn create_project<S: ProjectStateStore + Sync>(params: Json<ProjectParams>, project_store: Data<S>, config: Data<SkootrsConfig>) -> Result<impl Responder, actix_web::Error> {
     // TODO: This should be initialized elsewhere
     let project_service = LocalProjectService {
-        repo_service: LocalRepoService {},
-        ecosystem_service: LocalEcosystemService {},
-        source_service: LocalSourceService {},
+        repo_service: LocalRepoService {
+            branch: Some(config.default_branch.clone()),
+        },
+        ecosystem_service: LocalEcosystemService {
+            scaffolding: config.ecosystem_scaffolding.clone(),
+        },
+        source_service: LocalSourceService {
+            author: config.git_author.clone(),
+            branch: Some(config.default_branch.clone()),
+            forge_hosts: config.forge_hosts.clone(),
+        },
         facet_service: LocalFacetService {},
     };
 
-    let initialized_project = project_service.initialize(params.into_inner()).await
+    // Where releases are forwarded to and the branch changes are pushed to are up to whoever runs Skootrs,
+    // not whoever creates the project.
+    let params = ProjectParams {
+        guac_forwarding: config.guac_forwarding.clone(),
+        default_branch: config.default_branch.clone(),
+        ..params.into_inner()
+    };
+    let initialized_project = project_service.initialize(params).await
     .map_err(|err| actix_web::error::ErrorInternalServerError(err.to_string()))?;
     project_store.create(initialized_project.clone()).await.map_err(|err| actix_web::error::ErrorInternalServerError(err.to_string()))?;
     Ok(HttpResponse::Ok().json(initialized_project))
@@ -93,7 +112,7 @@ pub(super) async fn create_project(params: Json<ProjectParams>, project_store: D
         (status = 500, description = "Internal server error", body = ErrorResponse, example = json!(ErrorResponse::InitializationError("Unable to list repos".into()))),
     )
 )]
-pub(super) async fn list_projects(project_store: Data<SurrealProjectStateStore>) -> Result<impl Responder, actix_web::Error> {
+pub(super) async fn list_projects<S: ProjectStateStore + Sync>(project_store: Data<S>) -> Result<impl Responder, actix_web::Error> {
     let projects = project_store.select_all().await.map_err(|err| actix_web::error::ErrorInternalServerError(err.to_string()))?;
     Ok(HttpResponse::Ok().json(projects))
 }
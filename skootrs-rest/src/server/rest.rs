@@ -16,7 +16,7 @@
 use std::net::Ipv4Addr;
 
 use actix_web::{App, HttpServer, web::Data};
-use skootrs_statestore::SurrealProjectStateStore;
+use skootrs_statestore::ProjectStateStore;
 use tracing_actix_web::TracingLogger;
 use utoipa::{OpenApi, Modify, openapi::security::{SecurityScheme, ApiKey, ApiKeyValue}};
 use utoipa_rapidoc::RapiDoc;
@@ -24,12 +24,14 @@ use utoipa_redoc::{Redoc, Servable};
 use utoipa_swagger_ui::SwaggerUi;
 
 use crate::server::project::ErrorResponse;
-use skootrs_model::{skootrs::{InitializedProject, ProjectParams, InitializedRepo, InitializedGithubRepo, InitializedEcosystem, RepoParams, EcosystemParams, GithubUser, GithubRepoParams, SourceParams, InitializedSource, MavenParams, GoParams, InitializedGo, InitializedMaven, facet::{CommonFacetParams, SourceFileFacet, SourceFileFacetParams, InitializedFacet, FacetParams, SupportedFacetType}}, cd_events::repo_created::{RepositoryCreatedEvent, RepositoryCreatedEventContext, RepositoryCreatedEventContextId, RepositoryCreatedEventContextVersion, RepositoryCreatedEventSubject, RepositoryCreatedEventSubjectContent, RepositoryCreatedEventSubjectContentUrl, RepositoryCreatedEventSubjectId}, security_insights::insights10::{SecurityInsightsVersion100YamlSchema, SecurityInsightsVersion100YamlSchemaContributionPolicy, SecurityInsightsVersion100YamlSchemaContributionPolicyAutomatedToolsListItem, SecurityInsightsVersion100YamlSchemaContributionPolicyAutomatedToolsListItemComment, SecurityInsightsVersion100YamlSchemaDependencies, SecurityInsightsVersion100YamlSchemaDependenciesDependenciesLifecycle, SecurityInsightsVersion100YamlSchemaDependenciesDependenciesLifecycleComment, SecurityInsightsVersion100YamlSchemaDependenciesEnvDependenciesPolicy, SecurityInsightsVersion100YamlSchemaDependenciesEnvDependenciesPolicyComment, SecurityInsightsVersion100YamlSchemaDependenciesSbomItem, SecurityInsightsVersion100YamlSchemaDependenciesSbomItemSbomCreation, SecurityInsightsVersion100YamlSchemaHeader, SecurityInsightsVersion100YamlSchemaHeaderCommitHash, SecurityInsightsVersion100YamlSchemaProjectLifecycle, SecurityInsightsVersion100YamlSchemaProjectLifecycleReleaseProcess, SecurityInsightsVersion100YamlSchemaSecurityArtifacts, SecurityInsightsVersion100YamlSchemaSecurityArtifactsSelfAssessment, SecurityInsightsVersion100YamlSchemaSecurityArtifactsSelfAssessmentComment, SecurityInsightsVersion100YamlSchemaSecurityArtifactsThreatModel, SecurityInsightsVersion100YamlSchemaSecurityArtifactsThreatModelComment, SecurityInsightsVersion100YamlSchemaSecurityAssessmentsItem, SecurityInsightsVersion100YamlSchemaSecurityAssessmentsItemComment, SecurityInsightsVersion100YamlSchemaSecurityContactsItem, SecurityInsightsVersion100YamlSchemaSecurityContactsItemValue, SecurityInsightsVersion100YamlSchemaSecurityTestingItem, SecurityInsightsVersion100YamlSchemaSecurityTestingItemComment, SecurityInsightsVersion100YamlSchemaSecurityTestingItemIntegration, SecurityInsightsVersion100YamlSchemaVulnerabilityReporting, SecurityInsightsVersion100YamlSchemaVulnerabilityReportingComment, SecurityInsightsVersion100YamlSchemaVulnerabilityReportingPgpKey}};
+use skootrs_model::{skootrs::{InitializedProject, ProjectParams, CodeReviewParams, GuacForwardingConfig, InitializedRepo, InitializedGithubRepo, InitializedGitlabRepo, InitializedGiteaRepo, InitializedLocalRepo, InitializedEcosystem, InitializedProjectEcosystem, RepoParams, EcosystemParams, ProjectEcosystemParams, GithubUser, GithubRepoParams, GitlabRepoParams, GiteaRepoParams, LocalRepoParams, RepoVisibility, SupportedLicense, SourceParams, InitializedSource, MavenParams, GradleParams, GoParams, CargoParams, CargoCrateType, NpmParams, PythonParams, InitializedGo, InitializedMaven, InitializedGradle, InitializedCargo, InitializedNpm, InitializedPython, facet::{CommonFacetParams, SourceFileFacet, SourceFileFacetParams, InitializedFacet, FacetParams, SupportedFacetType}}, cd_events::repo_created::{RepositoryCreatedEvent, RepositoryCreatedEventContext, RepositoryCreatedEventContextId, RepositoryCreatedEventContextVersion, RepositoryCreatedEventSubject, RepositoryCreatedEventSubjectContent, RepositoryCreatedEventSubjectContentUrl, RepositoryCreatedEventSubjectId}, security_insights::insights10::{SecurityInsightsVersion100YamlSchema, SecurityInsightsVersion100YamlSchemaContributionPolicy, SecurityInsightsVersion100YamlSchemaContributionPolicyAutomatedToolsListItem, SecurityInsightsVersion100YamlSchemaContributionPolicyAutomatedToolsListItemComment, SecurityInsightsVersion100YamlSchemaDependencies, SecurityInsightsVersion100YamlSchemaDependenciesDependenciesLifecycle, SecurityInsightsVersion100YamlSchemaDependenciesDependenciesLifecycleComment, SecurityInsightsVersion100YamlSchemaDependenciesEnvDependenciesPolicy, SecurityInsightsVersion100YamlSchemaDependenciesEnvDependenciesPolicyComment, SecurityInsightsVersion100YamlSchemaDependenciesSbomItem, SecurityInsightsVersion100YamlSchemaDependenciesSbomItemSbomCreation, SecurityInsightsVersion100YamlSchemaHeader, SecurityInsightsVersion100YamlSchemaHeaderCommitHash, SecurityInsightsVersion100YamlSchemaProjectLifecycle, SecurityInsightsVersion100YamlSchemaProjectLifecycleReleaseProcess, SecurityInsightsVersion100YamlSchemaSecurityArtifacts, SecurityInsightsVersion100YamlSchemaSecurityArtifactsSelfAssessment, SecurityInsightsVersion100YamlSchemaSecurityArtifactsSelfAssessmentComment, SecurityInsightsVersion100YamlSchemaSecurityArtifactsThreatModel, SecurityInsightsVersion100YamlSchemaSecurityArtifactsThreatModelComment, SecurityInsightsVersion100YamlSchemaSecurityAssessmentsItem, SecurityInsightsVersion100YamlSchemaSecurityAssessmentsItemComment, SecurityInsightsVersion100YamlSchemaSecurityContactsItem, SecurityInsightsVersion100YamlSchemaSecurityContactsItemValue, SecurityInsightsVersion100YamlSchemaSecurityTestingItem, SecurityInsightsVersion100YamlSchemaSecurityTestingItemComment, SecurityInsightsVersion100YamlSchemaSecurityTestingItemIntegration, SecurityInsightsVersion100YamlSchemaVulnerabilityReporting, SecurityInsightsVersion100YamlSchemaVulnerabilityReportingComment, SecurityInsightsVersion100YamlSchemaVulnerabilityReportingPgpKey}};
+use skootrs_model::skootrs::SkootrsConfig;
 use skootrs_model::skootrs::facet::{SourceBundleFacet, SourceBundleFacetParams, APIBundleFacet, APIBundleFacetParams, SourceFileContent, APIContent};
 
-/// Run the Skootrs REST API server.
+/// Run the Skootrs REST API server, keeping the state of the projects it creates in `store` and creating
+/// them with the options in `config`.
 #[actix_web::main]
-pub async fn run_server() -> std::io::Result<()> {
+pub async fn run_server<S: ProjectStateStore + Send + Sync + 'static>(store: S, config: SkootrsConfig) -> std::io::Result<()> {
     #[derive(OpenApi)]
     #[openapi(
         paths(
@@ -44,19 +46,40 @@ pub async fn run_server() -> std::io::Result<()> {
                 // Skootrs Model schemas
                 InitializedProject,
                 ProjectParams,
+                CodeReviewParams,
+                GuacForwardingConfig,
                 InitializedRepo,
                 InitializedGithubRepo,
+                InitializedGitlabRepo,
+                InitializedGiteaRepo,
+                InitializedLocalRepo,
                 InitializedEcosystem,
+                InitializedProjectEcosystem,
                 RepoParams,
                 EcosystemParams,
+                ProjectEcosystemParams,
                 GithubUser,
                 GithubRepoParams,
+                GitlabRepoParams,
+                GiteaRepoParams,
+                LocalRepoParams,
+                RepoVisibility,
+                SupportedLicense,
                 SourceParams,
                 InitializedSource,
                 MavenParams,
                 GoParams,
+                CargoParams,
+                CargoCrateType,
                 InitializedGo,
                 InitializedMaven,
+                InitializedCargo,
+                GradleParams,
+                InitializedGradle,
+                NpmParams,
+                InitializedNpm,
+                PythonParams,
+                InitializedPython,
                 // Facet Schemas
                 CommonFacetParams,
                 SourceFileFacet,
@@ -134,14 +157,15 @@ pub async fn run_server() -> std::io::Result<()> {
         }
     }
 
-    let store: Data<SurrealProjectStateStore> = Data::new(SurrealProjectStateStore::new().await.map_err(|err| std::io::Error::new(std::io::ErrorKind::Other, err))?);
+    let store: Data<S> = Data::new(store);
+    let config: Data<SkootrsConfig> = Data::new(config);
     // Make instance variable of ApiDoc so all worker threads gets the same instance.
     let openapi = ApiDoc::openapi();
 
     HttpServer::new(move || {
         App::new()
             .wrap(TracingLogger::default())
-            .configure(crate::server::project::configure(store.clone()))
+            .configure(crate::server::project::configure(store.clone(), config.clone()))
             .service(Redoc::with_url("/redoc", openapi.clone()))
             .service(
                 SwaggerUi::new("/swagger-ui/{_:.*}").url("/api-docs/openapi.json", openapi.clone()),
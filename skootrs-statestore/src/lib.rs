@@ -14,11 +14,135 @@
 // limitations under the License.
 
 //! This is the crate where the statestore where the management of `Skootrs` project state is defined.
-//! The statestore currently supports an in memory `SurrealDB` instance that writes to a file.
+//! Stores implement the `ProjectStateStore` trait, and the statestore currently supports an in memory
+//! `SurrealDB` instance that writes to a file, a directory of JSON files, and a purely in memory store.
+//! The state is also stored inside of the project's repo itself in a `.skootrs` file. The projects the local
+//! Skootrs knows about are tracked in a local `.skootrscache` file of repo URLs.
+
+use std::{
+    collections::{btree_map::Entry, BTreeMap, BTreeSet},
+    fs,
+    future::Future,
+    path::{Path, PathBuf},
+    sync::{Mutex, MutexGuard},
+};
 
 use surrealdb::{engine::local::{Db, RocksDb}, Surreal};
 
-use skootrs_model::skootrs::{InitializedProject, SkootError};
+use skootrs_lib::service::{repo::{LocalRepoService, RepoService}, source::{LocalSourceService, SourceService}};
+use skootrs_model::skootrs::{ForgeHosts, InitializedProject, InitializedRepo, InitializedSource, SkootError, SkootrsConfig, StateStoreBackend};
+
+pub use skootrs_lib::service::project::IN_REPO_STATE_FILE;
+
+/// The name of the file that holds the local cache of references to projects the local Skootrs knows about.
+pub const LOCAL_REFERENCE_CACHE_FILE: &str = ".skootrscache";
+
+/// The in-repo state store for Skootrs projects. This stores the `InitializedProject` as JSON in the
+/// `.skootrs` file at the root of the project's local working copy and pushes it to the remote repo.
+#[derive(Debug)]
+pub struct InRepoProjectStateStore {
+    pub initialized_source: InitializedSource,
+    pub source_service: LocalSourceService,
+}
+
+impl InRepoProjectStateStore {
+    /// Store the project state in the `.skootrs` file of the repo and push it to the remote.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the state file can't be written, committed, or pushed.
+    pub fn create(&self, project: &InitializedProject) -> Result<(), SkootError> {
+        self.source_service.write_file(
+            self.initialized_source.clone(),
+            "./",
+            IN_REPO_STATE_FILE.to_string(),
+            serde_json::to_string_pretty(project)?,
+        )?;
+        self.source_service.commit_and_push_changes(
+            self.initialized_source.clone(),
+            "Updated Skootrs project state".to_string(),
+        )?;
+        Ok(())
+    }
+
+    /// Fetch the project state from the `.skootrs` file of the local working copy.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the state file can't be read or parsed.
+    pub fn read(&self) -> Result<InitializedProject, SkootError> {
+        let content = self.source_service.read_file(
+            &self.initialized_source,
+            "./",
+            IN_REPO_STATE_FILE.to_string(),
+        )?;
+        Ok(serde_json::from_str(&content)?)
+    }
+}
+
+/// The remote state store for Skootrs projects. This fetches the `.skootrs` file straight from a
+/// project's remote repo so a project can be managed from any machine without a local copy.
+#[derive(Debug)]
+pub struct RemoteProjectStateStore {
+    pub repo_service: LocalRepoService,
+    /// The hosts of self-hosted forges used to recognize the repo URLs of projects.
+    pub forge_hosts: ForgeHosts,
+}
+
+impl RemoteProjectStateStore {
+    /// Fetch a project's state from the `.skootrs` file in its remote repo.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repo URL isn't supported or the state file can't be fetched or parsed.
+    pub async fn select(&self, repo_url: String) -> Result<InitializedProject, SkootError> {
+        let initialized_repo = InitializedRepo::from_url(&repo_url, &self.forge_hosts)?;
+        let content = self
+            .repo_service
+            .fetch_file_content(&initialized_repo, IN_REPO_STATE_FILE)
+            .await?;
+        Ok(serde_json::from_str(&content)?)
+    }
+}
+
+/// The `ProjectStateStore` trait is the interface for the stores that hold the state of Skootrs projects.
+/// Projects are keyed by the full URL of their repo.
+pub trait ProjectStateStore {
+    /// Store a new project in the state store.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the project is already in the state store or can't be stored.
+    fn create(&self, project: InitializedProject) -> impl Future<Output = Result<(), SkootError>> + Send;
+
+    /// Fetch a project from the state store. Returns `None` if the project isn't in the state store.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the state store can't be read.
+    fn select(&self, repo_url: String) -> impl Future<Output = Result<Option<InitializedProject>, SkootError>> + Send;
+
+    /// Fetch all projects from the state store.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the state store can't be read.
+    fn select_all(&self) -> impl Future<Output = Result<Vec<InitializedProject>, SkootError>> + Send;
+
+    /// Replace the state of a project that is already in the state store.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the project isn't in the state store or can't be stored.
+    fn update(&self, project: InitializedProject) -> impl Future<Output = Result<(), SkootError>> + Send;
+
+    /// Remove a project from the state store. Returns the removed project, or `None` if it wasn't there.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the project can't be removed from the state store.
+    fn delete(&self, repo_url: String) -> impl Future<Output = Result<Option<InitializedProject>, SkootError>> + Send;
+}
 
 /// The `SurrealDB` state store for Skootrs projects.
 #[derive(Debug)]
@@ -26,55 +150,427 @@ pub struct SurrealProjectStateStore {
     pub db: Surreal<Db>
 }
 
-/// The functionality for the `SurrealDB` state store for Skootrs projects.
 impl SurrealProjectStateStore {
-    /// Create a new `SurrealDB` state store for Skootrs projects if `state.db` does not exist, otherwise open it.
+    /// Create a new `SurrealDB` state store for Skootrs projects at `path` if it does not exist, otherwise open it.
     ///
     /// # Errors
     ///
     /// Returns an error if the state store can't be created or opened.
-    pub async fn new() -> Result<Self, SkootError> {
-        let db = Surreal::new::<RocksDb>("state.db").await?;
+    pub async fn new(path: &str) -> Result<Self, SkootError> {
+        let db = Surreal::new::<RocksDb>(path).await?;
         db.use_ns("kusaridev").use_db("skootrs").await?;
         Ok(Self {
             db
         })
     }
+}
 
-    /// Store a new project in the state store. 
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the project can't be stored in the state store.
-    pub async fn create(&self, project: InitializedProject) -> Result<Option<InitializedProject>, SkootError> {
-        let created = self.db
+impl ProjectStateStore for SurrealProjectStateStore {
+    async fn create(&self, project: InitializedProject) -> Result<(), SkootError> {
+        let _created: Option<InitializedProject> = self.db
             .create(("project", project.repo.full_url()))
             .content(project)
             .await?;
-        Ok(created)
+        Ok(())
     }
 
-    /// Fetch a project from the state store.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the project can't be fetched from the state store.
-    pub async fn select(&self, repo_url: String) -> Result<Option<InitializedProject>, SkootError> {
+    async fn select(&self, repo_url: String) -> Result<Option<InitializedProject>, SkootError> {
         let record = self.db
             .select(("project", repo_url))
             .await?;
         Ok(record)
     }
 
-    /// Fetch all projects from the state store.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if all the projects can't be dumped from the state store.
-    pub async fn select_all(&self) -> Result<Vec<InitializedProject>, SkootError> {
+    async fn select_all(&self) -> Result<Vec<InitializedProject>, SkootError> {
         let records = self.db
             .select("project")
             .await?;
         Ok(records)
     }
+
+    async fn update(&self, project: InitializedProject) -> Result<(), SkootError> {
+        let repo_url = project.repo.full_url();
+        // `SurrealDB` creates records that don't exist on update, so check first to keep the semantics
+        // the same as the other state stores.
+        if self.select(repo_url.clone()).await?.is_none() {
+            return Err(format!("Project {repo_url} is not in the state store").into());
+        }
+        let _updated: Option<InitializedProject> = self.db
+            .update(("project", repo_url))
+            .content(project)
+            .await?;
+        Ok(())
+    }
+
+    async fn delete(&self, repo_url: String) -> Result<Option<InitializedProject>, SkootError> {
+        let deleted = self.db
+            .delete(("project", repo_url))
+            .await?;
+        Ok(deleted)
+    }
+}
+
+/// The JSON directory state store for Skootrs projects. Each project is stored as a pretty printed JSON
+/// file in the directory, named after the URL encoded full URL of its repo.
+#[derive(Debug)]
+pub struct JsonDirProjectStateStore {
+    pub path: PathBuf,
+}
+
+impl JsonDirProjectStateStore {
+    /// Create a new JSON directory state store for Skootrs projects at `path`, creating the directory if
+    /// it does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory can't be created.
+    pub fn new<P: AsRef<Path>>(path: P) -> Result<Self, SkootError> {
+        fs::create_dir_all(&path)?;
+        Ok(Self {
+            path: path.as_ref().to_path_buf(),
+        })
+    }
+
+    fn project_path(&self, repo_url: &str) -> PathBuf {
+        self.path.join(format!("{}.json", urlencoding::encode(repo_url)))
+    }
+
+    fn write(&self, project: &InitializedProject) -> Result<(), SkootError> {
+        fs::write(
+            self.project_path(&project.repo.full_url()),
+            serde_json::to_string_pretty(project)?,
+        )?;
+        Ok(())
+    }
+}
+
+impl ProjectStateStore for JsonDirProjectStateStore {
+    async fn create(&self, project: InitializedProject) -> Result<(), SkootError> {
+        let repo_url = project.repo.full_url();
+        if self.project_path(&repo_url).exists() {
+            return Err(format!("Project {repo_url} is already in the state store").into());
+        }
+        self.write(&project)
+    }
+
+    async fn select(&self, repo_url: String) -> Result<Option<InitializedProject>, SkootError> {
+        let path = self.project_path(&repo_url);
+        if !path.exists() {
+            return Ok(None);
+        }
+        Ok(Some(serde_json::from_str(&fs::read_to_string(path)?)?))
+    }
+
+    async fn select_all(&self) -> Result<Vec<InitializedProject>, SkootError> {
+        let mut projects = Vec::new();
+        for entry in fs::read_dir(&self.path)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|extension| extension == "json") {
+                projects.push(serde_json::from_str(&fs::read_to_string(path)?)?);
+            }
+        }
+        Ok(projects)
+    }
+
+    async fn update(&self, project: InitializedProject) -> Result<(), SkootError> {
+        let repo_url = project.repo.full_url();
+        if !self.project_path(&repo_url).exists() {
+            return Err(format!("Project {repo_url} is not in the state store").into());
+        }
+        self.write(&project)
+    }
+
+    async fn delete(&self, repo_url: String) -> Result<Option<InitializedProject>, SkootError> {
+        let deleted = self.select(repo_url.clone()).await?;
+        if deleted.is_some() {
+            fs::remove_file(self.project_path(&repo_url))?;
+        }
+        Ok(deleted)
+    }
+}
+
+/// The state store for Skootrs projects picked by the `state_store` backend of a `SkootrsConfig`.
+#[derive(Debug)]
+pub enum ConfiguredProjectStateStore {
+    Surreal(SurrealProjectStateStore),
+    JsonDir(JsonDirProjectStateStore),
+}
+
+impl ConfiguredProjectStateStore {
+    /// Opens the state store at the `state_store_path` of `config` with its `state_store` backend, creating
+    /// it if it does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the state store can't be created or opened.
+    pub async fn open(config: &SkootrsConfig) -> Result<Self, SkootError> {
+        match config.state_store {
+            StateStoreBackend::Surreal => Ok(Self::Surreal(SurrealProjectStateStore::new(&config.state_store_path).await?)),
+            StateStoreBackend::JsonDir => Ok(Self::JsonDir(JsonDirProjectStateStore::new(&config.state_store_path)?)),
+        }
+    }
+}
+
+impl ProjectStateStore for ConfiguredProjectStateStore {
+    async fn create(&self, project: InitializedProject) -> Result<(), SkootError> {
+        match self {
+            Self::Surreal(store) => store.create(project).await,
+            Self::JsonDir(store) => store.create(project).await,
+        }
+    }
+
+    async fn select(&self, repo_url: String) -> Result<Option<InitializedProject>, SkootError> {
+        match self {
+            Self::Surreal(store) => store.select(repo_url).await,
+            Self::JsonDir(store) => store.select(repo_url).await,
+        }
+    }
+
+    async fn select_all(&self) -> Result<Vec<InitializedProject>, SkootError> {
+        match self {
+            Self::Surreal(store) => store.select_all().await,
+            Self::JsonDir(store) => store.select_all().await,
+        }
+    }
+
+    async fn update(&self, project: InitializedProject) -> Result<(), SkootError> {
+        match self {
+            Self::Surreal(store) => store.update(project).await,
+            Self::JsonDir(store) => store.update(project).await,
+        }
+    }
+
+    async fn delete(&self, repo_url: String) -> Result<Option<InitializedProject>, SkootError> {
+        match self {
+            Self::Surreal(store) => store.delete(repo_url).await,
+            Self::JsonDir(store) => store.delete(repo_url).await,
+        }
+    }
+}
+
+/// The in memory state store for Skootrs projects. Nothing is persisted, so this is mostly useful for tests.
+#[derive(Debug, Default)]
+pub struct InMemoryProjectStateStore {
+    pub projects: Mutex<BTreeMap<String, InitializedProject>>,
+}
+
+impl InMemoryProjectStateStore {
+    fn projects(&self) -> Result<MutexGuard<'_, BTreeMap<String, InitializedProject>>, SkootError> {
+        self.projects
+            .lock()
+            .map_err(|_| SkootError::from("In memory state store lock is poisoned"))
+    }
+}
+
+impl ProjectStateStore for InMemoryProjectStateStore {
+    async fn create(&self, project: InitializedProject) -> Result<(), SkootError> {
+        match self.projects()?.entry(project.repo.full_url()) {
+            Entry::Occupied(entry) => {
+                Err(format!("Project {} is already in the state store", entry.key()).into())
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(project);
+                Ok(())
+            }
+        }
+    }
+
+    async fn select(&self, repo_url: String) -> Result<Option<InitializedProject>, SkootError> {
+        Ok(self.projects()?.get(&repo_url).cloned())
+    }
+
+    async fn select_all(&self) -> Result<Vec<InitializedProject>, SkootError> {
+        Ok(self.projects()?.values().cloned().collect())
+    }
+
+    async fn update(&self, project: InitializedProject) -> Result<(), SkootError> {
+        match self.projects()?.entry(project.repo.full_url()) {
+            Entry::Occupied(mut entry) => {
+                entry.insert(project);
+                Ok(())
+            }
+            Entry::Vacant(entry) => {
+                Err(format!("Project {} is not in the state store", entry.key()).into())
+            }
+        }
+    }
+
+    async fn delete(&self, repo_url: String) -> Result<Option<InitializedProject>, SkootError> {
+        Ok(self.projects()?.remove(&repo_url))
+    }
+}
+
+/// The local reference cache of Skootrs projects. This is just the list of repo URLs for the projects
+/// the local Skootrs knows about. The state of each project is resolved from the repo itself.
+#[derive(Debug, Default)]
+pub struct LocalProjectReferenceCache {
+    pub path: String,
+    pub local_cache: BTreeSet<String>,
+}
+
+impl LocalProjectReferenceCache {
+    /// Load the reference cache from the file at `path`, or start an empty one if the file doesn't exist.
+    /// The file is a list of repo URLs, one per line.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache file exists but can't be read.
+    pub fn load_or_create(path: &str) -> Result<Self, SkootError> {
+        let local_cache = if Path::new(path).exists() {
+            fs::read_to_string(path)?
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(ToString::to_string)
+                .collect()
+        } else {
+            BTreeSet::new()
+        };
+
+        Ok(Self {
+            path: path.to_string(),
+            local_cache,
+        })
+    }
+
+    /// Write the reference cache back to its file, creating the directory it is in if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache file can't be written.
+    pub fn save(&self) -> Result<(), SkootError> {
+        if let Some(parent) = Path::new(&self.path).parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut content = String::new();
+        for repo_url in &self.local_cache {
+            content.push_str(repo_url);
+            content.push('\n');
+        }
+        fs::write(&self.path, content)?;
+        Ok(())
+    }
+
+    /// Returns the repo URLs of all the projects in the reference cache.
+    #[must_use]
+    pub fn list(&self) -> Vec<String> {
+        self.local_cache.iter().cloned().collect()
+    }
+
+    /// Adds a project's repo URL to the reference cache. Returns `false` if it was already there.
+    pub fn add(&mut self, repo_url: String) -> bool {
+        self.local_cache.insert(repo_url)
+    }
+
+    /// Removes a project's repo URL from the reference cache. Returns `false` if it wasn't there.
+    pub fn remove(&mut self, repo_url: &str) -> bool {
+        self.local_cache.remove(repo_url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use skootrs_model::skootrs::{InitializedEcosystem, InitializedGo, InitializedLocalRepo};
+    use tempdir::TempDir;
+
+    fn test_project(name: &str) -> InitializedProject {
+        InitializedProject {
+            repo: InitializedRepo::Local(InitializedLocalRepo {
+                name: name.to_string(),
+                path: format!("/tmp/remotes/{name}.git"),
+            }),
+            ecosystems: vec![InitializedEcosystem::Go(InitializedGo {
+                name: name.to_string(),
+                host: "localhost".to_string(),
+                major_version: None,
+            })
+            .into()],
+            source: InitializedSource {
+                path: format!("/tmp/{name}"),
+            },
+            facets: vec![],
+            missing_facets: vec![],
+            guac_collector_endpoint: None,
+        }
+    }
+
+    async fn assert_state_store_round_trip<S: ProjectStateStore>(store: S) {
+        let project = test_project("test");
+        let repo_url = project.repo.full_url();
+        assert!(store.select(repo_url.clone()).await.unwrap().is_none());
+        assert!(store.update(project.clone()).await.is_err());
+
+        store.create(project.clone()).await.unwrap();
+        store.create(test_project("other")).await.unwrap();
+        assert!(store.create(project.clone()).await.is_err());
+        assert_eq!(store.select_all().await.unwrap().len(), 2);
+
+        let mut updated = project.clone();
+        updated.source.path = "/tmp/elsewhere".to_string();
+        store.update(updated).await.unwrap();
+        let selected = store.select(repo_url.clone()).await.unwrap().unwrap();
+        assert_eq!(selected.source.path, "/tmp/elsewhere");
+
+        let deleted = store.delete(repo_url.clone()).await.unwrap();
+        assert!(deleted.is_some());
+        assert!(store.delete(repo_url.clone()).await.unwrap().is_none());
+        assert!(store.select(repo_url).await.unwrap().is_none());
+        assert_eq!(store.select_all().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_json_dir_state_store() {
+        let temp_dir = TempDir::new("test").unwrap();
+        let store = JsonDirProjectStateStore::new(temp_dir.path().join("state")).unwrap();
+        assert_state_store_round_trip(store).await;
+    }
+
+    #[tokio::test]
+    async fn test_configured_json_dir_state_store() {
+        let temp_dir = TempDir::new("test").unwrap();
+        let config = SkootrsConfig {
+            state_store_path: temp_dir.path().join("state").to_string_lossy().to_string(),
+            state_store: StateStoreBackend::JsonDir,
+            ..SkootrsConfig::default()
+        };
+        let store = ConfiguredProjectStateStore::open(&config).await.unwrap();
+        assert!(matches!(store, ConfiguredProjectStateStore::JsonDir(_)));
+        assert_state_store_round_trip(store).await;
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_state_store() {
+        assert_state_store_round_trip(InMemoryProjectStateStore::default()).await;
+    }
+
+    #[test]
+    fn test_reference_cache_round_trip() {
+        let temp_dir = TempDir::new("test").unwrap();
+        // The cache's directory, e.g. ~/.cache/skootrs, is created the first time it is saved.
+        let path = temp_dir.path().join("skootrs").join(LOCAL_REFERENCE_CACHE_FILE);
+        let path = path.to_str().unwrap();
+
+        let mut cache = LocalProjectReferenceCache::load_or_create(path).unwrap();
+        assert!(cache.list().is_empty());
+        assert!(cache.add("https://github.com/kusaridev/skootrs".to_string()));
+        assert!(cache.add("https://github.com/kusaridev/other".to_string()));
+        assert!(!cache.add("https://github.com/kusaridev/skootrs".to_string()));
+        cache.save().unwrap();
+
+        let mut cache = LocalProjectReferenceCache::load_or_create(path).unwrap();
+        assert_eq!(
+            cache.list(),
+            vec![
+                "https://github.com/kusaridev/other".to_string(),
+                "https://github.com/kusaridev/skootrs".to_string(),
+            ]
+        );
+        assert!(cache.remove("https://github.com/kusaridev/other"));
+        assert!(!cache.remove("https://github.com/kusaridev/other"));
+        cache.save().unwrap();
+
+        let cache = LocalProjectReferenceCache::load_or_create(path).unwrap();
+        assert_eq!(cache.list(), vec!["https://github.com/kusaridev/skootrs".to_string()]);
+    }
 }